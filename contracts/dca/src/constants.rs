@@ -6,6 +6,34 @@ pub const AFTER_FAILED_AUTOMATION_REPLY_ID: u64 = 3;
 pub const AFTER_DELEGATION_REPLY_ID: u64 = 4;
 pub const AFTER_ORDER_MIGRATION_REPLY_ID: u64 = 5;
 pub const FAIL_SILENTLY_REPLY_ID: u64 = 6;
+pub const AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID: u64 = 7;
+pub const AFTER_FAILED_HOOK_REPLY_ID: u64 = 8;
+pub const AFTER_ALLOWANCE_PULL_REPLY_ID: u64 = 9;
+pub const AFTER_STAKING_REWARDS_CLAIM_REPLY_ID: u64 = 10;
+
+pub const LOW_BALANCE_SWAP_COUNT: u128 = 3;
+
+pub const DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS: u64 = 600;
+
+pub const DEFAULT_HOOK_GAS_LIMIT: u64 = 500_000;
+
+pub const PRICE_HISTORY_CAPACITY: u64 = 100;
+pub const PRICE_HISTORY_INTERVAL_SECONDS: u64 = 60 * 60;
+
+pub const BITCOIN_MODEL_ID_BUCKETS: [(u64, u8); 10] = [
+    (32, 30),
+    (38, 35),
+    (44, 40),
+    (51, 45),
+    (57, 50),
+    (65, 55),
+    (77, 60),
+    (96, 70),
+    (123, 80),
+    (124, 90),
+];
+
+pub const DEFAULT_PERFORMANCE_FEE_PERCENT: Decimal = Decimal::new(Uint128::new(200000000000000000));
 
 pub const SWAP_FEE_RATE: &str = "0.0015";
 
@@ -1,35 +1,65 @@
 use crate::constants::{
-    AFTER_DELEGATION_REPLY_ID, AFTER_FAILED_AUTOMATION_REPLY_ID, AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
-    AFTER_ORDER_MIGRATION_REPLY_ID, AFTER_SWAP_REPLY_ID, FAIL_SILENTLY_REPLY_ID,
+    AFTER_ALLOWANCE_PULL_REPLY_ID, AFTER_DELEGATION_REPLY_ID, AFTER_FAILED_AUTOMATION_REPLY_ID,
+    AFTER_FAILED_HOOK_REPLY_ID, AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID,
+    AFTER_LIMIT_ORDER_PLACED_REPLY_ID, AFTER_ORDER_MIGRATION_REPLY_ID,
+    AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, AFTER_SWAP_REPLY_ID, FAIL_SILENTLY_REPLY_ID,
 };
 use crate::error::ContractError;
 use crate::handlers::cancel_vault::cancel_vault_handler;
+use crate::handlers::claim::claim_handler;
+use crate::handlers::create_custom_swap_fee::create_custom_swap_fee_handler;
 use crate::handlers::create_vault::{create_vault_handler, save_price_trigger};
 use crate::handlers::deposit::deposit_handler;
 use crate::handlers::disburse_escrow::disburse_escrow_handler;
 use crate::handlers::disburse_funds::disburse_funds_handler;
-use crate::handlers::execute_trigger::execute_trigger_handler;
+use crate::handlers::execute_trigger::{
+    execute_trigger_handler, swap_after_allowance_pull, swap_claimed_staking_rewards,
+};
+use crate::handlers::execute_triggers::{execute_triggers_handler, log_failed_trigger_execution};
+use crate::handlers::get_base_denoms::get_base_denoms_handler;
+use crate::handlers::get_claimable::get_claimable_handler;
 use crate::handlers::get_config::get_config_handler;
+use crate::handlers::get_custom_swap_fees::get_custom_swap_fees_handler;
 use crate::handlers::get_disburse_escrow_tasks::get_disburse_escrow_tasks_handler;
 use crate::handlers::get_events::get_events_handler;
 use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
 use crate::handlers::get_pairs::get_pairs_handler;
+use crate::handlers::get_referral_volume::get_referral_volume_handler;
+use crate::handlers::get_statistics::get_statistics_handler;
+use crate::handlers::get_swap_adjustment_submissions::get_swap_adjustment_submissions_handler;
 use crate::handlers::get_time_trigger_ids::get_time_trigger_ids_handler;
 use crate::handlers::get_trigger_id_by_fin_limit_order_idx::get_trigger_id_by_fin_limit_order_idx_handler;
 use crate::handlers::get_vault::get_vault_handler;
 use crate::handlers::get_vault_performance::get_vault_performance_handler;
+use crate::handlers::get_vault_report::get_vault_report_handler;
 use crate::handlers::get_vaults::get_vaults_handler;
 use crate::handlers::get_vaults_by_address::get_vaults_by_address_handler;
 use crate::handlers::handle_failed_automation::handle_failed_automation_handler;
+use crate::handlers::handle_ibc_lifecycle_complete::handle_ibc_lifecycle_complete_handler;
 use crate::handlers::instantiate::instantiate_handler;
 use crate::handlers::migrate::migrate_handler;
+use crate::handlers::migrate_events::migrate_events_handler;
 use crate::handlers::migrate_limit_order::{migrate_limit_order, save_new_limit_order_idx};
+use crate::handlers::migrate_statistics::migrate_statistics_handler;
 use crate::handlers::old_z_delegate_handler::old_z_delegate_handler;
+use crate::handlers::pause_vault::pause_vault_handler;
+use crate::handlers::receive_cw20::receive_cw20_handler;
+use crate::handlers::register_base_denom::register_base_denom_handler;
+use crate::handlers::register_cw20_denom::register_cw20_denom_handler;
+use crate::handlers::remove_custom_swap_fee::remove_custom_swap_fee_handler;
+use crate::handlers::resume_vault::resume_vault_handler;
+use crate::handlers::retry_post_execution_action::retry_post_execution_action_handler;
+use crate::handlers::transfer_vault_ownership::{
+    accept_vault_ownership_handler, transfer_vault_ownership_handler,
+};
 use crate::handlers::update_config::update_config_handler;
 use crate::handlers::update_swap_adjustment_handler::update_swap_adjustment_handler;
 use crate::handlers::update_vault::update_vault_handler;
+use crate::handlers::withdraw_from_vault::withdraw_from_vault_handler;
 use crate::handlers::z_delegate::{log_delegation_result, z_delegate_handler};
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::helpers::hooks::log_failed_hook_notification;
+use crate::helpers::reply::get_reply_type;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
@@ -76,6 +106,15 @@ pub fn execute(
             target_receive_amount,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            end_time_utc_seconds,
+            max_executions,
+            stop_loss,
+            take_profit,
+            maximum_receive_amount,
+            price_band,
+            referrer,
+            hook,
+            funding_mode,
         } => create_vault_handler(
             deps,
             env,
@@ -93,6 +132,15 @@ pub fn execute(
             target_receive_amount,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            end_time_utc_seconds,
+            max_executions,
+            stop_loss,
+            take_profit,
+            maximum_receive_amount,
+            price_band,
+            referrer,
+            hook,
+            funding_mode,
         ),
         ExecuteMsg::UpdateVault {
             vault_id,
@@ -103,6 +151,13 @@ pub fn execute(
             time_interval,
             swap_adjustment_strategy,
             swap_amount,
+            end_time_utc_seconds,
+            max_executions,
+            stop_loss,
+            take_profit,
+            maximum_receive_amount,
+            price_band,
+            hook,
         } => update_vault_handler(
             deps,
             env,
@@ -115,14 +170,36 @@ pub fn execute(
             time_interval,
             swap_adjustment_strategy,
             swap_amount,
+            end_time_utc_seconds,
+            max_executions,
+            stop_loss,
+            take_profit,
+            maximum_receive_amount,
+            price_band,
+            hook,
         ),
         ExecuteMsg::CancelVault { vault_id } => cancel_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::PauseVault { vault_id } => pause_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::ResumeVault { vault_id } => resume_vault_handler(deps, env, info, vault_id),
+        ExecuteMsg::TransferVaultOwnership {
+            vault_id,
+            new_owner,
+        } => transfer_vault_ownership_handler(deps, env, info, vault_id, new_owner),
+        ExecuteMsg::AcceptVaultOwnership { vault_id } => {
+            accept_vault_ownership_handler(deps, env, info, vault_id)
+        }
         ExecuteMsg::ExecuteTrigger { trigger_id, route } => {
             execute_trigger_handler(deps, env, trigger_id, route)
         }
+        ExecuteMsg::ExecuteTriggers { trigger_ids } => {
+            execute_triggers_handler(deps, env, trigger_ids)
+        }
         ExecuteMsg::Deposit { address, vault_id } => {
             deposit_handler(deps, env, info, address, vault_id)
         }
+        ExecuteMsg::WithdrawFromVault { vault_id, amount } => {
+            withdraw_from_vault_handler(deps, env, info, vault_id, amount)
+        }
         ExecuteMsg::UpdateConfig {
             executors,
             fee_collectors,
@@ -135,6 +212,13 @@ pub fn execute(
             twap_period,
             default_slippage_tolerance,
             exchange_contract_address,
+            referral_fee_share,
+            value_averaging_swap_fee_percent,
+            swap_adjustment_submitters,
+            swap_adjustment_quorum,
+            performance_fee_percent,
+            escrow_crystallisation_period_seconds,
+            hook_gas_limit,
         } => update_config_handler(
             deps,
             info,
@@ -149,7 +233,29 @@ pub fn execute(
             twap_period,
             default_slippage_tolerance,
             exchange_contract_address,
+            referral_fee_share,
+            value_averaging_swap_fee_percent,
+            swap_adjustment_submitters,
+            swap_adjustment_quorum,
+            performance_fee_percent,
+            escrow_crystallisation_period_seconds,
+            hook_gas_limit,
         ),
+        ExecuteMsg::CreateCustomSwapFee {
+            denom,
+            paired_denom,
+            swap_fee_percent,
+        } => create_custom_swap_fee_handler(deps, info, denom, paired_denom, swap_fee_percent),
+        ExecuteMsg::RemoveCustomSwapFee {
+            denom,
+            paired_denom,
+        } => remove_custom_swap_fee_handler(deps, info, denom, paired_denom),
+        ExecuteMsg::RegisterBaseDenom {
+            base_denom,
+            model_id_buckets,
+            escrow_level,
+        } => register_base_denom_handler(deps, info, base_denom, model_id_buckets, escrow_level),
+        ExecuteMsg::RegisterCw20Denom { denom } => register_cw20_denom_handler(deps, info, denom),
         ExecuteMsg::UpdateSwapAdjustment { strategy, value } => {
             update_swap_adjustment_handler(deps, env, info, strategy, value)
         }
@@ -172,24 +278,44 @@ pub fn execute(
             ..
         } => old_z_delegate_handler(deps.as_ref(), info, delegator_address, validator_address),
         ExecuteMsg::MigrateLimitOrder { vault_id } => migrate_limit_order(deps, vault_id),
+        ExecuteMsg::MigrateStatistics { limit } => migrate_statistics_handler(deps, info, limit),
+        ExecuteMsg::MigrateEvents { limit } => migrate_events_handler(deps, info, limit),
+        ExecuteMsg::Claim {} => claim_handler(deps, info),
+        ExecuteMsg::RetryPostExecutionAction { vault_id, event_id } => {
+            retry_post_execution_action_handler(deps, env, info, vault_id, event_id)
+        }
+        ExecuteMsg::Receive(receive_msg) => receive_cw20_handler(deps, env, info, receive_msg),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
-    match reply.id {
+    match get_reply_type(reply.id) {
         AFTER_LIMIT_ORDER_PLACED_REPLY_ID => save_price_trigger(deps, reply),
         AFTER_SWAP_REPLY_ID => disburse_funds_handler(deps, &env, reply),
         AFTER_FAILED_AUTOMATION_REPLY_ID => handle_failed_automation_handler(deps, env, reply),
         AFTER_DELEGATION_REPLY_ID => log_delegation_result(reply),
         AFTER_ORDER_MIGRATION_REPLY_ID => save_new_limit_order_idx(deps, reply),
         FAIL_SILENTLY_REPLY_ID => Ok(Response::new()),
-        id => Err(ContractError::CustomError {
-            val: format!("unhandled DCA contract reply id: {}", id),
+        AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID => log_failed_trigger_execution(reply),
+        AFTER_FAILED_HOOK_REPLY_ID => log_failed_hook_notification(reply),
+        AFTER_ALLOWANCE_PULL_REPLY_ID => swap_after_allowance_pull(deps, env, reply),
+        AFTER_STAKING_REWARDS_CLAIM_REPLY_ID => swap_claimed_staking_rewards(deps, env, reply),
+        _ => Err(ContractError::CustomError {
+            val: format!("unhandled DCA contract reply id: {}", reply.id),
         }),
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::IbcLifecycleComplete(lifecycle) => {
+            handle_ibc_lifecycle_complete_handler(deps, env, lifecycle)
+        }
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -225,24 +351,51 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_after,
             limit,
             reverse,
+            filter,
         } => to_json_binary(&get_events_by_resource_id_handler(
             deps,
             resource_id,
             start_after,
             limit,
             reverse,
+            filter,
         )?),
         QueryMsg::GetEvents {
             start_after,
             limit,
             reverse,
-        } => to_json_binary(&get_events_handler(deps, start_after, limit, reverse)?),
+            filter,
+        } => to_json_binary(&get_events_handler(
+            deps,
+            start_after,
+            limit,
+            reverse,
+            filter,
+        )?),
         QueryMsg::GetConfig {} => to_json_binary(&get_config_handler(deps)?),
         QueryMsg::GetVaultPerformance { vault_id } => {
             to_json_binary(&get_vault_performance_handler(deps, vault_id)?)
         }
+        QueryMsg::GetVaultReport { vault_id } => {
+            to_json_binary(&get_vault_report_handler(deps, vault_id)?)
+        }
         QueryMsg::GetDisburseEscrowTasks { limit } => {
             to_json_binary(&get_disburse_escrow_tasks_handler(deps, env, limit)?)
         }
+        QueryMsg::GetCustomSwapFees {} => to_json_binary(&get_custom_swap_fees_handler(deps)?),
+        QueryMsg::GetReferralVolume { referrer } => {
+            to_json_binary(&get_referral_volume_handler(deps, referrer)?)
+        }
+        QueryMsg::GetClaimable { address } => {
+            to_json_binary(&get_claimable_handler(deps, address)?)
+        }
+        QueryMsg::GetStatistics {
+            swap_denom,
+            target_denom,
+        } => to_json_binary(&get_statistics_handler(deps, swap_denom, target_denom)?),
+        QueryMsg::GetBaseDenoms {} => to_json_binary(&get_base_denoms_handler(deps)?),
+        QueryMsg::GetSwapAdjustmentSubmissions { strategy } => to_json_binary(
+            &get_swap_adjustment_submissions_handler(deps, env, strategy)?,
+        ),
     }
 }
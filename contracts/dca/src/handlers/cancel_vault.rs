@@ -1,5 +1,7 @@
 use crate::constants::FAIL_SILENTLY_REPLY_ID;
 use crate::error::ContractError;
+use crate::helpers::coin::get_send_message;
+use crate::helpers::hooks::get_hook_message;
 use crate::helpers::validation::{
     assert_sender_is_admin_or_vault_owner, assert_vault_is_not_cancelled,
 };
@@ -9,9 +11,10 @@ use crate::state::events::create_event;
 use crate::state::triggers::delete_trigger;
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData};
+use crate::types::hook::VaultHookNotification;
 use crate::types::trigger::TriggerConfiguration;
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{to_json_binary, BankMsg, DepsMut, Response, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, DepsMut, Response, Uint128, WasmMsg};
 use cosmwasm_std::{Env, MessageInfo, SubMsg};
 use exchange::msg::ExecuteMsg;
 use shared::coin::empty_of;
@@ -43,10 +46,11 @@ pub fn cancel_vault_handler(
     let mut submessages = Vec::<SubMsg>::new();
 
     if vault.balance.amount > Uint128::zero() {
-        submessages.push(SubMsg::new(BankMsg::Send {
-            to_address: vault.owner.to_string(),
-            amount: vec![vault.balance.clone()],
-        }));
+        submessages.push(SubMsg::new(get_send_message(
+            deps.storage,
+            vault.owner.to_string(),
+            vault.balance.clone(),
+        )));
     }
 
     update_vault(
@@ -88,6 +92,14 @@ pub fn cancel_vault_handler(
         ));
     };
 
+    submessages.extend(get_hook_message(
+        deps.storage,
+        &vault,
+        VaultHookNotification::VaultCancelled {
+            refunded: vault.balance.clone(),
+        },
+    )?);
+
     delete_trigger(deps.storage, vault.id)?;
 
     Ok(Response::new()
@@ -110,7 +122,41 @@ mod cancel_vault_tests {
     use crate::types::event::{EventBuilder, EventData};
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{BankMsg, Coin, Decimal, SubMsg, Uint128};
+    use crate::state::cw20_denoms::save_cw20_denom;
+    use cosmwasm_std::{Addr, BankMsg, Coin, Decimal, SubMsg, Uint128};
+    use cw20::Cw20ExecuteMsg;
+
+    #[test]
+    fn with_hook_should_notify_hook() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                hook: Some(Addr::unchecked("hook")),
+                ..Vault::default()
+            },
+        );
+
+        let response = cancel_vault_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        assert!(response.messages.contains(
+            &get_hook_message(
+                deps.as_ref().storage,
+                &vault,
+                VaultHookNotification::VaultCancelled {
+                    refunded: vault.balance.clone(),
+                },
+            )
+            .unwrap()
+            .unwrap()
+        ));
+    }
 
     #[test]
     fn should_return_balance_to_owner() {
@@ -130,6 +176,38 @@ mod cancel_vault_tests {
         })));
     }
 
+    #[test]
+    fn with_cw20_balance_should_transfer_balance_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        save_cw20_denom(deps.as_mut().storage, "cw20-token").unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE.into(), "cw20-token"),
+                ..Vault::default()
+            },
+        );
+
+        let response = cancel_vault_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: "cw20-token".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: vault.owner.to_string(),
+                amount: ONE,
+            })
+            .unwrap(),
+            funds: vec![],
+        })));
+    }
+
     #[test]
     fn with_price_trigger_should_return_balance_to_owner() {
         let mut deps = mock_dependencies();
@@ -170,9 +248,10 @@ mod cancel_vault_tests {
 
         cancel_vault_handler(deps.as_mut(), env.clone(), info, vault.id).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(
             &EventBuilder::new(vault.id, env.block, EventData::DcaVaultCancelled {}).build(1)
@@ -0,0 +1,110 @@
+use crate::{
+    error::ContractError, helpers::coin::get_send_message,
+    state::claimable_balances::clear_claimable_balance,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, SubMsg};
+
+pub fn claim_handler(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let claimable = clear_claimable_balance(deps.storage, info.sender.clone())?;
+
+    if claimable.is_empty() {
+        return Err(ContractError::CustomError {
+            val: format!("{} has no claimable balance", info.sender),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("claim", "true")
+        .add_attribute("address", info.sender.clone())
+        .add_attribute(
+            "claimed",
+            claimable
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+        .add_submessages(claimable.into_iter().map(|coin| {
+            SubMsg::new(get_send_message(
+                deps.storage,
+                info.sender.to_string(),
+                coin,
+            ))
+        })))
+}
+
+#[cfg(test)]
+mod claim_tests {
+    use super::*;
+    use crate::{
+        handlers::get_claimable::get_claimable_handler,
+        state::claimable_balances::add_claimable_funds,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, BankMsg, Coin,
+    };
+
+    #[test]
+    fn with_no_claimable_balance_fails() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = claim_handler(deps.as_mut(), mock_info("owner", &[])).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: owner has no claimable balance".to_string()
+        );
+    }
+
+    #[test]
+    fn sends_claimable_balance_to_sender() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let funds = vec![Coin::new(100, "ukuji"), Coin::new(50, "uusk")];
+
+        add_claimable_funds(
+            deps.as_mut().storage,
+            Addr::unchecked("owner"),
+            funds.clone(),
+        )
+        .unwrap();
+
+        let response = claim_handler(deps.as_mut(), mock_info("owner", &[])).unwrap();
+
+        assert_eq!(
+            response.messages,
+            funds
+                .into_iter()
+                .map(|coin| SubMsg::new(BankMsg::Send {
+                    to_address: "owner".to_string(),
+                    amount: vec![coin],
+                }))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn clears_claimable_balance() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        add_claimable_funds(
+            deps.as_mut().storage,
+            Addr::unchecked("owner"),
+            vec![Coin::new(100, "ukuji")],
+        )
+        .unwrap();
+
+        claim_handler(deps.as_mut(), mock_info("owner", &[])).unwrap();
+
+        let claimable = get_claimable_handler(deps.as_ref(), Addr::unchecked("owner"))
+            .unwrap()
+            .claimable;
+
+        assert!(claimable.is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_fee_level_is_valid, assert_sender_is_admin},
+    state::custom_swap_fees::{get_custom_swap_fee_key, save_custom_swap_fee},
+};
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response};
+
+pub fn create_custom_swap_fee_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    paired_denom: Option<String>,
+    swap_fee_percent: Decimal,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_fee_level_is_valid(&swap_fee_percent)?;
+
+    let key = get_custom_swap_fee_key(denom, paired_denom);
+
+    save_custom_swap_fee(deps.storage, key.clone(), swap_fee_percent)?;
+
+    Ok(Response::new()
+        .add_attribute("create_custom_swap_fee", "true")
+        .add_attribute("key", key)
+        .add_attribute("swap_fee_percent", swap_fee_percent.to_string()))
+}
+
+#[cfg(test)]
+mod create_custom_swap_fee_tests {
+    use super::*;
+    use crate::{
+        handlers::get_custom_swap_fees::get_custom_swap_fees_handler,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info("not-admin", &[]),
+            "ukuji".to_string(),
+            None,
+            Decimal::zero(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_fee_larger_than_5_percent_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "ukuji".to_string(),
+            None,
+            Decimal::percent(6),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: fee level cannot be larger than 5%");
+    }
+
+    #[test]
+    fn saves_denom_and_pair_fees() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "ukuji".to_string(),
+            None,
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        create_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "uusk".to_string(),
+            Some("ukuji".to_string()),
+            Decimal::zero(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_custom_swap_fees_handler(deps.as_ref())
+                .unwrap()
+                .custom_fees,
+            vec![
+                ("ukuji".to_string(), Decimal::percent(1)),
+                ("ukuji+uusk".to_string(), Decimal::zero()),
+            ]
+        );
+    }
+}
@@ -1,30 +1,41 @@
 use crate::constants::{AFTER_LIMIT_ORDER_PLACED_REPLY_ID, TWO_MICRONS};
 use crate::error::ContractError;
 use crate::helpers::message::get_attribute_in_event;
+use crate::helpers::reply::{get_vault_id_from_reply_id, get_vault_reply_id};
 use crate::helpers::validation::{
-    assert_address_is_valid, assert_contract_destination_callbacks_are_valid,
+    assert_address_is_valid, assert_allowance_funded_vault_is_valid,
+    assert_allowance_swap_denom_is_valid, assert_contract_destination_callbacks_are_valid,
     assert_contract_is_not_paused, assert_destination_allocations_add_up_to_one,
     assert_destination_callback_addresses_are_valid, assert_destinations_limit_is_not_breached,
-    assert_exactly_one_asset, assert_label_is_no_longer_than_100_characters,
-    assert_no_destination_allocations_are_zero, assert_route_exists_for_denoms,
-    assert_slippage_tolerance_is_less_than_or_equal_to_one,
+    assert_end_time_is_after_start_time, assert_exactly_one_asset,
+    assert_exit_conditions_are_valid, assert_ibc_transfer_destinations_are_valid,
+    assert_label_is_no_longer_than_100_characters,
+    assert_max_executions_is_greater_than_execution_count,
+    assert_maximum_receive_amount_is_greater_than_minimum_receive_amount,
+    assert_no_destination_allocations_are_zero, assert_price_band_is_valid,
+    assert_route_exists_for_denoms, assert_slippage_tolerance_is_less_than_or_equal_to_one,
     assert_swap_adjustment_and_performance_assessment_strategies_are_compatible,
     assert_swap_adjustment_strategy_params_are_valid, assert_swap_amount_is_greater_than_50000,
     assert_target_start_time_is_not_in_the_past, assert_time_interval_is_valid,
-    assert_weighted_scale_multiplier_is_no_more_than_10,
+    assert_vault_destinations_are_valid, assert_weighted_scale_multiplier_is_no_more_than_10,
+    asset_sender_is_vault_owner,
 };
 use crate::helpers::vault::get_risk_weighted_average_model_id;
 use crate::msg::ExecuteMsg;
-use crate::state::cache::VAULT_ID_CACHE;
+use crate::state::base_denoms::get_base_denom_config;
 use crate::state::config::get_config;
+use crate::state::cw20_denoms::is_cw20_denom;
 use crate::state::events::create_event;
 use crate::state::triggers::save_trigger;
 use crate::state::vaults::{save_vault, update_vault};
 use crate::types::destination::Destination;
 use crate::types::event::{EventBuilder, EventData};
+use crate::types::exit_condition::ExitCondition;
+use crate::types::funding_mode::FundingMode;
 use crate::types::performance_assessment_strategy::{
     PerformanceAssessmentStrategy, PerformanceAssessmentStrategyParams,
 };
+use crate::types::price_band::PriceBand;
 use crate::types::swap_adjustment_strategy::{
     SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
@@ -35,6 +46,7 @@ use cosmwasm_std::{to_json_binary, Addr, Binary, Coin, Decimal, Reply, SubMsg, W
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Timestamp, Uint128, Uint64};
 use exchange::msg::ExecuteMsg as ExchangeExecuteMsg;
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_vault_handler(
     deps: DepsMut,
     env: Env,
@@ -52,17 +64,59 @@ pub fn create_vault_handler(
     target_receive_amount: Option<Uint128>,
     performance_assessment_strategy_params: Option<PerformanceAssessmentStrategyParams>,
     swap_adjustment_strategy_params: Option<SwapAdjustmentStrategyParams>,
+    end_time_utc_seconds: Option<Uint64>,
+    max_executions: Option<u64>,
+    stop_loss: Option<ExitCondition>,
+    take_profit: Option<ExitCondition>,
+    maximum_receive_amount: Option<Uint128>,
+    price_band: Option<PriceBand>,
+    referrer: Option<Addr>,
+    hook: Option<Addr>,
+    funding_mode: Option<FundingMode>,
 ) -> Result<Response, ContractError> {
     assert_contract_is_not_paused(deps.storage)?;
     assert_address_is_valid(deps.as_ref(), &owner, "owner")?;
-    assert_exactly_one_asset(info.funds.clone())?;
+
+    let funding_mode = funding_mode.unwrap_or_default();
+
+    let deposit = match &funding_mode {
+        FundingMode::Deposit => {
+            assert_exactly_one_asset(info.funds.clone())?;
+            info.funds[0].clone()
+        }
+        FundingMode::Allowance { swap_denom } => {
+            asset_sender_is_vault_owner(owner.clone(), info.sender.clone())?;
+
+            assert_allowance_funded_vault_is_valid(
+                info.funds.clone(),
+                &target_receive_amount,
+                &performance_assessment_strategy_params,
+            )?;
+
+            assert_allowance_swap_denom_is_valid(deps.as_ref(), swap_denom)?;
+
+            Coin::new(0, swap_denom.clone())
+        }
+        FundingMode::StakingRewards => {
+            asset_sender_is_vault_owner(owner.clone(), info.sender.clone())?;
+
+            assert_allowance_funded_vault_is_valid(
+                info.funds.clone(),
+                &target_receive_amount,
+                &performance_assessment_strategy_params,
+            )?;
+
+            Coin::new(0, deps.querier.query_bonded_denom()?)
+        }
+    };
+
     assert_swap_amount_is_greater_than_50000(swap_amount)?;
     assert_destinations_limit_is_not_breached(&destinations)?;
     assert_time_interval_is_valid(&time_interval)?;
 
     assert_route_exists_for_denoms(
         deps.as_ref(),
-        info.funds[0].denom.clone(),
+        deposit.denom.clone(),
         target_denom.clone(),
         route.clone(),
     )?;
@@ -91,22 +145,62 @@ pub fn create_vault_handler(
         )?;
     }
 
+    if let Some(end_time) = end_time_utc_seconds {
+        assert_end_time_is_after_start_time(
+            target_start_time_utc_seconds.map_or(env.block.time, |start_time| {
+                Timestamp::from_seconds(start_time.u64())
+            }),
+            Timestamp::from_seconds(end_time.u64()),
+        )?;
+    }
+
+    if let Some(max_executions) = max_executions {
+        assert_max_executions_is_greater_than_execution_count(max_executions, 0)?;
+    }
+
+    assert_maximum_receive_amount_is_greater_than_minimum_receive_amount(
+        minimum_receive_amount,
+        maximum_receive_amount,
+    )?;
+
+    if let Some(price_band) = &price_band {
+        assert_price_band_is_valid(price_band)?;
+    }
+
+    if let Some(referrer) = &referrer {
+        assert_address_is_valid(deps.as_ref(), referrer, "referrer")?;
+
+        if *referrer == owner {
+            return Err(ContractError::CustomError {
+                val: "referrer cannot be the vault owner".to_string(),
+            });
+        }
+    }
+
+    if let Some(hook) = &hook {
+        assert_address_is_valid(deps.as_ref(), hook, "hook")?;
+    }
+
     if destinations.is_empty() {
         destinations.push(Destination {
             allocation: Decimal::percent(100),
             address: owner.clone(),
             msg: None,
+            ibc_transfer: None,
+            vault_id: None,
         });
     }
 
     assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
     assert_contract_destination_callbacks_are_valid(&destinations, &env.contract.address)?;
+    assert_ibc_transfer_destinations_are_valid(deps.storage, &destinations, &target_denom)?;
+    assert_vault_destinations_are_valid(deps.storage, &destinations, &target_denom, None)?;
     assert_no_destination_allocations_are_zero(&destinations)?;
     assert_destination_allocations_add_up_to_one(&destinations)?;
 
     let config = get_config(deps.storage)?;
 
-    let swap_denom = info.funds[0].denom.clone();
+    let swap_denom = deposit.denom.clone();
 
     let swap_adjustment_strategy = match swap_adjustment_strategy_params {
         Some(params) => Some(match params {
@@ -116,9 +210,10 @@ pub fn create_vault_handler(
             } => SwapAdjustmentStrategy::RiskWeightedAverage {
                 model_id: get_risk_weighted_average_model_id(
                     &env.block.time,
-                    &info.funds[0],
+                    &deposit,
                     &swap_amount,
                     &time_interval,
+                    &get_base_denom_config(deps.storage, &base_denom)?.model_id_buckets,
                 ),
                 base_denom,
                 position_type,
@@ -135,10 +230,25 @@ pub fn create_vault_handler(
                     increase_only,
                 }
             }
+            SwapAdjustmentStrategyParams::ValueAveraging {
+                target_growth_per_execution,
+            } => SwapAdjustmentStrategy::ValueAveraging {
+                target_growth_per_execution,
+                target_value: Uint128::zero(),
+            },
+            SwapAdjustmentStrategyParams::MovingAverage {
+                periods,
+                multiplier,
+            } => SwapAdjustmentStrategy::MovingAverage {
+                periods,
+                multiplier,
+            },
         }),
         None => None,
     };
 
+    assert_exit_conditions_are_valid(&stop_loss, &take_profit, &swap_adjustment_strategy)?;
+
     let performance_assessment_strategy = match performance_assessment_strategy_params {
         Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
             Some(PerformanceAssessmentStrategy::CompareToStandardDca {
@@ -149,11 +259,16 @@ pub fn create_vault_handler(
         _ => None,
     };
 
-    let escrow_level = performance_assessment_strategy
-        .clone()
-        .map_or(Decimal::zero(), |_| {
-            config.risk_weighted_average_escrow_level
-        });
+    let escrow_level = match &swap_adjustment_strategy {
+        Some(SwapAdjustmentStrategy::RiskWeightedAverage { base_denom, .. }) => {
+            get_base_denom_config(deps.storage, base_denom)?.escrow_level
+        }
+        _ => performance_assessment_strategy
+            .clone()
+            .map_or(Decimal::zero(), |_| {
+                config.risk_weighted_average_escrow_level
+            }),
+    };
 
     let vault_builder = VaultBuilder {
         owner,
@@ -166,41 +281,50 @@ pub fn create_vault_handler(
         route,
         slippage_tolerance: slippage_tolerance.unwrap_or(config.default_slippage_tolerance),
         minimum_receive_amount,
-        balance: info.funds[0].clone(),
+        balance: deposit.clone(),
         time_interval,
         started_at: None,
         escrow_level,
-        deposited_amount: info.funds[0].clone(),
+        deposited_amount: deposit.clone(),
         swapped_amount: Coin::new(0, swap_denom),
         received_amount: Coin::new(0, target_denom.clone()),
         escrowed_amount: Coin::new(0, target_denom),
         swap_adjustment_strategy,
         performance_assessment_strategy,
+        end_time: end_time_utc_seconds.map(|seconds| Timestamp::from_seconds(seconds.u64())),
+        max_executions,
+        stop_loss,
+        take_profit,
+        maximum_receive_amount,
+        price_band,
+        referrer,
+        hook,
+        funding_mode,
     };
 
     let vault = save_vault(deps.storage, vault_builder)?;
 
-    VAULT_ID_CACHE.save(deps.storage, &vault.id)?;
-
-    create_event(
-        deps.storage,
-        EventBuilder::new(
-            vault.id,
-            env.block.clone(),
-            EventData::DcaVaultFundsDeposited {
-                amount: Coin::new(
-                    (info.funds[0].amount
-                        - if target_receive_amount.is_some() {
-                            TWO_MICRONS
-                        } else {
-                            Uint128::zero()
-                        })
-                    .into(),
-                    info.funds[0].denom.clone(),
-                ),
-            },
-        ),
-    )?;
+    if !vault.is_allowance_funded() {
+        create_event(
+            deps.storage,
+            EventBuilder::new(
+                vault.id,
+                env.block.clone(),
+                EventData::DcaVaultFundsDeposited {
+                    amount: Coin::new(
+                        (deposit.amount
+                            - if target_receive_amount.is_some() {
+                                TWO_MICRONS
+                            } else {
+                                Uint128::zero()
+                            })
+                        .into(),
+                        deposit.denom.clone(),
+                    ),
+                },
+            ),
+        )?;
+    }
 
     let mut response = Response::new()
         .add_attribute("create_vault", "true")
@@ -238,6 +362,14 @@ pub fn create_vault_handler(
             Ok(response)
         }
         (None, Some(target_receive_amount)) => {
+            if is_cw20_denom(deps.storage, &vault.get_swap_denom()) {
+                return Err(ContractError::CustomError {
+                    val: String::from(
+                        "cannot provide a target_receive_amount for a cw20 swap denom",
+                    ),
+                });
+            }
+
             let vault = update_vault(
                 deps.storage,
                 Vault {
@@ -265,7 +397,7 @@ pub fn create_vault_handler(
                     .unwrap(),
                     funds: vec![Coin::new(TWO_MICRONS.into(), vault.get_swap_denom())],
                 },
-                AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
+                get_vault_reply_id(AFTER_LIMIT_ORDER_PLACED_REPLY_ID, vault.id),
             )))
         }
         (Some(_), Some(_)) => Err(ContractError::CustomError {
@@ -277,6 +409,7 @@ pub fn create_vault_handler(
 }
 
 pub fn save_price_trigger(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+    let vault_id = get_vault_id_from_reply_id(reply.id);
     let submit_order_response = reply.result.into_result().unwrap();
 
     let order_idx = get_attribute_in_event(&submit_order_response.events, "wasm", "order_idx")?
@@ -288,8 +421,6 @@ pub fn save_price_trigger(deps: DepsMut, reply: Reply) -> Result<Response, Contr
             .parse::<Decimal>()
             .expect("the target price of the submitted order");
 
-    let vault_id = VAULT_ID_CACHE.load(deps.storage)?;
-
     save_trigger(
         deps.storage,
         Trigger {
@@ -312,24 +443,30 @@ mod create_vault_tests {
     use crate::constants::{ONE, TEN};
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::handlers::get_vault::get_vault_handler;
+    use crate::handlers::register_base_denom::register_base_denom_handler;
     use crate::msg::ExecuteMsg;
+    use crate::state::base_denoms::ModelIdBucket;
     use crate::state::config::{get_config, update_config};
-    use crate::tests::helpers::instantiate_contract;
+    use crate::state::cw20_denoms::save_cw20_denom;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
     use crate::tests::mocks::{
         calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER, VALIDATOR,
     };
     use crate::types::config::Config;
-    use crate::types::destination::Destination;
+    use crate::types::destination::{Destination, IbcTransfer};
     use crate::types::event::{EventBuilder, EventData};
-    use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+    use crate::types::exit_condition::ExitAction;
+    use crate::types::position_type::PositionType;
+    use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
     use crate::types::time_interval::TimeInterval;
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{
-        to_json_binary, Addr, Coin, ContractResult, Decimal, Decimal256, SubMsg, SystemResult,
-        Timestamp, Uint128, WasmMsg,
+        to_json_binary, Addr, Coin, ContractResult, Decimal, Decimal256, SubMsg, SystemError,
+        SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
     };
+    use cw20::TokenInfoResponse;
     use exchange::msg::Pair;
 
     #[test]
@@ -357,6 +494,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -394,6 +540,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -404,18 +559,12 @@ mod create_vault_tests {
     }
 
     #[test]
-    fn with_non_existent_pair_fails() {
+    fn with_allowance_funding_mode_and_funds_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
-
-        deps.querier.update_wasm(|_| {
-            SystemResult::Ok(ContractResult::Ok(
-                to_json_binary::<Vec<Pair>>(&vec![]).unwrap(),
-            ))
-        });
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let err = create_vault_handler(
             deps.as_mut(),
@@ -434,38 +583,46 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::Allowance {
+                swap_denom: DENOM_UUSK.to_string(),
+            }),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            format!(
-                "Error: swapping {} to {} not supported",
-                DENOM_UUSK, DENOM_UKUJI
-            )
+            "Error: cannot send funds when creating an allowance funded vault"
         );
     }
 
     #[test]
-    fn with_destination_allocations_less_than_100_percent_fails() {
+    fn with_allowance_funding_mode_for_another_owner_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+        let info = mock_info("attacker", &[]);
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let err = create_vault_handler(
             deps.as_mut(),
             env,
-            &user_info,
-            user_info.sender.clone(),
+            &info,
+            Addr::unchecked(USER),
             None,
             vec![Destination {
-                allocation: Decimal::percent(50),
-                address: Addr::unchecked(USER),
+                allocation: Decimal::percent(100),
+                address: info.sender.clone(),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             }],
             DENOM_UKUJI.to_string(),
             None,
@@ -477,43 +634,39 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::Allowance {
+                swap_denom: DENOM_UUSK.to_string(),
+            }),
         )
         .unwrap_err();
 
-        assert_eq!(
-            err.to_string(),
-            "Error: destination allocations must add up to 1"
-        );
+        assert_eq!(err.to_string(), "Unauthorized");
+        assert!(get_vault_handler(deps.as_ref(), Uint128::one()).is_err());
     }
 
     #[test]
-    fn with_destination_allocation_equal_to_zero_fails() {
+    fn with_allowance_funding_mode_creates_vault_with_empty_balance() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+        let info = mock_info(USER, &[]);
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let err = create_vault_handler(
+        create_vault_handler(
             deps.as_mut(),
             env,
-            &user_info,
-            user_info.sender.clone(),
+            &info,
+            info.sender.clone(),
             None,
-            vec![
-                Destination {
-                    allocation: Decimal::percent(100),
-                    address: Addr::unchecked(USER),
-                    msg: None,
-                },
-                Destination {
-                    allocation: Decimal::percent(0),
-                    address: Addr::unchecked("other"),
-                    msg: None,
-                },
-            ],
+            vec![],
             DENOM_UKUJI.to_string(),
             None,
             None,
@@ -524,22 +677,58 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::Allowance {
+                swap_denom: DENOM_UUSK.to_string(),
+            }),
         )
-        .unwrap_err();
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
 
+        assert_eq!(vault.balance, Coin::new(0, DENOM_UUSK));
         assert_eq!(
-            err.to_string(),
-            "Error: all destination allocations must be greater than 0"
+            vault.funding_mode,
+            FundingMode::Allowance {
+                swap_denom: DENOM_UUSK.to_string()
+            }
         );
+        assert_eq!(vault.status, VaultStatus::Scheduled);
     }
 
     #[test]
-    fn with_more_than_10_destination_allocations_fails() {
+    fn with_allowance_funding_mode_and_unregistered_cw20_swap_denom_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "cw20-token" => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&TokenInfoResponse {
+                        name: "Token".to_string(),
+                        symbol: "TKN".to_string(),
+                        decimals: 6,
+                        total_supply: Uint128::new(1000000),
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "unsupported query".to_string(),
+            }),
+        });
 
         let err = create_vault_handler(
             deps.as_mut(),
@@ -547,13 +736,7 @@ mod create_vault_tests {
             &info,
             info.sender.clone(),
             None,
-            (0..20)
-                .map(|i| Destination {
-                    allocation: Decimal::percent(5),
-                    address: Addr::unchecked(format!("destination-{}", i)),
-                    msg: None,
-                })
-                .collect(),
+            vec![],
             DENOM_UKUJI.to_string(),
             None,
             None,
@@ -564,24 +747,38 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::Allowance {
+                swap_denom: "cw20-token".to_string(),
+            }),
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: no more than 10 destinations can be provided"
+            "Error: cw20 token cw20-token is not registered"
         );
+        assert!(!is_cw20_denom(deps.as_ref().storage, "cw20-token"));
     }
 
     #[test]
-    fn with_swap_amount_less_than_50000_fails() {
+    fn with_allowance_funding_mode_and_registered_cw20_swap_denom_creates_vault() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let err = create_vault_handler(
+        save_cw20_denom(deps.as_mut().storage, "cw20-token").unwrap();
+
+        create_vault_handler(
             deps.as_mut(),
             env,
             &info,
@@ -592,38 +789,56 @@ mod create_vault_tests {
             None,
             None,
             None,
-            Uint128::new(10000),
+            Uint128::new(100000),
             TimeInterval::Daily,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::Allowance {
+                swap_denom: "cw20-token".to_string(),
+            }),
         )
-        .unwrap_err();
+        .unwrap();
 
-        assert_eq!(
-            err.to_string(),
-            "Error: swap amount must be greater than 50000"
-        );
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.balance, Coin::new(0, "cw20-token"));
     }
 
     #[test]
-    fn with_too_high_weighted_scale_multiplier_fails() {
+    fn with_staking_rewards_funding_mode_for_another_owner_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
+        let info = mock_info("attacker", &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        deps.querier.update_staking(DENOM_UUSK, &[], &[]);
 
         let err = create_vault_handler(
             deps.as_mut(),
             env,
-            &user_info,
-            user_info.sender.clone(),
+            &info,
+            Addr::unchecked(USER),
             None,
-            vec![],
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: info.sender.clone(),
+                msg: None,
+                ibc_transfer: None,
+                vault_id: None,
+            }],
             DENOM_UKUJI.to_string(),
             None,
             None,
@@ -633,32 +848,775 @@ mod create_vault_tests {
             None,
             None,
             None,
-            Some(SwapAdjustmentStrategyParams::WeightedScale {
-                base_receive_amount: Uint128::new(100000),
-                multiplier: Decimal::percent(1100),
-                increase_only: false,
-            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::StakingRewards),
         )
         .unwrap_err();
 
-        assert_eq!(
-            err.to_string(),
-            "Error: Cannot set weighted scale multiplier to more than 10"
-        );
+        assert_eq!(err.to_string(), "Unauthorized");
+        assert!(get_vault_handler(deps.as_ref(), Uint128::one()).is_err());
     }
 
     #[test]
-    fn when_contract_is_paused_fails() {
+    fn with_staking_rewards_funding_mode_creates_vault_in_bonded_denom() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let config = get_config(deps.as_ref().storage).unwrap();
+        deps.querier.update_staking(DENOM_UUSK, &[], &[]);
 
-        update_config(
-            deps.as_mut().storage,
+        create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(FundingMode::StakingRewards),
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.balance, Coin::new(0, DENOM_UUSK));
+        assert_eq!(vault.funding_mode, FundingMode::StakingRewards);
+    }
+
+    #[test]
+    fn with_non_existent_pair_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary::<Vec<Pair>>(&vec![]).unwrap(),
+            ))
+        });
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: swapping {} to {} not supported",
+                DENOM_UUSK, DENOM_UKUJI
+            )
+        );
+    }
+
+    #[test]
+    fn with_destination_allocations_less_than_100_percent_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(50),
+                address: Addr::unchecked(USER),
+                msg: None,
+                ibc_transfer: None,
+                vault_id: None,
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: destination allocations must add up to 1"
+        );
+    }
+
+    #[test]
+    fn with_destination_allocation_equal_to_zero_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![
+                Destination {
+                    allocation: Decimal::percent(100),
+                    address: Addr::unchecked(USER),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: None,
+                },
+                Destination {
+                    allocation: Decimal::percent(0),
+                    address: Addr::unchecked("other"),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: None,
+                },
+            ],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: all destination allocations must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_invalid_ibc_transfer_channel_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked("osmo1receiver"),
+                msg: None,
+                ibc_transfer: Some(IbcTransfer {
+                    channel_id: "transfer/3".to_string(),
+                    memo: None,
+                    timeout_seconds: None,
+                }),
+                vault_id: None,
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: ibc channel id transfer/3 is invalid"
+        );
+    }
+
+    #[test]
+    fn with_non_json_ibc_transfer_memo_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked("osmo1receiver"),
+                msg: None,
+                ibc_transfer: Some(IbcTransfer {
+                    channel_id: "channel-3".to_string(),
+                    memo: Some("hello".to_string()),
+                    timeout_seconds: None,
+                }),
+                vault_id: None,
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: ibc transfer memo must be a json object"
+        );
+    }
+
+    #[test]
+    fn with_ibc_callback_in_ibc_transfer_memo_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: Addr::unchecked("osmo1receiver"),
+                msg: None,
+                ibc_transfer: Some(IbcTransfer {
+                    channel_id: "channel-3".to_string(),
+                    memo: Some("{\"ibc_callback\":\"other\"}".to_string()),
+                    timeout_seconds: None,
+                }),
+                vault_id: None,
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: ibc transfer memo cannot include an ibc_callback field"
+        );
+    }
+
+    #[test]
+    fn with_vault_destination_with_mismatched_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(TEN.into(), DENOM_UUSK),
+                ..Vault::default()
+            },
+        );
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: destination_vault.owner.clone(),
+                msg: None,
+                ibc_transfer: None,
+                vault_id: Some(destination_vault.id),
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: destination vault {} swap denom uusk does not match target denom ukuji",
+                destination_vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_cancelled_vault_destination_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                balance: Coin::new(0, DENOM_UKUJI),
+                ..Vault::default()
+            },
+        );
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![Destination {
+                allocation: Decimal::percent(100),
+                address: destination_vault.owner.clone(),
+                msg: None,
+                ibc_transfer: None,
+                vault_id: Some(destination_vault.id),
+            }],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: destination vault {} is cancelled",
+                destination_vault.id
+            )
+        );
+    }
+
+    #[test]
+    fn with_more_than_10_destination_allocations_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            (0..20)
+                .map(|i| Destination {
+                    allocation: Decimal::percent(5),
+                    address: Addr::unchecked(format!("destination-{}", i)),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: None,
+                })
+                .collect(),
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no more than 10 destinations can be provided"
+        );
+    }
+
+    #[test]
+    fn with_swap_amount_less_than_50000_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(10000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap amount must be greater than 50000"
+        );
+    }
+
+    #[test]
+    fn with_too_high_weighted_scale_multiplier_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::WeightedScale {
+                base_receive_amount: Uint128::new(100000),
+                multiplier: Decimal::percent(1100),
+                increase_only: false,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Cannot set weighted scale multiplier to more than 10"
+        );
+    }
+
+    #[test]
+    fn with_zero_value_averaging_target_growth_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::ValueAveraging {
+                target_growth_per_execution: Uint128::zero(),
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Value averaging target growth per execution must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_too_few_moving_average_periods_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            Some(SwapAdjustmentStrategyParams::MovingAverage {
+                periods: 1,
+                multiplier: Decimal::one(),
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: Moving average periods must be between 2 and 100"
+        );
+    }
+
+    #[test]
+    fn when_contract_is_paused_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
             Config {
                 paused: true,
                 ..config
@@ -683,36 +1641,575 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: contract is paused")
+    }
+
+    #[test]
+    fn with_time_trigger_with_target_time_in_the_past_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let admin_info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+
+        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &user_info,
+            user_info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.minus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: target_start_time_utc_seconds must be some time in the future"
+        );
+    }
+
+    #[test]
+    fn with_invalid_custom_time_interval_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Custom { seconds: 23 },
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: custom time interval must be at least 60 seconds"
+        );
+    }
+
+    #[test]
+    fn with_invalid_cron_time_interval_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Cron {
+                expression: "0 0 31 2 *".to_string(),
+            },
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cron expression 0 0 31 2 * never matches a valid date"
+        );
+    }
+
+    #[test]
+    fn with_end_time_before_target_start_time_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(100).seconds().into()),
+            None,
+            None,
+            None,
+            Some(env.block.time.plus_seconds(50).seconds().into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: end_time_utc_seconds must be after the vault start time and the current time"
+        );
+    }
+
+    #[test]
+    fn with_end_time_in_the_past_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            Some(env.block.time.minus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: end_time_utc_seconds must be after the vault start time and the current time"
+        );
+    }
+
+    #[test]
+    fn with_zero_max_executions_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: max_executions must be greater than the current execution count of 0"
+        );
+    }
+
+    #[test]
+    fn should_save_end_conditions() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+            Some(env.block.time.plus_seconds(1000).seconds().into()),
+            Some(12),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.end_time,
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(1000).seconds()
+            ))
+        );
+        assert_eq!(vault.max_executions, Some(12));
+        assert_eq!(vault.execution_count, 0);
+    }
+
+    #[test]
+    fn with_stop_loss_price_above_take_profit_price_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(120),
+                action: ExitAction::CancelVault,
+            }),
+            Some(ExitCondition {
+                price: Decimal::percent(110),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: stop loss price must be less than take profit price"
+        );
+    }
+
+    #[test]
+    fn with_zero_stop_loss_price_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(0),
+                action: ExitAction::CancelVault,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: stop loss and take profit prices must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn should_save_exit_conditions() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::RiskWeightedAverage {
+                base_denom: BaseDenom::Bitcoin,
+                position_type: PositionType::Exit,
+            }),
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            }),
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.stop_loss,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            })
+        );
+        assert_eq!(
+            vault.take_profit,
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            })
+        );
+    }
+
+    #[test]
+    fn with_exit_conditions_on_non_exit_position_vault_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            }),
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
-        assert_eq!(err.to_string(), "Error: contract is paused")
+        assert_eq!(
+            err.to_string(),
+            "Error: stop loss and take profit can only be set on exit position vaults"
+        );
     }
 
     #[test]
-    fn with_time_trigger_with_target_time_in_the_past_fails() {
+    fn with_maximum_receive_amount_not_greater_than_minimum_receive_amount_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let admin_info = mock_info(ADMIN, &[]);
-
-        instantiate_contract(deps.as_mut(), env.clone(), admin_info);
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
 
-        let user_info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let err = create_vault_handler(
             deps.as_mut(),
             env.clone(),
-            &user_info,
-            user_info.sender.clone(),
+            &info,
+            info.sender.clone(),
             None,
             vec![],
             DENOM_UKUJI.to_string(),
             None,
             None,
-            None,
+            Some(Uint128::new(1000)),
             Uint128::new(100000),
             TimeInterval::Daily,
-            Some(env.block.time.minus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::new(1000)),
+            None,
             None,
             None,
             None,
@@ -721,21 +2218,21 @@ mod create_vault_tests {
 
         assert_eq!(
             err.to_string(),
-            "Error: target_start_time_utc_seconds must be some time in the future"
+            "Error: maximum receive amount must be greater than minimum receive amount"
         );
     }
 
     #[test]
-    fn with_invalid_custom_time_interval_fails() {
+    fn with_price_band_lower_deviation_of_one_fails() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
-        let info = mock_info(USER, &[Coin::new(10000, DENOM_UUSK)]);
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
         let err = create_vault_handler(
             deps.as_mut(),
-            env,
+            env.clone(),
             &info,
             info.sender.clone(),
             None,
@@ -745,20 +2242,170 @@ mod create_vault_tests {
             None,
             None,
             Uint128::new(100000),
-            TimeInterval::Custom { seconds: 23 },
+            TimeInterval::Daily,
+            None,
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            Some(PriceBand {
+                lower_deviation: Decimal::percent(100),
+                upper_deviation: Decimal::percent(10),
+            }),
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Error: custom time interval must be at least 60 seconds"
+            "Error: price band lower deviation must be less than 1"
+        );
+    }
+
+    #[test]
+    fn should_save_maximum_receive_amount_and_price_band() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::new(1000)),
+            Some(PriceBand {
+                lower_deviation: Decimal::percent(5),
+                upper_deviation: Decimal::percent(10),
+            }),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.maximum_receive_amount, Some(Uint128::new(1000)));
+        assert_eq!(
+            vault.price_band,
+            Some(PriceBand {
+                lower_deviation: Decimal::percent(5),
+                upper_deviation: Decimal::percent(10),
+            })
         );
     }
 
+    #[test]
+    fn should_save_referrer() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Addr::unchecked("referrer")),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.referrer, Some(Addr::unchecked("referrer")));
+    }
+
+    #[test]
+    fn with_owner_as_referrer_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env,
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(info.sender.clone()),
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: referrer cannot be the vault owner");
+    }
+
     #[test]
     fn with_both_target_time_and_target_price_fails() {
         let mut deps = calc_mock_dependencies();
@@ -786,6 +2433,15 @@ mod create_vault_tests {
             Some(Uint128::new(872316)),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -823,6 +2479,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -860,6 +2525,15 @@ mod create_vault_tests {
             None,
             None,
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -901,6 +2575,15 @@ mod create_vault_tests {
                 multiplier: Decimal::percent(1001),
                 increase_only: false,
             }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -938,6 +2621,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -975,6 +2667,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1008,6 +2709,18 @@ mod create_vault_tests {
                 escrowed_amount: Coin::new(0, DENOM_UKUJI.to_string()),
                 swap_adjustment_strategy: None,
                 performance_assessment_strategy: None,
+                pending_owner: None,
+                end_time: None,
+                max_executions: None,
+                execution_count: 0,
+                stop_loss: None,
+                take_profit: None,
+                maximum_receive_amount: None,
+                price_band: None,
+                referrer: None,
+                hook: None,
+                funding_mode: FundingMode::Deposit,
+                performance_fee_high_water_mark: Uint128::zero(),
                 trigger: Some(TriggerConfiguration::Time {
                     target_time: Timestamp::from_seconds(env.block.time.plus_seconds(10).seconds()),
                 }),
@@ -1043,6 +2756,15 @@ mod create_vault_tests {
             Some(ONE / TWO_MICRONS),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1082,6 +2804,18 @@ mod create_vault_tests {
                 escrowed_amount: Coin::new(0, DENOM_UKUJI.to_string()),
                 swap_adjustment_strategy: None,
                 performance_assessment_strategy: None,
+                pending_owner: None,
+                end_time: None,
+                max_executions: None,
+                execution_count: 0,
+                stop_loss: None,
+                take_profit: None,
+                maximum_receive_amount: None,
+                price_band: None,
+                referrer: None,
+                hook: None,
+                funding_mode: FundingMode::Deposit,
+                performance_fee_high_water_mark: Uint128::zero(),
                 trigger: None,
             }
         );
@@ -1114,13 +2848,28 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert!(events.contains(
             &EventBuilder::new(
@@ -1162,6 +2911,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1193,6 +2951,8 @@ mod create_vault_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 allocation: Decimal::percent(50),
@@ -1204,6 +2964,8 @@ mod create_vault_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -1224,6 +2986,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1261,6 +3032,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1305,6 +3085,15 @@ mod create_vault_tests {
                 multiplier: Decimal::percent(200),
                 increase_only: false,
             }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1342,6 +3131,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1385,6 +3183,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1430,6 +3237,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1477,6 +3293,15 @@ mod create_vault_tests {
             Some(ONE / TWO_MICRONS),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1494,7 +3319,7 @@ mod create_vault_tests {
                     })
                     .unwrap()
                 },
-                AFTER_LIMIT_ORDER_PLACED_REPLY_ID
+                get_vault_reply_id(AFTER_LIMIT_ORDER_PLACED_REPLY_ID, Uint128::one())
             )
         );
     }
@@ -1526,6 +3351,15 @@ mod create_vault_tests {
             None,
             Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
             Some(SwapAdjustmentStrategyParams::default()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1541,6 +3375,123 @@ mod create_vault_tests {
         );
     }
 
+    #[test]
+    fn with_unregistered_base_denom_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        let err = create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::RiskWeightedAverage {
+                base_denom: BaseDenom::Custom("ethereum".to_string()),
+                position_type: PositionType::Enter,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: base denom Custom(\"ethereum\") is not registered"
+        );
+    }
+
+    #[test]
+    fn should_use_registered_base_denom_model_id_and_escrow_level() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let mut info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        register_base_denom_handler(
+            deps.as_mut(),
+            info,
+            BaseDenom::Custom("ethereum".to_string()),
+            vec![ModelIdBucket {
+                max_days: 365,
+                model_id: 75,
+            }],
+            Decimal::percent(12),
+        )
+        .unwrap();
+
+        info = mock_info(USER, &[Coin::new(100000, DENOM_UUSK)]);
+
+        create_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            &info,
+            info.sender.clone(),
+            None,
+            vec![],
+            DENOM_UKUJI.to_string(),
+            None,
+            None,
+            None,
+            Uint128::new(100000),
+            TimeInterval::Daily,
+            Some(env.block.time.plus_seconds(10).seconds().into()),
+            None,
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca),
+            Some(SwapAdjustmentStrategyParams::RiskWeightedAverage {
+                base_denom: BaseDenom::Custom("ethereum".to_string()),
+                position_type: PositionType::Enter,
+            }),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(
+            vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                model_id: 75,
+                base_denom: BaseDenom::Custom("ethereum".to_string()),
+                position_type: PositionType::Enter,
+            })
+        );
+        assert_eq!(vault.escrow_level, Decimal::percent(12));
+    }
+
     #[test]
     fn should_set_appropriate_escrow_level_for_no_performance_assessment_strategy() {
         let mut deps = calc_mock_dependencies();
@@ -1568,6 +3519,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1603,6 +3563,8 @@ mod create_vault_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             }],
             DENOM_UKUJI.to_string(),
             None,
@@ -1614,6 +3576,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -1649,6 +3620,8 @@ mod create_vault_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             }],
             DENOM_UKUJI.to_string(),
             None,
@@ -1660,6 +3633,15 @@ mod create_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1679,6 +3661,8 @@ mod create_vault_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             }]
         );
     }
@@ -1688,7 +3672,9 @@ mod create_vault_tests {
 mod save_limit_order_id_tests {
     use super::save_price_trigger;
     use crate::{
-        state::{cache::VAULT_ID_CACHE, triggers::get_trigger},
+        constants::AFTER_LIMIT_ORDER_PLACED_REPLY_ID,
+        helpers::reply::get_vault_reply_id,
+        state::triggers::get_trigger,
         types::trigger::{Trigger, TriggerConfiguration},
     };
     use cosmwasm_std::{
@@ -1702,12 +3688,8 @@ mod save_limit_order_id_tests {
         let vault_id = Uint128::one();
         let order_idx = Uint128::new(67);
 
-        VAULT_ID_CACHE
-            .save(deps.as_mut().storage, &vault_id)
-            .unwrap();
-
         let reply = Reply {
-            id: 1,
+            id: get_vault_reply_id(AFTER_LIMIT_ORDER_PLACED_REPLY_ID, vault_id),
             result: SubMsgResult::Ok(SubMsgResponse {
                 events: vec![Event::new("wasm")
                     .add_attribute("order_idx", order_idx.to_string())
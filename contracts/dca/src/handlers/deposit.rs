@@ -1,21 +1,13 @@
 use crate::error::ContractError;
-use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{
     assert_contract_is_not_paused, assert_deposited_denom_matches_send_denom,
     assert_exactly_one_asset, assert_vault_is_not_cancelled,
 };
-use crate::helpers::vault::get_risk_weighted_average_model_id;
-use crate::state::events::create_event;
-use crate::state::triggers::save_trigger;
-use crate::state::vaults::{get_vault, update_vault};
-use crate::types::event::{EventBuilder, EventData};
-use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
-use crate::types::trigger::{Trigger, TriggerConfiguration};
-use crate::types::vault::{Vault, VaultStatus};
+use crate::helpers::vault::deposit_into_vault;
+use crate::state::vaults::get_vault;
 use cosmwasm_std::{Addr, Env};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{DepsMut, MessageInfo, Response, Uint128};
-use shared::coin::add;
 
 pub fn deposit_handler(
     deps: DepsMut,
@@ -45,68 +37,7 @@ pub fn deposit_handler(
         vault.balance.denom.clone(),
     )?;
 
-    let vault_was_inactive = vault.is_inactive();
-    let new_balance = add(vault.balance.clone(), info.funds[0].clone())?;
-
-    let vault = update_vault(
-        deps.storage,
-        Vault {
-            balance: new_balance.clone(),
-            deposited_amount: add(vault.deposited_amount.clone(), info.funds[0].clone())?,
-            status: if vault.is_inactive() {
-                VaultStatus::Active
-            } else {
-                vault.status
-            },
-            swap_adjustment_strategy: vault.swap_adjustment_strategy.clone().map(
-                |swap_adjustment_strategy| match swap_adjustment_strategy {
-                    SwapAdjustmentStrategy::RiskWeightedAverage {
-                        base_denom,
-                        position_type,
-                        ..
-                    } => SwapAdjustmentStrategy::RiskWeightedAverage {
-                        model_id: get_risk_weighted_average_model_id(
-                            &env.block.time,
-                            &new_balance,
-                            &vault.swap_amount,
-                            &vault.time_interval,
-                        ),
-                        base_denom,
-                        position_type,
-                    },
-                    _ => swap_adjustment_strategy,
-                },
-            ),
-            ..vault
-        },
-    )?;
-
-    create_event(
-        deps.storage,
-        EventBuilder::new(
-            vault.id,
-            env.block.clone(),
-            EventData::DcaVaultFundsDeposited {
-                amount: info.funds[0].clone(),
-            },
-        ),
-    )?;
-
-    if vault.is_active() && vault_was_inactive && vault.trigger.is_none() {
-        save_trigger(
-            deps.storage,
-            Trigger {
-                vault_id,
-                configuration: TriggerConfiguration::Time {
-                    target_time: get_next_target_time(
-                        env.block.time,
-                        vault.started_at.unwrap_or(env.block.time),
-                        vault.time_interval,
-                    ),
-                },
-            },
-        )?;
-    };
+    let vault = deposit_into_vault(deps.storage, &env, vault, info.funds[0].clone())?;
 
     Ok(Response::new()
         .add_attribute("deposit", "true")
@@ -131,7 +62,7 @@ mod dposit_tests {
     use crate::types::vault::{Vault, VaultStatus};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::{Addr, Coin};
-    use shared::coin::subtract;
+    use shared::coin::{add, subtract};
 
     #[test]
     fn updates_the_vault_balance() {
@@ -175,9 +106,10 @@ mod dposit_tests {
 
         deposit_handler(deps.as_mut(), env.clone(), info, vault.owner, vault.id).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(
             &EventBuilder::new(
@@ -2,24 +2,30 @@ use crate::{
     error::ContractError,
     helpers::{
         disbursement::get_disbursement_messages,
-        fees::{get_fee_messages, get_performance_fee},
+        fees::{get_added_value, get_fee_messages, get_performance_fee},
+        hooks::get_hook_message,
         price::get_twap_to_now,
         validation::assert_sender_is_executor,
     },
     state::{
-        cache::VAULT_ID_CACHE,
         config::get_config,
-        disburse_escrow_tasks::{delete_disburse_escrow_task, get_disburse_escrow_task_due_date},
+        disburse_escrow_tasks::{
+            delete_disburse_escrow_task, get_disburse_escrow_task_due_date,
+            save_disburse_escrow_task,
+        },
         events::create_event,
         vaults::{get_vault, update_vault},
     },
     types::{
+        config::Config,
         event::{EventBuilder, EventData},
+        hook::VaultHookNotification,
         vault::Vault,
     },
 };
-use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, StdResult, Storage, Uint128};
 use shared::coin::{empty_of, subtract};
+use std::cmp::max;
 
 pub fn disburse_escrow_handler(
     deps: DepsMut,
@@ -36,7 +42,11 @@ pub fn disburse_escrow_handler(
         .add_attribute("vault_id", vault.id)
         .add_attribute("owner", vault.owner.clone());
 
+    let config = get_config(deps.storage)?;
+
     if vault.escrowed_amount.amount.is_zero() {
+        delete_disburse_escrow_task(deps.storage, vault.id)?;
+
         return Ok(response
             .add_attribute(
                 "performance_fee",
@@ -58,8 +68,6 @@ pub fn disburse_escrow_handler(
         }
     }
 
-    let config = get_config(deps.storage)?;
-
     let current_price = get_twap_to_now(
         &deps.querier,
         config.exchange_contract_address.clone(),
@@ -69,13 +77,17 @@ pub fn disburse_escrow_handler(
         vault.route.clone(),
     )?;
 
-    let performance_fee = get_performance_fee(&vault, current_price)?;
+    let performance_fee = get_performance_fee(&vault, current_price, &config)?;
     let amount_to_disburse = subtract(&vault.escrowed_amount, &performance_fee)?;
 
     let vault = update_vault(
         deps.storage,
         Vault {
-            escrowed_amount: empty_of(vault.escrowed_amount),
+            escrowed_amount: empty_of(vault.escrowed_amount.clone()),
+            performance_fee_high_water_mark: max(
+                vault.performance_fee_high_water_mark,
+                get_added_value(&vault, current_price),
+            ),
             ..vault
         },
     )?;
@@ -92,13 +104,12 @@ pub fn disburse_escrow_handler(
         ),
     )?;
 
-    delete_disburse_escrow_task(deps.storage, vault.id)?;
-
-    VAULT_ID_CACHE.save(deps.storage, &vault.id)?;
+    schedule_next_disburse_escrow_task(deps.storage, &env, &config, &vault)?;
 
     Ok(response
         .add_submessages(get_disbursement_messages(
             deps.storage,
+            &env,
             &vault,
             amount_to_disburse.amount,
         )?)
@@ -109,18 +120,41 @@ pub fn disburse_escrow_handler(
             vault.target_denom.clone(),
             true,
         )?)
+        .add_submessages(get_hook_message(
+            deps.storage,
+            &vault,
+            VaultHookNotification::EscrowDisbursed {
+                amount_disbursed: amount_to_disburse.clone(),
+                performance_fee: performance_fee.clone(),
+            },
+        )?)
         .add_attribute("performance_fee", format!("{:?}", performance_fee))
         .add_attribute("escrow_disbursed", format!("{:?}", amount_to_disburse)))
 }
 
+fn schedule_next_disburse_escrow_task(
+    storage: &mut dyn Storage,
+    env: &Env,
+    config: &Config,
+    vault: &Vault,
+) -> StdResult<()> {
+    match config.escrow_crystallisation_period_seconds {
+        Some(period) if !vault.is_inactive() && !vault.is_cancelled() => {
+            save_disburse_escrow_task(storage, vault.id, env.block.time.plus_seconds(period))
+        }
+        _ => delete_disburse_escrow_task(storage, vault.id),
+    }
+}
+
 #[cfg(test)]
 mod disburse_escrow_tests {
     use super::*;
     use crate::{
         constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE, TEN, TEN_DECIMAL},
         handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        helpers::reply::get_vault_reply_id,
         state::{
-            config::get_config,
+            config::{get_config, update_config},
             disburse_escrow_tasks::{get_disburse_escrow_tasks, save_disburse_escrow_task},
             vaults::get_vault,
         },
@@ -129,6 +163,7 @@ mod disburse_escrow_tests {
             mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
         },
         types::{
+            config::Config,
             destination::Destination,
             event::{Event, EventData},
             performance_assessment_strategy::PerformanceAssessmentStrategy,
@@ -138,7 +173,7 @@ mod disburse_escrow_tests {
     };
     use cosmwasm_std::{
         testing::{mock_env, mock_info},
-        BankMsg, Coin, Decimal, SubMsg, Uint128,
+        BankMsg, Coin, Decimal, SubMsg, Timestamp, Uint128,
     };
     use shared::coin::add_to;
 
@@ -175,32 +210,36 @@ mod disburse_escrow_tests {
     }
 
     #[test]
-    fn caches_vault_id_for_after_automation_handler() {
+    fn tags_disbursement_messages_with_vault_id() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
 
-        instantiate_contract(deps.as_mut(), env.clone(), info);
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        setup_vault(deps.as_mut(), env.clone(), Vault::default());
 
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
             Vault {
+                status: VaultStatus::Inactive,
+                destinations: vec![Destination::default()],
                 escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
                 ..Vault::default()
             },
         );
 
-        save_disburse_escrow_task(
-            deps.as_mut().storage,
-            vault.id,
-            env.block.time.minus_seconds(10),
-        )
-        .unwrap();
-
-        let cached_vault_id = VAULT_ID_CACHE.load(deps.as_ref().storage).unwrap();
+        let response = disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
 
-        assert_eq!(vault.id, cached_vault_id)
+        assert_ne!(vault.id, Uint128::zero());
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: vault.destinations[0].address.to_string(),
+                amount: vec![vault.escrowed_amount]
+            },
+            get_vault_reply_id(AFTER_FAILED_AUTOMATION_REPLY_ID, vault.id)
+        )));
     }
 
     #[test]
@@ -365,9 +404,10 @@ mod disburse_escrow_tests {
 
         disburse_escrow_handler(deps.as_mut(), env.clone(), info, vault.id).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         let performance_fee = Coin::new(
             (ONE * Decimal::percent(20) - Uint128::one()).into(),
@@ -478,4 +518,358 @@ mod disburse_escrow_tests {
         assert_eq!(disburse_escrow_tasks_before.len(), 1);
         assert_eq!(disburse_escrow_tasks_after.len(), 0);
     }
+
+    #[test]
+    fn uses_configured_performance_fee_percent() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                performance_fee_percent: Some(Decimal::percent(10)),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        let performance_fee =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events
+                .into_iter()
+                .find_map(|event| match event.data {
+                    EventData::DcaVaultEscrowDisbursed {
+                        performance_fee, ..
+                    } => Some(performance_fee),
+                    _ => None,
+                })
+                .unwrap();
+
+        assert_eq!(
+            performance_fee,
+            Coin::new((ONE * Decimal::percent(10)).into(), DENOM_UUSK)
+        );
+    }
+
+    #[test]
+    fn only_charges_performance_fee_on_value_above_high_water_mark() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                performance_fee_high_water_mark: ONE * Decimal::percent(50),
+                ..Vault::default()
+            },
+        );
+
+        disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        let performance_fee =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events
+                .into_iter()
+                .find_map(|event| match event.data {
+                    EventData::DcaVaultEscrowDisbursed {
+                        performance_fee, ..
+                    } => Some(performance_fee),
+                    _ => None,
+                })
+                .unwrap();
+
+        assert_eq!(
+            performance_fee,
+            Coin::new((ONE * Decimal::percent(10)).into(), DENOM_UUSK)
+        );
+    }
+
+    #[test]
+    fn with_crystallisation_period_and_active_vault_reschedules_disburse_escrow_task() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Active,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        disburse_escrow_handler(deps.as_mut(), env.clone(), info, vault.id).unwrap();
+
+        let vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(vault.performance_fee_high_water_mark, ONE);
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(60 * 60 * 24 * 30).seconds()
+            ))
+        );
+    }
+
+    #[test]
+    fn with_crystallisation_period_and_inactive_vault_deletes_disburse_escrow_task() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.minus_seconds(10),
+        )
+        .unwrap();
+
+        disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_crystallisation_period_and_empty_escrow_deletes_disburse_escrow_task() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Active,
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.minus_seconds(10),
+        )
+        .unwrap();
+
+        disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn publishes_escrow_disbursed_event_for_each_tranche() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Active,
+                swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        disburse_escrow_handler(deps.as_mut(), env.clone(), info.clone(), vault.id).unwrap();
+
+        let vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        update_vault(
+            deps.as_mut().storage,
+            Vault {
+                swapped_amount: Coin::new((TEN + TEN).into(), DENOM_UKUJI),
+                received_amount: Coin::new((TEN + TEN + ONE + ONE).into(), DENOM_UUSK),
+                deposited_amount: Coin::new((TEN + TEN).into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(((TEN + ONE) * Decimal::percent(5)).into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((TEN + TEN).into(), DENOM_UKUJI),
+                        received_amount: Coin::new((TEN + TEN).into(), DENOM_UUSK),
+                    },
+                ),
+                ..vault
+            },
+        )
+        .unwrap();
+
+        let mut env = env;
+        env.block.time = env.block.time.plus_seconds(60 * 60 * 24 * 30);
+
+        disburse_escrow_handler(deps.as_mut(), env, info, vault.id).unwrap();
+
+        let performance_fees =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events
+                .into_iter()
+                .filter_map(|event| match event.data {
+                    EventData::DcaVaultEscrowDisbursed {
+                        performance_fee, ..
+                    } => Some(performance_fee),
+                    _ => None,
+                })
+                .collect::<Vec<Coin>>();
+
+        assert_eq!(
+            performance_fees,
+            vec![
+                Coin::new((ONE * Decimal::percent(20)).into(), DENOM_UUSK),
+                Coin::new((ONE * Decimal::percent(20)).into(), DENOM_UUSK),
+            ]
+        );
+        assert_eq!(
+            get_vault(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .performance_fee_high_water_mark,
+            ONE + ONE
+        );
+    }
 }
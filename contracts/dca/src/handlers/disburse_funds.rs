@@ -1,15 +1,27 @@
 use crate::error::ContractError;
+use crate::helpers::coin::{get_send_message, query_balance};
 use crate::helpers::disbursement::get_disbursement_messages;
 use crate::helpers::fees::{get_automation_fee_rate, get_fee_messages, get_swap_fee_rate};
+use crate::helpers::hooks::get_hook_message;
 use crate::helpers::math::checked_mul;
+use crate::helpers::reply::get_vault_id_from_reply_id;
+use crate::helpers::vault::end_vault;
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SWAP_CACHE, VAULT_ID_CACHE};
+use crate::state::cache::SWAP_CACHE;
+use crate::state::config::get_config;
+use crate::state::disburse_escrow_tasks::{
+    delete_disburse_escrow_task, get_disburse_escrow_task_due_date, save_disburse_escrow_task,
+};
 use crate::state::events::create_event;
+use crate::state::execution_summaries::{record_completed_execution, record_skipped_execution};
+use crate::state::referrals::record_referral;
+use crate::state::statistics::record_execution_statistics;
 use crate::state::triggers::delete_trigger;
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use crate::types::hook::VaultHookNotification;
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{to_json_binary, SubMsg, SubMsgResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Decimal, SubMsg, SubMsgResult, Uint128, WasmMsg};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{Attribute, Coin, DepsMut, Env, Reply, Response};
 use shared::coin::{add_to, subtract};
@@ -19,7 +31,7 @@ pub fn disburse_funds_handler(
     env: &Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
-    let vault_id = VAULT_ID_CACHE.load(deps.storage)?;
+    let vault_id = get_vault_id_from_reply_id(reply.id);
     let mut vault = get_vault(deps.storage, vault_id)?;
 
     let mut attributes = Vec::<Attribute>::new();
@@ -27,20 +39,27 @@ pub fn disburse_funds_handler(
 
     match reply.result {
         SubMsgResult::Ok(_) => {
-            let swap_cache = SWAP_CACHE.load(deps.storage)?;
+            let swap_cache = SWAP_CACHE.load(deps.storage, vault_id.into())?;
+            SWAP_CACHE.remove(deps.storage, vault_id.into());
 
-            let swap_denom_balance = &deps
-                .querier
-                .query_balance(&env.contract.address, vault.get_swap_denom())?;
+            let swap_denom_balance = &query_balance(
+                &deps.querier,
+                deps.storage,
+                &env.contract.address,
+                vault.get_swap_denom(),
+            )?;
 
-            let receive_denom_balance = &deps
-                .querier
-                .query_balance(&env.contract.address, vault.target_denom.clone())?;
+            let receive_denom_balance = &query_balance(
+                &deps.querier,
+                deps.storage,
+                &env.contract.address,
+                vault.target_denom.clone(),
+            )?;
 
             let coin_sent = subtract(&swap_cache.swap_denom_balance, swap_denom_balance)?;
             let coin_received = subtract(receive_denom_balance, &swap_cache.receive_denom_balance)?;
 
-            let swap_fee_rate = get_swap_fee_rate(deps.storage, &vault.swap_adjustment_strategy)?;
+            let swap_fee_rate = get_swap_fee_rate(deps.storage, &vault)?;
             let automation_fee_rate = get_automation_fee_rate(deps.storage, &vault)?;
 
             let swap_fee = checked_mul(coin_received.amount, swap_fee_rate)?;
@@ -49,17 +68,46 @@ pub fn disburse_funds_handler(
             let total_fee = swap_fee + automation_fee;
             let total_after_all_fees = coin_received.amount - total_fee;
 
+            let referral_fee_share = match vault.referrer {
+                Some(_) => get_config(deps.storage)?.referral_fee_share,
+                None => Decimal::zero(),
+            };
+
+            let swap_fee_referral_amount = checked_mul(swap_fee, referral_fee_share)?;
+            let automation_fee_referral_amount = checked_mul(automation_fee, referral_fee_share)?;
+            let referral_fee = swap_fee_referral_amount + automation_fee_referral_amount;
+
             sub_msgs.append(&mut get_fee_messages(
                 deps.as_ref(),
                 env.clone(),
-                vec![swap_fee, automation_fee],
+                vec![
+                    swap_fee - swap_fee_referral_amount,
+                    automation_fee - automation_fee_referral_amount,
+                ],
                 coin_received.denom.clone(),
                 false,
             )?);
 
+            if let Some(referrer) = vault.referrer.clone() {
+                let referral_fee = Coin::new(referral_fee.into(), coin_received.denom.clone());
+
+                if !referral_fee.amount.is_zero() {
+                    sub_msgs.push(SubMsg::new(get_send_message(
+                        deps.storage,
+                        referrer.to_string(),
+                        referral_fee.clone(),
+                    )));
+                }
+
+                record_referral(deps.storage, referrer, coin_sent.clone(), referral_fee)?;
+            }
+
             let amount_to_escrow = total_after_all_fees * vault.escrow_level;
             let total_after_escrow = total_after_all_fees - amount_to_escrow;
 
+            let had_low_balance = vault.has_low_balance();
+            let was_inactive = vault.is_inactive();
+
             vault.balance.amount -= coin_sent.amount;
 
             vault = update_vault(
@@ -68,7 +116,8 @@ pub fn disburse_funds_handler(
                     swapped_amount: add_to(&vault.swapped_amount, coin_sent.amount),
                     received_amount: add_to(&vault.received_amount, total_after_all_fees),
                     escrowed_amount: add_to(&vault.escrowed_amount, amount_to_escrow),
-                    status: if vault.balance.amount.is_zero() {
+                    execution_count: vault.execution_count + 1,
+                    status: if vault.balance.amount.is_zero() && !vault.is_allowance_funded() {
                         VaultStatus::Inactive
                     } else {
                         vault.status
@@ -77,8 +126,22 @@ pub fn disburse_funds_handler(
                 },
             )?;
 
+            if let Some(period) = get_config(deps.storage)?.escrow_crystallisation_period_seconds {
+                if !amount_to_escrow.is_zero()
+                    && vault.is_active()
+                    && get_disburse_escrow_task_due_date(deps.storage, vault.id)?.is_none()
+                {
+                    save_disburse_escrow_task(
+                        deps.storage,
+                        vault.id,
+                        env.block.time.plus_seconds(period),
+                    )?;
+                }
+            }
+
             sub_msgs.append(
-                &mut get_disbursement_messages(deps.storage, &vault, total_after_escrow)?.into(),
+                &mut get_disbursement_messages(deps.storage, env, &vault, total_after_escrow)?
+                    .into(),
             );
 
             create_event(
@@ -94,12 +157,64 @@ pub fn disburse_funds_handler(
                 ),
             )?;
 
+            record_execution_statistics(deps.storage, &coin_sent, &coin_received, total_fee)?;
+            record_completed_execution(
+                deps.storage,
+                vault.id,
+                &coin_sent,
+                &coin_received,
+                &Coin::new(total_fee.into(), coin_received.denom.clone()),
+            )?;
+
+            if vault.is_active() {
+                if let Some(reason) = vault.get_end_reason(env.block.time) {
+                    let end_msgs;
+                    (vault, end_msgs) = end_vault(deps.storage, env, vault, reason)?;
+                    sub_msgs.extend(end_msgs);
+                }
+            }
+
+            sub_msgs.extend(get_hook_message(
+                deps.storage,
+                &vault,
+                VaultHookNotification::ExecutionCompleted {
+                    sent: coin_sent.clone(),
+                    received: coin_received.clone(),
+                    fee: Coin::new(total_fee.into(), coin_received.denom.clone()),
+                },
+            )?);
+
+            if vault.has_low_balance() && !had_low_balance {
+                sub_msgs.extend(get_hook_message(
+                    deps.storage,
+                    &vault,
+                    VaultHookNotification::BalanceLow {
+                        balance: vault.balance.clone(),
+                        swap_amount: vault.swap_amount,
+                    },
+                )?);
+            }
+
+            if vault.is_inactive() && !was_inactive {
+                sub_msgs.extend(get_hook_message(
+                    deps.storage,
+                    &vault,
+                    VaultHookNotification::VaultFinished {
+                        swapped_amount: vault.swapped_amount.clone(),
+                        received_amount: vault.received_amount.clone(),
+                    },
+                )?);
+            }
+
             attributes.push(Attribute::new("disburse_funds", "true"));
             attributes.push(Attribute::new("swapped_amount", coin_sent.to_string()));
             attributes.push(Attribute::new("received_amount", coin_received.to_string()));
             attributes.push(Attribute::new("fee_amount", total_fee.to_string()));
         }
         SubMsgResult::Err(_) => {
+            SWAP_CACHE.remove(deps.storage, vault_id.into());
+            record_skipped_execution(deps.storage, vault.id)?;
+
             create_event(
                 deps.storage,
                 EventBuilder::new(
@@ -111,6 +226,14 @@ pub fn disburse_funds_handler(
                 ),
             )?;
 
+            sub_msgs.extend(get_hook_message(
+                deps.storage,
+                &vault,
+                VaultHookNotification::ExecutionSkipped {
+                    reason: ExecutionSkippedReason::SlippageToleranceExceeded,
+                },
+            )?);
+
             attributes.push(Attribute::new(
                 "execution_skipped",
                 "slippage_tolerance_exceeded",
@@ -120,6 +243,8 @@ pub fn disburse_funds_handler(
 
     if vault.should_not_continue() {
         if vault.escrowed_amount.amount > Uint128::zero() {
+            delete_disburse_escrow_task(deps.storage, vault.id)?;
+
             sub_msgs.push(SubMsg::new(WasmMsg::Execute {
                 contract_addr: env.contract.address.to_string(),
                 msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id })?,
@@ -140,11 +265,18 @@ mod disburse_funds_tests {
     use super::*;
     use crate::{
         constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, AFTER_SWAP_REPLY_ID, ONE, TEN, TWO_MICRONS},
-        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
-        helpers::vault::get_swap_amount,
+        handlers::{
+            disburse_escrow::disburse_escrow_handler,
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+        },
+        helpers::{reply::get_vault_reply_id, vault::get_swap_amount},
         state::{
             cache::{SwapCache, SWAP_CACHE},
-            config::get_config,
+            config::{get_config, update_config},
+            custom_swap_fees::save_custom_swap_fee,
+            disburse_escrow_tasks::{get_disburse_escrow_task_due_date, save_disburse_escrow_task},
+            referrals::{get_referral_volume, ReferralVolume},
+            statistics::{get_pair_statistics, get_total_value_locked, PairStatistics},
             swap_adjustments::update_swap_adjustment,
             vaults::get_vault,
         },
@@ -153,11 +285,12 @@ mod disburse_funds_tests {
                 instantiate_contract, instantiate_contract_with_multiple_fee_collectors,
                 setup_vault,
             },
-            mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
         },
         types::{
+            config::Config,
             destination::Destination,
-            event::{Event, EventBuilder, EventData, ExecutionSkippedReason},
+            event::{Event, EventBuilder, EventData, ExecutionSkippedReason, VaultEndReason},
             fee_collector::FeeCollector,
             performance_assessment_strategy::PerformanceAssessmentStrategy,
             position_type::PositionType,
@@ -167,10 +300,132 @@ mod disburse_funds_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+        Addr, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult, Timestamp,
+        Uint128,
     };
     use std::str::FromStr;
 
+    #[test]
+    fn uses_swap_cache_of_vault_in_reply_id() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let other_vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        for (vault_id, swap_denom_balance) in [(other_vault.id, TEN + TEN), (vault.id, TEN)] {
+            SWAP_CACHE
+                .save(
+                    deps.as_mut().storage,
+                    vault_id.into(),
+                    &SwapCache {
+                        swap_denom_balance: Coin::new(swap_denom_balance.into(), DENOM_UKUJI),
+                        receive_denom_balance: Coin::new(0, DENOM_UUSK),
+                    },
+                )
+                .unwrap();
+        }
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new((TEN - ONE).into(), DENOM_UKUJI),
+                Coin::new(ONE.into(), DENOM_UUSK),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: get_vault_reply_id(AFTER_SWAP_REPLY_ID, vault.id),
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swapped_amount,
+            Coin::new(ONE.into(), DENOM_UKUJI)
+        );
+        assert!(SWAP_CACHE
+            .may_load(deps.as_ref().storage, vault.id.into())
+            .unwrap()
+            .is_none());
+        assert!(SWAP_CACHE
+            .may_load(deps.as_ref().storage, other_vault.id.into())
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn with_zero_custom_swap_fee_returns_all_funds_to_destination() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                ..Vault::default()
+            },
+        );
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            vault.target_denom.clone(),
+            Decimal::zero(),
+        )
+        .unwrap();
+
+        let receive_amount = Uint128::new(10000);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::reply_always(
+            BankMsg::Send {
+                to_address: vault.destinations.first().unwrap().address.to_string(),
+                amount: vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
+            },
+            AFTER_FAILED_AUTOMATION_REPLY_ID
+        )));
+    }
+
     #[test]
     fn with_succcesful_swap_returns_funds_to_destination() {
         let mut deps = mock_dependencies();
@@ -191,6 +446,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -258,6 +514,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -292,6 +549,97 @@ mod disburse_funds_tests {
         })));
     }
 
+    #[test]
+    fn with_referrer_shares_fee_with_referrer() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                referral_fee_share: Decimal::percent(25),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let referrer = Addr::unchecked("referrer");
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                referrer: Some(referrer.clone()),
+                ..Vault::default()
+            },
+        );
+
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let config = get_config(&deps.storage).unwrap();
+        let swap_fee = config.default_swap_fee_percent * receive_amount;
+        let referral_fee = swap_fee * Decimal::percent(25);
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: config.fee_collectors[0].address.to_string(),
+            amount: vec![Coin::new(
+                (swap_fee - referral_fee).into(),
+                vault.target_denom.clone()
+            )]
+        })));
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: referrer.to_string(),
+            amount: vec![Coin::new(referral_fee.into(), vault.target_denom.clone())]
+        })));
+
+        assert_eq!(
+            get_referral_volume(deps.as_ref().storage, referrer).unwrap(),
+            ReferralVolume {
+                volume: vec![Coin::new(vault.swap_amount.into(), vault.get_swap_denom())],
+                fees_earned: vec![Coin::new(referral_fee.into(), vault.target_denom)],
+            }
+        );
+    }
+
     #[test]
     fn with_succcesful_swap_returns_fee_to_multiple_fee_collectors() {
         let mut deps = mock_dependencies();
@@ -319,6 +667,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -383,17 +732,353 @@ mod disburse_funds_tests {
     }
 
     #[test]
-    fn with_succcesful_swap_adjusts_vault_balance() {
+    fn with_succcesful_swap_adjusts_vault_balance() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.balance.amount,
+            vault.balance.amount - vault.swap_amount
+        );
+    }
+
+    #[test]
+    fn with_succcesful_swap_adjusts_swapped_amount_stat() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount
+                        - get_swap_amount(&deps.as_ref(), &env, &vault)
+                            .unwrap()
+                            .amount)
+                        .into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swapped_amount.amount,
+            get_swap_amount(&deps.as_ref(), &env, &vault)
+                .unwrap()
+                .amount
+        );
+    }
+
+    #[test]
+    fn with_succcesful_swap_records_pair_statistics() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let config = get_config(&deps.storage).unwrap();
+
+        assert_eq!(
+            get_pair_statistics(deps.as_ref().storage, None, None).unwrap(),
+            vec![PairStatistics {
+                swap_denom: vault.get_swap_denom(),
+                target_denom: vault.target_denom.clone(),
+                execution_count: 1,
+                swapped_amount: vault.swap_amount,
+                received_amount: receive_amount,
+                fee_amount: config.default_swap_fee_percent * receive_amount,
+            }]
+        );
+        assert_eq!(
+            get_total_value_locked(deps.as_ref().storage).unwrap(),
+            vec![Coin::new(
+                (vault.balance.amount - vault.swap_amount).into(),
+                vault.get_swap_denom()
+            )]
+        );
+    }
+
+    #[test]
+    fn with_hook_notifies_hook_of_completed_execution_low_balance_and_finish() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE.into(), DENOM_UKUJI),
+                hook: Some(Addr::unchecked("hook")),
+                ..Vault::default()
+            },
+        );
+
+        let receive_amount = Uint128::new(234312312);
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(receive_amount.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        let fee = get_config(&deps.storage).unwrap().default_swap_fee_percent * receive_amount;
+
+        assert!(response.messages.contains(
+            &get_hook_message(
+                deps.as_ref().storage,
+                &updated_vault,
+                VaultHookNotification::ExecutionCompleted {
+                    sent: vault.balance.clone(),
+                    received: Coin::new(receive_amount.into(), vault.target_denom.clone()),
+                    fee: Coin::new(fee.into(), vault.target_denom.clone()),
+                },
+            )
+            .unwrap()
+            .unwrap()
+        ));
+        assert!(response.messages.contains(
+            &get_hook_message(
+                deps.as_ref().storage,
+                &updated_vault,
+                VaultHookNotification::VaultFinished {
+                    swapped_amount: updated_vault.swapped_amount.clone(),
+                    received_amount: updated_vault.received_amount.clone(),
+                },
+            )
+            .unwrap()
+            .unwrap()
+        ));
+        assert!(!response.messages.iter().any(|message| message
+            == &get_hook_message(
+                deps.as_ref().storage,
+                &updated_vault,
+                VaultHookNotification::BalanceLow {
+                    balance: updated_vault.balance.clone(),
+                    swap_amount: updated_vault.swap_amount,
+                },
+            )
+            .unwrap()
+            .unwrap()));
+    }
+
+    #[test]
+    fn with_hook_notifies_hook_when_balance_becomes_low() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((ONE + ONE + ONE).into(), DENOM_UKUJI),
+                swap_amount: ONE,
+                hook: Some(Addr::unchecked("hook")),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new((ONE + ONE).into(), vault.get_swap_denom()),
+                Coin::new(ONE.into(), vault.target_denom.clone()),
+            ],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(response.messages.contains(
+            &get_hook_message(
+                deps.as_ref().storage,
+                &updated_vault,
+                VaultHookNotification::BalanceLow {
+                    balance: Coin::new((ONE + ONE).into(), DENOM_UKUJI),
+                    swap_amount: ONE,
+                },
+            )
+            .unwrap()
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    fn with_succcesful_swap_increments_execution_count() {
         let mut deps = mock_dependencies();
         let env = mock_env();
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
-        let receive_amount = Uint128::new(234312312);
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_count: 4,
+                ..Vault::default()
+            },
+        );
 
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -403,13 +1088,7 @@ mod disburse_funds_tests {
 
         deps.querier.update_balance(
             "cosmos2contract",
-            vec![
-                Coin::new(
-                    (vault.balance.amount - vault.swap_amount).into(),
-                    vault.get_swap_denom(),
-                ),
-                Coin::new(receive_amount.into(), vault.target_denom.clone()),
-            ],
+            vec![Coin::new(234312312, vault.target_denom.clone())],
         );
 
         disburse_funds_handler(
@@ -427,24 +1106,30 @@ mod disburse_funds_tests {
 
         let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
 
-        assert_eq!(
-            updated_vault.balance.amount,
-            vault.balance.amount - vault.swap_amount
-        );
+        assert_eq!(updated_vault.execution_count, 5);
     }
 
     #[test]
-    fn with_succcesful_swap_adjusts_swapped_amount_stat() {
+    fn with_succcesful_swap_reaching_max_executions_ends_vault() {
         let mut deps = mock_dependencies();
         let env = mock_env();
         instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
 
-        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
-        let receive_amount = Uint128::new(234312312);
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_count: 4,
+                max_executions: Some(5),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
 
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -456,18 +1141,14 @@ mod disburse_funds_tests {
             "cosmos2contract",
             vec![
                 Coin::new(
-                    (vault.balance.amount
-                        - get_swap_amount(&deps.as_ref(), &env, &vault)
-                            .unwrap()
-                            .amount)
-                        .into(),
-                    vault.get_swap_denom(),
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    DENOM_UKUJI,
                 ),
-                Coin::new(receive_amount.into(), vault.target_denom.clone()),
+                Coin::new(ONE.into(), vault.target_denom.clone()),
             ],
         );
 
-        disburse_funds_handler(
+        let response = disburse_funds_handler(
             deps.as_mut(),
             &env,
             Reply {
@@ -482,12 +1163,40 @@ mod disburse_funds_tests {
 
         let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
 
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert!(updated_vault.balance.amount.is_zero());
+        assert!(updated_vault.trigger.is_none());
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![Coin::new(
+                (vault.balance.amount - vault.swap_amount).into(),
+                DENOM_UKUJI
+            )],
+        })));
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+            funds: vec![],
+        })));
         assert_eq!(
-            updated_vault.swapped_amount.amount,
-            get_swap_amount(&deps.as_ref(), &env, &vault)
-                .unwrap()
-                .amount
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            None
         );
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.iter().any(|event| event.data
+            == EventData::DcaVaultEnded {
+                reason: VaultEndReason::MaxExecutionsReached,
+                execution_count: 5,
+                refunded: Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    DENOM_UKUJI
+                ),
+            }));
     }
 
     #[test]
@@ -502,6 +1211,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -569,6 +1279,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -605,6 +1316,7 @@ mod disburse_funds_tests {
                             base_denom: BaseDenom::Bitcoin,
                             position_type,
                         },
+                        &Addr::unchecked(ADMIN),
                         adjustment,
                         env.block.time,
                     )
@@ -654,6 +1366,75 @@ mod disburse_funds_tests {
         assert_ne!(escrow_amount, Uint128::zero());
     }
 
+    #[test]
+    fn with_succcesful_swap_and_crystallisation_period_saves_disburse_escrow_task() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination::default()],
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![
+                Coin::new(
+                    (vault.balance.amount - vault.swap_amount).into(),
+                    vault.get_swap_denom(),
+                ),
+                Coin::new(10000, vault.target_denom.clone()),
+            ],
+        );
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(60 * 60 * 24 * 30).seconds()
+            ))
+        );
+    }
+
     #[test]
     fn with_succcesful_swap_publishes_dca_execution_completed_event() {
         let mut deps = mock_dependencies();
@@ -667,6 +1448,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -703,6 +1485,7 @@ mod disburse_funds_tests {
                             base_denom: BaseDenom::Bitcoin,
                             position_type,
                         },
+                        &Addr::unchecked(ADMIN),
                         adjustment,
                         env.block.time,
                     )
@@ -725,9 +1508,10 @@ mod disburse_funds_tests {
 
         let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
@@ -771,6 +1555,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -807,6 +1592,7 @@ mod disburse_funds_tests {
                             base_denom: BaseDenom::Bitcoin,
                             position_type,
                         },
+                        &Addr::unchecked(ADMIN),
                         adjustment,
                         env.block.time,
                     )
@@ -829,9 +1615,10 @@ mod disburse_funds_tests {
 
         let updated_vault = get_vault(&deps.storage, vault.id).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(&Event {
             id: 1,
@@ -875,6 +1662,41 @@ mod disburse_funds_tests {
         assert_eq!(updated_vault.balance, balance);
     }
 
+    #[test]
+    fn with_failed_swap_removes_swap_cache() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: get_vault_reply_id(AFTER_SWAP_REPLY_ID, vault.id),
+                result: SubMsgResult::Err("Generic failure".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert!(SWAP_CACHE
+            .may_load(deps.as_ref().storage, vault.id.into())
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn with_failed_swap_publishes_skipped_event_with_slippage_failure() {
         let mut deps = mock_dependencies();
@@ -890,9 +1712,10 @@ mod disburse_funds_tests {
 
         disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(
             &EventBuilder::new(
@@ -944,6 +1767,25 @@ mod disburse_funds_tests {
         assert_eq!(vault.balance, Coin::new(TEN.into(), vault.get_swap_denom()));
     }
 
+    #[test]
+    fn with_failed_swap_does_not_increment_execution_count() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let reply = Reply {
+            id: AFTER_SWAP_REPLY_ID,
+            result: SubMsgResult::Err("failed for slippage".to_string()),
+        };
+
+        disburse_funds_handler(deps.as_mut(), &env, reply).unwrap();
+
+        let vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(vault.execution_count, 0);
+    }
+
     #[test]
     fn with_insufficient_remaining_funds_sets_vault_to_inactive() {
         let mut deps = mock_dependencies();
@@ -963,6 +1805,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -1013,6 +1856,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -1035,9 +1879,10 @@ mod disburse_funds_tests {
         )
         .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(&Event {
             id: 1,
@@ -1071,6 +1916,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -1128,6 +1974,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -1160,6 +2007,102 @@ mod disburse_funds_tests {
         })));
     }
 
+    #[test]
+    fn for_finished_vault_with_pending_disburse_escrow_task_disburses_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                escrow_crystallisation_period_seconds: Some(60 * 60 * 24 * 30),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE.into(), DENOM_UKUJI),
+                swap_amount: ONE,
+                deposited_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                destinations: vec![Destination::default()],
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                    },
+                ),
+                escrow_level: Decimal::percent(5),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.plus_seconds(60 * 60 * 24 * 30),
+        )
+        .unwrap();
+
+        SWAP_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &SwapCache {
+                    swap_denom_balance: vault.balance.clone(),
+                    receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
+                },
+            )
+            .unwrap();
+
+        deps.querier.update_balance(
+            "cosmos2contract",
+            vec![Coin::new(ONE.into(), vault.target_denom.clone())],
+        );
+
+        let response = disburse_funds_handler(
+            deps.as_mut(),
+            &env,
+            Reply {
+                id: AFTER_SWAP_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id }).unwrap(),
+            funds: vec![],
+        })));
+
+        disburse_escrow_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_str(), &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let vault = get_vault(&deps.storage, vault.id).unwrap();
+
+        assert_eq!(vault.status, VaultStatus::Inactive);
+        assert!(vault.escrowed_amount.amount.is_zero());
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn for_finished_standard_and_plus_deletes_trigger() {
         let mut deps = mock_dependencies();
@@ -1188,6 +2131,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
@@ -1248,6 +2192,7 @@ mod disburse_funds_tests {
         SWAP_CACHE
             .save(
                 deps.as_mut().storage,
+                vault.id.into(),
                 &SwapCache {
                     swap_denom_balance: vault.balance.clone(),
                     receive_denom_balance: Coin::new(0, vault.target_denom.clone()),
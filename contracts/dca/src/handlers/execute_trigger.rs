@@ -1,23 +1,49 @@
-use crate::constants::AFTER_SWAP_REPLY_ID;
+use crate::constants::{
+    AFTER_ALLOWANCE_PULL_REPLY_ID, AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, AFTER_SWAP_REPLY_ID,
+};
 use crate::error::ContractError;
-use crate::helpers::price::{get_slippage, get_twap_to_now};
+use crate::helpers::coin::{
+    get_allowance_pull_message, get_execute_message, get_send_message, query_balance,
+};
+use crate::helpers::hooks::get_hook_message;
+use crate::helpers::price::{get_price, get_slippage, get_twap_to_now};
+use crate::helpers::reply::{get_vault_id_from_reply_id, get_vault_reply_id};
+use crate::helpers::staking::{
+    get_claimed_staking_rewards, get_withdraw_delegator_rewards_message,
+};
 use crate::helpers::time::get_next_target_time;
 use crate::helpers::validation::{assert_contract_is_not_paused, assert_target_time_is_in_past};
-use crate::helpers::vault::{get_swap_amount, simulate_standard_dca_execution};
+use crate::helpers::vault::{
+    deposit_into_vault, end_vault, get_swap_amount, simulate_standard_dca_execution,
+};
 use crate::msg::ExecuteMsg;
-use crate::state::cache::{SwapCache, SWAP_CACHE, VAULT_ID_CACHE};
+use crate::state::cache::{
+    PendingSwapCache, StakingRewardsClaimCache, SwapCache, PENDING_SWAP_CACHE,
+    STAKING_REWARDS_CLAIM_CACHE, SWAP_CACHE,
+};
 use crate::state::config::get_config;
+use crate::state::disburse_escrow_tasks::{delete_disburse_escrow_task, save_disburse_escrow_task};
 use crate::state::events::create_event;
+use crate::state::execution_summaries::record_skipped_execution;
+use crate::state::price_history::record_price;
 use crate::state::triggers::{delete_trigger, save_trigger};
 use crate::state::vaults::{get_vault, update_vault};
 use crate::types::event::{EventBuilder, EventData, ExecutionSkippedReason};
+use crate::types::exit_condition::ExitAction;
+use crate::types::hook::VaultHookNotification;
+use crate::types::price_band::PriceBandEdge;
 use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
 use crate::types::trigger::{Trigger, TriggerConfiguration};
 use crate::types::vault::{Vault, VaultStatus};
-use cosmwasm_std::{to_json_binary, Binary, Coin, Decimal, SubMsg, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, BlockInfo, Coin, Decimal, Reply, Storage, SubMsg, SubMsgResponse,
+    SubMsgResult, WasmMsg,
+};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{DepsMut, Env, Response, Uint128};
 use exchange::msg::{ExecuteMsg as ExchangeExecuteMsg, Order, QueryMsg as ExchangeQueryMsg};
+use shared::coin::empty_of;
+use std::cmp::max;
 
 pub fn execute_trigger_handler(
     deps: DepsMut,
@@ -95,6 +121,22 @@ pub fn execute_trigger_handler(
         }
     }
 
+    if let Some(reason) = vault.get_end_reason(env.block.time) {
+        let (vault, sub_msgs) = end_vault(deps.storage, &env, vault, reason)?;
+
+        return Ok(response
+            .add_submessages(sub_msgs)
+            .add_submessages(get_hook_message(
+                deps.storage,
+                &vault,
+                VaultHookNotification::VaultFinished {
+                    swapped_amount: vault.swapped_amount.clone(),
+                    received_amount: vault.received_amount.clone(),
+                },
+            )?)
+            .add_attribute("execution_skipped", "vault_end_condition_reached"));
+    }
+
     if vault.is_scheduled() {
         vault = update_vault(
             deps.storage,
@@ -131,6 +173,29 @@ pub fn execute_trigger_handler(
         ),
     )?;
 
+    let canonical_price = match route {
+        None => Some(belief_price),
+        Some(_) => get_twap_to_now(
+            &deps.querier,
+            config.exchange_contract_address.clone(),
+            vault.get_swap_denom(),
+            vault.target_denom.clone(),
+            config.twap_period,
+            None,
+        )
+        .ok(),
+    };
+
+    if let Some(canonical_price) = canonical_price {
+        record_price(
+            deps.storage,
+            &vault.get_swap_denom(),
+            &vault.target_denom,
+            canonical_price,
+            env.block.time,
+        )?;
+    }
+
     response = response.add_attribute("belief_price", belief_price.to_string());
 
     if let Some(SwapAdjustmentStrategy::RiskWeightedAverage { .. }) = vault.swap_adjustment_strategy
@@ -145,6 +210,82 @@ pub fn execute_trigger_handler(
         )?;
     }
 
+    let triggered_exit_action = if !vault.is_active() {
+        None
+    } else if vault.stop_loss_triggered(belief_price) {
+        vault.stop_loss.clone().map(|stop_loss| {
+            (
+                stop_loss.action,
+                EventData::DcaVaultStopLossTriggered {
+                    price: belief_price,
+                    action: stop_loss.action,
+                },
+            )
+        })
+    } else if vault.take_profit_triggered(belief_price) {
+        vault.take_profit.clone().map(|take_profit| {
+            (
+                take_profit.action,
+                EventData::DcaVaultTakeProfitTriggered {
+                    price: belief_price,
+                    action: take_profit.action,
+                },
+            )
+        })
+    } else {
+        None
+    }
+    .map(|(action, event_data)| {
+        create_event(
+            deps.storage,
+            EventBuilder::new(vault.id, env.block.clone(), event_data),
+        )
+        .map(|_| action)
+    })
+    .transpose()?;
+
+    if triggered_exit_action == Some(ExitAction::CancelVault) {
+        if vault.balance.amount > Uint128::zero() {
+            response = response.add_message(get_send_message(
+                deps.storage,
+                vault.owner.to_string(),
+                vault.balance.clone(),
+            ));
+        }
+
+        if vault.escrowed_amount.amount > Uint128::zero() {
+            save_disburse_escrow_task(
+                deps.storage,
+                vault.id,
+                vault.get_expected_execution_completed_date(env.block.time),
+            )?;
+        }
+
+        create_event(
+            deps.storage,
+            EventBuilder::new(vault.id, env.block, EventData::DcaVaultCancelled {}),
+        )?;
+
+        response = response.add_submessages(get_hook_message(
+            deps.storage,
+            &vault,
+            VaultHookNotification::VaultCancelled {
+                refunded: vault.balance.clone(),
+            },
+        )?);
+
+        update_vault(
+            deps.storage,
+            Vault {
+                status: VaultStatus::Cancelled,
+                balance: empty_of(vault.balance.clone()),
+                ..vault
+            },
+        )?;
+
+        return Ok(response.add_attribute("exit_action", "cancel_vault"));
+    }
+
     let should_execute_again = vault.is_active()
         || vault.performance_assessment_strategy.clone().map_or(
             false,
@@ -169,6 +310,8 @@ pub fn execute_trigger_handler(
         )?;
     } else {
         if vault.should_not_continue() && vault.escrowed_amount.amount > Uint128::zero() {
+            delete_disburse_escrow_task(deps.storage, vault.id)?;
+
             response = response.add_submessage(SubMsg::new(WasmMsg::Execute {
                 contract_addr: env.contract.address.to_string(),
                 msg: to_json_binary(&ExecuteMsg::DisburseEscrow { vault_id: vault.id })?,
@@ -183,43 +326,141 @@ pub fn execute_trigger_handler(
         return Ok(response.add_attribute("execution_skipped", "vault_is_inactive"));
     }
 
-    let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault)?;
+    if triggered_exit_action == Some(ExitAction::SwapRemainingBalance) {
+        let minimum_receive_amount = Decimal::one()
+            .checked_sub(vault.slippage_tolerance)?
+            .checked_div(belief_price)
+            .ok()
+            .and_then(|minimum_price| vault.balance.amount.checked_mul_floor(minimum_price).ok())
+            .ok_or_else(|| ContractError::CustomError {
+                val: format!(
+                    "cannot calculate minimum receive amount at belief price {}",
+                    belief_price
+                ),
+            })?;
+
+        return add_swap_submessage(
+            deps,
+            &env,
+            response.add_attribute("exit_action", "swap_remaining_balance"),
+            &vault,
+            config.exchange_contract_address.to_string(),
+            route,
+            vault.balance.clone(),
+            minimum_receive_amount,
+        );
+    }
+
+    if let Some(SwapAdjustmentStrategy::ValueAveraging {
+        target_growth_per_execution,
+        target_value,
+    }) = vault.swap_adjustment_strategy
+    {
+        vault = update_vault(
+            deps.storage,
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution,
+                    target_value: target_value + target_growth_per_execution,
+                }),
+                ..vault
+            },
+        )?;
+    }
+
+    if vault.is_staking_rewards_funded() {
+        return claim_staking_rewards(deps, &env, response, &vault, route, belief_price);
+    }
+
+    swap_or_skip_execution(
+        deps,
+        &env,
+        response,
+        vault,
+        config.exchange_contract_address,
+        route,
+        belief_price,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn swap_or_skip_execution(
+    deps: DepsMut,
+    env: &Env,
+    mut response: Response,
+    mut vault: Vault,
+    exchange_contract_address: Addr,
+    route: Option<Binary>,
+    belief_price: Decimal,
+) -> Result<Response, ContractError> {
+    let held_balance = vault.balance.clone();
+
+    if vault.is_allowance_funded() {
+        vault.balance.amount = max(vault.balance.amount, vault.swap_amount);
+    }
+
+    let adjusted_swap_amount = get_swap_amount(&deps.as_ref(), env, &vault)?;
 
     if adjusted_swap_amount.amount.is_zero() {
-        create_event(
+        response = skip_execution(
             deps.storage,
-            EventBuilder::new(
-                vault.id,
-                env.block,
-                EventData::DcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::SwapAmountAdjustedToZero,
-                },
-            ),
+            env.block.clone(),
+            &vault,
+            response,
+            ExecutionSkippedReason::SwapAmountAdjustedToZero,
         )?;
 
         return Ok(response.add_attribute("execution_skipped", "swap_amount_adjusted_to_zero"));
     }
 
     if vault.price_threshold_exceeded(belief_price)? {
-        create_event(
+        response = skip_execution(
             deps.storage,
-            EventBuilder::new(
-                vault.id,
-                env.block,
-                EventData::DcaVaultExecutionSkipped {
-                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
-                        price: belief_price,
-                    },
-                },
-            ),
+            env.block.clone(),
+            &vault,
+            response,
+            ExecutionSkippedReason::PriceThresholdExceeded {
+                price: belief_price,
+            },
         )?;
 
         return Ok(response.add_attribute("execution_skipped", "price_threshold_exceeded"));
     };
 
+    let price_band_breach = if vault.maximum_receive_amount_exceeded(belief_price)? {
+        Some((belief_price, PriceBandEdge::Lower))
+    } else if vault.price_band.is_some() {
+        let current_price = get_price(
+            &deps.querier,
+            deps.storage,
+            exchange_contract_address.clone(),
+            adjusted_swap_amount.clone(),
+            vault.target_denom.clone(),
+            route.clone(),
+        )?;
+
+        vault
+            .price_band_breached(belief_price, current_price)
+            .map(|edge| (current_price, edge))
+    } else {
+        None
+    };
+
+    if let Some((price, edge)) = price_band_breach {
+        response = skip_execution(
+            deps.storage,
+            env.block.clone(),
+            &vault,
+            response,
+            ExecutionSkippedReason::PriceBandBreached { price, edge },
+        )?;
+
+        return Ok(response.add_attribute("execution_skipped", "price_band_breached"));
+    }
+
     let get_slippage_result = get_slippage(
         &deps.querier,
-        config.exchange_contract_address.clone(),
+        exchange_contract_address.clone(),
         adjusted_swap_amount.clone(),
         vault.target_denom.clone(),
         belief_price,
@@ -229,15 +470,12 @@ pub fn execute_trigger_handler(
     match get_slippage_result {
         Ok(slippage) => {
             if slippage > vault.slippage_tolerance {
-                create_event(
+                response = skip_execution(
                     deps.storage,
-                    EventBuilder::new(
-                        vault.id,
-                        env.block,
-                        EventData::DcaVaultExecutionSkipped {
-                            reason: ExecutionSkippedReason::SlippageToleranceExceeded,
-                        },
-                    ),
+                    env.block.clone(),
+                    &vault,
+                    response,
+                    ExecutionSkippedReason::SlippageToleranceExceeded,
                 )?;
 
                 return Ok(response
@@ -247,35 +485,18 @@ pub fn execute_trigger_handler(
             }
         }
         Err(_) => {
-            create_event(
+            response = skip_execution(
                 deps.storage,
-                EventBuilder::new(
-                    vault.id,
-                    env.block,
-                    EventData::DcaVaultExecutionSkipped {
-                        reason: ExecutionSkippedReason::SlippageQueryError,
-                    },
-                ),
+                env.block.clone(),
+                &vault,
+                response,
+                ExecutionSkippedReason::SlippageQueryError,
             )?;
 
             return Ok(response.add_attribute("execution_skipped", "slippage_query_error"));
         }
     }
 
-    VAULT_ID_CACHE.save(deps.storage, &vault.id)?;
-
-    SWAP_CACHE.save(
-        deps.storage,
-        &SwapCache {
-            swap_denom_balance: deps
-                .querier
-                .query_balance(&env.contract.address, vault.get_swap_denom())?,
-            receive_denom_balance: deps
-                .querier
-                .query_balance(&env.contract.address, vault.target_denom.clone())?,
-        },
-    )?;
-
     let adjusted_minimum_receive_amount =
         vault
             .minimum_receive_amount
@@ -284,22 +505,269 @@ pub fn execute_trigger_handler(
                     * minimum_receive_amount
             });
 
+    let pull_amount = adjusted_swap_amount
+        .amount
+        .saturating_sub(held_balance.amount);
+
+    if !pull_amount.is_zero() {
+        let pull_amount = Coin::new(pull_amount.into(), vault.get_swap_denom());
+
+        PENDING_SWAP_CACHE.save(
+            deps.storage,
+            vault.id.into(),
+            &PendingSwapCache {
+                pull_amount: pull_amount.clone(),
+                swap_amount: adjusted_swap_amount,
+                minimum_receive_amount: adjusted_minimum_receive_amount,
+                route,
+            },
+        )?;
+
+        return Ok(response
+            .add_attribute("allowance_pull", pull_amount.to_string())
+            .add_submessage(SubMsg::reply_always(
+                get_allowance_pull_message(deps.storage, env, &vault.owner, pull_amount),
+                get_vault_reply_id(AFTER_ALLOWANCE_PULL_REPLY_ID, vault.id),
+            )));
+    }
+
+    add_swap_submessage(
+        deps,
+        env,
+        response,
+        &vault,
+        exchange_contract_address.to_string(),
+        route,
+        adjusted_swap_amount,
+        adjusted_minimum_receive_amount,
+    )
+}
+
+pub fn swap_after_allowance_pull(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, get_vault_id_from_reply_id(reply.id))?;
+    let pending_swap = PENDING_SWAP_CACHE.load(deps.storage, vault.id.into())?;
+
+    PENDING_SWAP_CACHE.remove(deps.storage, vault.id.into());
+
+    let response = Response::new()
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("allowance_pull", pending_swap.pull_amount.to_string());
+
+    match reply.result {
+        SubMsgResult::Ok(_) => {
+            let vault = deposit_into_vault(deps.storage, &env, vault, pending_swap.pull_amount)?;
+            let config = get_config(deps.storage)?;
+
+            add_swap_submessage(
+                deps,
+                &env,
+                response.add_attribute("allowance_pull_succeeded", "true"),
+                &vault,
+                config.exchange_contract_address.to_string(),
+                pending_swap.route,
+                pending_swap.swap_amount,
+                pending_swap.minimum_receive_amount,
+            )
+        }
+        SubMsgResult::Err(error) => {
+            let response = skip_execution(
+                deps.storage,
+                env.block,
+                &vault,
+                response,
+                ExecutionSkippedReason::AllowancePullFailed,
+            )?;
+
+            Ok(response
+                .add_attribute("allowance_pull_succeeded", "false")
+                .add_attribute("execution_skipped", "allowance_pull_failed")
+                .add_attribute("error", error))
+        }
+    }
+}
+
+fn claim_staking_rewards(
+    deps: DepsMut,
+    env: &Env,
+    response: Response,
+    vault: &Vault,
+    route: Option<Binary>,
+    belief_price: Decimal,
+) -> Result<Response, ContractError> {
+    let validators = deps
+        .querier
+        .query_all_delegations(vault.owner.clone())?
+        .into_iter()
+        .map(|delegation| delegation.validator)
+        .collect::<Vec<String>>();
+
+    if validators.is_empty() {
+        let response = skip_execution(
+            deps.storage,
+            env.block.clone(),
+            vault,
+            response,
+            ExecutionSkippedReason::NoStakingRewardsClaimed,
+        )?;
+
+        return Ok(response.add_attribute("execution_skipped", "no_delegations"));
+    }
+
+    STAKING_REWARDS_CLAIM_CACHE.save(
+        deps.storage,
+        vault.id.into(),
+        &StakingRewardsClaimCache {
+            belief_price,
+            route,
+        },
+    )?;
+
     Ok(response
-        .add_attribute("min_rcv", adjusted_minimum_receive_amount.to_string())
-        .add_attribute("swap", adjusted_swap_amount.to_string())
+        .add_attribute("claim_staking_rewards", validators.len().to_string())
         .add_submessage(SubMsg::reply_always(
-            WasmMsg::Execute {
-                contract_addr: config.exchange_contract_address.to_string(),
-                msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+            get_withdraw_delegator_rewards_message(env, &vault.owner, validators),
+            get_vault_reply_id(AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, vault.id),
+        )))
+}
+
+pub fn swap_claimed_staking_rewards(
+    deps: DepsMut,
+    env: Env,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, get_vault_id_from_reply_id(reply.id))?;
+    let claim = STAKING_REWARDS_CLAIM_CACHE.load(deps.storage, vault.id.into())?;
+
+    STAKING_REWARDS_CLAIM_CACHE.remove(deps.storage, vault.id.into());
+
+    let response = Response::new().add_attribute("vault_id", vault.id);
+
+    let claimed_amount = match reply.result {
+        SubMsgResult::Ok(SubMsgResponse { data, .. }) => {
+            get_claimed_staking_rewards(data, &vault.get_swap_denom())?
+        }
+        SubMsgResult::Err(error) => {
+            let response = skip_execution(
+                deps.storage,
+                env.block,
+                &vault,
+                response,
+                ExecutionSkippedReason::StakingRewardsClaimFailed,
+            )?;
+
+            return Ok(response
+                .add_attribute("execution_skipped", "staking_rewards_claim_failed")
+                .add_attribute("error", error));
+        }
+    };
+
+    if claimed_amount.is_zero() {
+        let response = skip_execution(
+            deps.storage,
+            env.block,
+            &vault,
+            response,
+            ExecutionSkippedReason::NoStakingRewardsClaimed,
+        )?;
+
+        return Ok(response.add_attribute("execution_skipped", "no_staking_rewards_claimed"));
+    }
+
+    let config = get_config(deps.storage)?;
+
+    swap_or_skip_execution(
+        deps,
+        &env,
+        response.add_attribute("staking_rewards_claimed", claimed_amount.to_string()),
+        Vault {
+            swap_amount: claimed_amount,
+            minimum_receive_amount: vault.minimum_receive_amount.map(|minimum_receive_amount| {
+                Decimal::from_ratio(claimed_amount, vault.swap_amount) * minimum_receive_amount
+            }),
+            ..vault
+        },
+        config.exchange_contract_address,
+        claim.route,
+        claim.belief_price,
+    )
+}
+
+fn skip_execution(
+    storage: &mut dyn Storage,
+    block: BlockInfo,
+    vault: &Vault,
+    response: Response,
+    reason: ExecutionSkippedReason,
+) -> Result<Response, ContractError> {
+    record_skipped_execution(storage, vault.id)?;
+
+    create_event(
+        storage,
+        EventBuilder::new(
+            vault.id,
+            block,
+            EventData::DcaVaultExecutionSkipped {
+                reason: reason.clone(),
+            },
+        ),
+    )?;
+
+    Ok(response.add_submessages(get_hook_message(
+        storage,
+        vault,
+        VaultHookNotification::ExecutionSkipped { reason },
+    )?))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn add_swap_submessage(
+    deps: DepsMut,
+    env: &Env,
+    response: Response,
+    vault: &Vault,
+    exchange_contract_address: String,
+    route: Option<Binary>,
+    swap_amount: Coin,
+    minimum_receive_amount: Uint128,
+) -> Result<Response, ContractError> {
+    let swap_cache = SwapCache {
+        swap_denom_balance: query_balance(
+            &deps.querier,
+            deps.storage,
+            &env.contract.address,
+            vault.get_swap_denom(),
+        )?,
+        receive_denom_balance: query_balance(
+            &deps.querier,
+            deps.storage,
+            &env.contract.address,
+            vault.target_denom.clone(),
+        )?,
+    };
+
+    SWAP_CACHE.save(deps.storage, vault.id.into(), &swap_cache)?;
+
+    Ok(response
+        .add_attribute("min_rcv", minimum_receive_amount.to_string())
+        .add_attribute("swap", swap_amount.to_string())
+        .add_submessage(SubMsg::reply_always(
+            get_execute_message(
+                deps.storage,
+                exchange_contract_address,
+                to_json_binary(&ExchangeExecuteMsg::Swap {
                     minimum_receive_amount: Coin {
-                        amount: adjusted_minimum_receive_amount,
-                        denom: vault.target_denom,
+                        amount: minimum_receive_amount,
+                        denom: vault.target_denom.clone(),
                     },
                     route,
                 })?,
-                funds: vec![adjusted_swap_amount],
-            },
-            AFTER_SWAP_REPLY_ID,
+                swap_amount,
+            ),
+            get_vault_reply_id(AFTER_SWAP_REPLY_ID, vault.id),
         )))
 }
 
@@ -309,25 +777,83 @@ mod execute_trigger_tests {
     use crate::constants::{
         AFTER_SWAP_REPLY_ID, HALF_DECIMAL, ONE, ONE_MICRON, TEN, THREE_DECIMAL, TWO_MICRONS,
     };
+    use crate::handlers::disburse_escrow::disburse_escrow_handler;
     use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
     use crate::helpers::fees::{get_automation_fee_rate, get_swap_fee_rate};
     use crate::helpers::vault::get_swap_amount;
     use crate::msg::ExecuteMsg;
     use crate::state::config::update_config;
+    use crate::state::cw20_denoms::save_cw20_denom;
+    use crate::state::disburse_escrow_tasks::get_disburse_escrow_task_due_date;
+    use crate::state::price_history::get_moving_average_price;
     use crate::state::swap_adjustments::update_swap_adjustment;
     use crate::state::triggers::delete_trigger;
     use crate::state::vaults::get_vault;
     use crate::tests::helpers::{instantiate_contract, setup_vault};
-    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK};
+    use crate::tests::mocks::{
+        calc_mock_dependencies, CalcMockQuerier, ADMIN, DENOM_UKUJI, DENOM_UUSK, USER, VALIDATOR,
+    };
     use crate::types::config::Config;
-    use crate::types::event::{Event, EventData, ExecutionSkippedReason};
+    use crate::types::event::{Event, EventData, ExecutionSkippedReason, VaultEndReason};
+    use crate::types::exit_condition::ExitCondition;
+    use crate::types::funding_mode::FundingMode;
     use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
     use crate::types::position_type::PositionType;
+    use crate::types::price_band::PriceBand;
     use crate::types::swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy};
     use crate::types::trigger::TriggerConfiguration;
     use crate::types::vault::{Vault, VaultStatus};
-    use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{to_json_binary, Coin, Decimal, SubMsg, Uint128, WasmMsg};
+    use cosmos_sdk_proto::cosmos::authz::v1beta1::MsgExecResponse;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmos_sdk_proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorRewardResponse;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, Attribute, BankMsg, Coin, ContractResult, Decimal,
+        FullDelegation, OwnedDeps, Reply, SubMsg, SubMsgResponse, SubMsgResult, SystemResult,
+        Uint128, WasmMsg, WasmQuery,
+    };
+    use cw20::{BalanceResponse, Cw20ExecuteMsg};
+    use prost::Message;
+
+    const CW20_TOKEN: &str = "cw20-token";
+
+    fn with_delegations(
+        deps: &mut OwnedDeps<MockStorage, MockApi, CalcMockQuerier>,
+        validators: Vec<&str>,
+    ) {
+        deps.querier.update_staking(
+            DENOM_UKUJI,
+            &[],
+            &validators
+                .into_iter()
+                .map(|validator| FullDelegation {
+                    delegator: Addr::unchecked(USER),
+                    validator: validator.to_string(),
+                    amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                    can_redelegate: Coin::new(0, DENOM_UKUJI),
+                    accumulated_rewards: vec![],
+                })
+                .collect::<Vec<FullDelegation>>(),
+        );
+    }
+
+    fn staking_rewards_response(amount: Uint128) -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(Binary::from(
+                MsgExecResponse {
+                    results: vec![MsgWithdrawDelegatorRewardResponse {
+                        amount: vec![ProtoCoin {
+                            denom: DENOM_UKUJI.to_string(),
+                            amount: amount.to_string(),
+                        }],
+                    }
+                    .encode_to_vec()],
+                }
+                .encode_to_vec(),
+            )),
+        })
+    }
 
     #[test]
     fn when_contract_is_paused_should_fail() {
@@ -512,9 +1038,10 @@ mod execute_trigger_tests {
 
         execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(
             events.first().unwrap(),
@@ -594,8 +1121,7 @@ mod execute_trigger_tests {
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let fee_rate = get_swap_fee_rate(deps.as_mut().storage, &vault.swap_adjustment_strategy)
-            .unwrap()
+        let fee_rate = get_swap_fee_rate(deps.as_mut().storage, &vault).unwrap()
             + get_automation_fee_rate(deps.as_mut().storage, &vault).unwrap();
 
         let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
@@ -683,9 +1209,10 @@ mod execute_trigger_tests {
 
         execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(&Event {
             id: 2,
@@ -699,7 +1226,7 @@ mod execute_trigger_tests {
     }
 
     #[test]
-    fn with_swap_adjusted_to_zero_should_not_send_swap_message() {
+    fn with_value_averaging_strategy_should_advance_target_value() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -710,24 +1237,33 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
-                    base_receive_amount: ONE,
-                    multiplier: Decimal::percent(500),
-                    increase_only: false,
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution: ONE,
+                    target_value: ONE,
                 }),
                 ..Vault::default()
             },
         );
 
-        deps.querier.update_fin_price(&THREE_DECIMAL);
-
         let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
 
-        assert!(response.messages.is_empty());
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::ValueAveraging {
+                target_growth_per_execution: ONE,
+                target_value: ONE + ONE,
+            })
+        );
+        assert!(response.attributes.contains(&Attribute::new(
+            "swap",
+            Coin::new((ONE + ONE).into(), DENOM_UKUJI).to_string()
+        )));
     }
 
     #[test]
-    fn with_rwa_swap_adjustment_strategy_should_adjust_swap_amount() {
+    fn with_value_averaging_strategy_ahead_of_target_should_skip_and_still_advance_target_value() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -738,26 +1274,167 @@ mod execute_trigger_tests {
             deps.as_mut(),
             env.clone(),
             Vault {
-                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
-                escrowed_amount: Coin::new(0, DENOM_UUSK),
-                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
-                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution: ONE,
+                    target_value: ONE,
+                }),
                 ..Vault::default()
             },
         );
 
-        let swap_adjustment = Decimal::percent(150);
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
 
-        [PositionType::Enter, PositionType::Exit]
-            .into_iter()
-            .for_each(|position_type| {
-                update_swap_adjustment(
-                    deps.as_mut().storage,
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            updated_vault.swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::ValueAveraging {
+                target_growth_per_execution: ONE,
+                target_value: ONE + ONE,
+            })
+        );
+    }
+
+    #[test]
+    fn should_record_belief_price_for_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        deps.querier.update_fin_price(&THREE_DECIMAL);
+
+        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        assert_eq!(
+            get_moving_average_price(
+                deps.as_ref().storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                1
+            )
+            .unwrap(),
+            Some(THREE_DECIMAL)
+        );
+    }
+
+    #[test]
+    fn with_caller_supplied_route_should_record_canonical_price_for_pair() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        deps.querier.update_wasm(|query| {
+            SystemResult::Ok(ContractResult::Ok(match query {
+                WasmQuery::Smart { msg, .. } => match from_json::<ExchangeQueryMsg>(msg).unwrap() {
+                    ExchangeQueryMsg::GetTwapToNow { route, .. } => to_json_binary(&match route {
+                        Some(_) => Decimal::percent(1000),
+                        None => THREE_DECIMAL,
+                    })
+                    .unwrap(),
+                    ExchangeQueryMsg::GetExpectedReceiveAmount {
+                        swap_amount,
+                        target_denom,
+                        ..
+                    } => {
+                        to_json_binary(&Coin::new(swap_amount.amount.into(), target_denom)).unwrap()
+                    }
+                    _ => unimplemented!(),
+                },
+                _ => unimplemented!(),
+            }))
+        });
+
+        execute_trigger_handler(
+            deps.as_mut(),
+            env,
+            vault.id,
+            Some(Binary::from(b"manipulated-route")),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_moving_average_price(
+                deps.as_ref().storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                1
+            )
+            .unwrap(),
+            Some(THREE_DECIMAL)
+        );
+    }
+
+    #[test]
+    fn with_swap_adjusted_to_zero_should_not_send_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::WeightedScale {
+                    base_receive_amount: ONE,
+                    multiplier: Decimal::percent(500),
+                    increase_only: false,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&THREE_DECIMAL);
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        assert!(response.messages.is_empty());
+    }
+
+    #[test]
+    fn with_rwa_swap_adjustment_strategy_should_adjust_swap_amount() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(0, DENOM_UUSK),
+                performance_assessment_strategy: Some(PerformanceAssessmentStrategy::default()),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        let swap_adjustment = Decimal::percent(150);
+
+        [PositionType::Enter, PositionType::Exit]
+            .into_iter()
+            .for_each(|position_type| {
+                update_swap_adjustment(
+                    deps.as_mut().storage,
                     SwapAdjustmentStrategy::RiskWeightedAverage {
                         model_id: 30,
                         base_denom: BaseDenom::Bitcoin,
                         position_type,
                     },
+                    &Addr::unchecked(ADMIN),
                     swap_adjustment,
                     env.block.time,
                 )
@@ -845,9 +1522,10 @@ mod execute_trigger_tests {
 
         execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(
             events.get(1).unwrap(),
@@ -889,9 +1567,10 @@ mod execute_trigger_tests {
 
         execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert!(events.contains(&Event {
             id: 2,
@@ -929,8 +1608,7 @@ mod execute_trigger_tests {
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
 
-        let fee_rate = get_swap_fee_rate(deps.as_ref().storage, &vault.swap_adjustment_strategy)
-            .unwrap()
+        let fee_rate = get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap()
             + get_automation_fee_rate(deps.as_ref().storage, &vault).unwrap();
 
         let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
@@ -996,6 +1674,60 @@ mod execute_trigger_tests {
         })));
     }
 
+    #[test]
+    fn for_inactive_vault_with_pending_disburse_escrow_task_should_disburse_escrow() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Inactive,
+                balance: Coin::new(0, DENOM_UKUJI),
+                escrow_level: Decimal::percent(5),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                    },
+                ),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+                ..Vault::default()
+            },
+        );
+
+        save_disburse_escrow_task(
+            deps.as_mut().storage,
+            vault.id,
+            env.block.time.plus_seconds(60 * 60 * 24 * 30),
+        )
+        .unwrap();
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        disburse_escrow_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(env.contract.address.as_str(), &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        assert!(get_vault(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .escrowed_amount
+            .amount
+            .is_zero());
+    }
+
     #[test]
     fn for_inactive_vault_with_unfinished_performance_assessment_should_not_disburse_escrow() {
         let mut deps = calc_mock_dependencies();
@@ -1343,9 +2075,10 @@ mod execute_trigger_tests {
 
         execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(
             events.get(1).unwrap(),
@@ -1364,7 +2097,7 @@ mod execute_trigger_tests {
     }
 
     #[test]
-    fn should_create_new_trigger_if_price_threshold_exceeded() {
+    fn with_hook_should_notify_hook_of_skipped_execution() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1377,33 +2110,73 @@ mod execute_trigger_tests {
             Vault {
                 swap_amount: ONE,
                 minimum_receive_amount: Some(ONE + ONE),
+                hook: Some(Addr::unchecked("hook")),
                 ..Vault::default()
             },
         );
 
-        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
 
-        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        assert!(response.messages.contains(
+            &get_hook_message(
+                deps.as_ref().storage,
+                &vault,
+                VaultHookNotification::ExecutionSkipped {
+                    reason: ExecutionSkippedReason::PriceThresholdExceeded {
+                        price: Decimal::one()
+                    }
+                }
+            )
+            .unwrap()
+            .unwrap()
+        ));
+    }
 
-        let old_target_time = match vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+    #[test]
+    fn should_skip_execution_if_maximum_receive_amount_exceeded() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
 
-        let new_target_time = match updated_vault.trigger.unwrap() {
-            TriggerConfiguration::Time { target_time } => target_time,
-            _ => panic!("wrong trigger type"),
-        };
+        instantiate_contract(deps.as_mut(), env.clone(), info);
 
-        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE + ONE,
+                maximum_receive_amount: Some(ONE),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(response.messages.is_empty());
         assert_eq!(
-            new_target_time.seconds(),
-            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+            events.get(1).unwrap(),
+            &Event {
+                id: 2,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::DcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::PriceBandBreached {
+                        price: Decimal::one(),
+                        edge: PriceBandEdge::Lower,
+                    }
+                }
+            }
         );
     }
 
     #[test]
-    fn should_trigger_execution_if_price_threshold_not_exceeded() {
+    fn should_skip_execution_if_price_band_upper_edge_breached() {
         let mut deps = calc_mock_dependencies();
         let env = mock_env();
         let info = mock_info(ADMIN, &[]);
@@ -1415,32 +2188,1089 @@ mod execute_trigger_tests {
             env.clone(),
             Vault {
                 swap_amount: ONE,
-                minimum_receive_amount: Some(ONE / Uint128::new(2)),
+                price_band: Some(PriceBand {
+                    lower_deviation: Decimal::percent(1),
+                    upper_deviation: Decimal::percent(1),
+                }),
                 ..Vault::default()
             },
         );
 
-        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
 
-        let config = get_config(deps.as_ref().storage).unwrap();
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
+        assert!(response.messages.is_empty());
         assert_eq!(
-            response.messages.first().unwrap(),
-            &SubMsg::reply_always(
-                WasmMsg::Execute {
-                    contract_addr: config.exchange_contract_address.to_string(),
-                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
-                        minimum_receive_amount: Coin {
-                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
-                            denom: vault.target_denom.clone(),
-                        },
-                        route: vault.route.clone()
-                    })
-                    .unwrap(),
-                    funds: vec![Coin::new(vault.swap_amount.into(), vault.get_swap_denom())]
-                },
-                AFTER_SWAP_REPLY_ID,
-            )
+            events.get(1).unwrap(),
+            &Event {
+                id: 2,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::DcaVaultExecutionSkipped {
+                    reason: ExecutionSkippedReason::PriceBandBreached {
+                        price: Decimal::from_ratio(ONE, ONE * Decimal::percent(95)),
+                        edge: PriceBandEdge::Upper,
+                    }
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn should_trigger_execution_if_price_is_within_price_band() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                maximum_receive_amount: Some(ONE + ONE),
+                price_band: Some(PriceBand {
+                    lower_deviation: Decimal::percent(10),
+                    upper_deviation: Decimal::percent(10),
+                }),
+                ..Vault::default()
+            },
         );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, AFTER_SWAP_REPLY_ID);
+    }
+
+    #[test]
+    fn should_create_new_trigger_if_price_threshold_exceeded() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                minimum_receive_amount: Some(ONE + ONE),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        let old_target_time = match vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        let new_target_time = match updated_vault.trigger.unwrap() {
+            TriggerConfiguration::Time { target_time } => target_time,
+            _ => panic!("wrong trigger type"),
+        };
+
+        assert_eq!(old_target_time.seconds(), env.block.time.seconds());
+        assert_eq!(
+            new_target_time.seconds(),
+            env.block.time.plus_seconds(24 * 60 * 60).seconds()
+        );
+    }
+
+    #[test]
+    fn should_trigger_execution_if_price_threshold_not_exceeded() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                minimum_receive_amount: Some(ONE / Uint128::new(2)),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: Coin {
+                            amount: vault.minimum_receive_amount.unwrap_or(Uint128::zero()),
+                            denom: vault.target_denom.clone(),
+                        },
+                        route: vault.route.clone()
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(vault.swap_amount.into(), vault.get_swap_denom())]
+                },
+                AFTER_SWAP_REPLY_ID,
+            )
+        );
+    }
+
+    #[test]
+    fn with_cw20_swap_denom_should_create_cw20_send_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } => {
+                SystemResult::Ok(ContractResult::Ok(if contract_addr == CW20_TOKEN {
+                    to_json_binary(&BalanceResponse { balance: TEN }).unwrap()
+                } else {
+                    match from_json::<ExchangeQueryMsg>(msg).unwrap() {
+                        ExchangeQueryMsg::GetTwapToNow { .. } => {
+                            to_json_binary(&Decimal::one()).unwrap()
+                        }
+                        ExchangeQueryMsg::GetExpectedReceiveAmount {
+                            swap_amount,
+                            target_denom,
+                            ..
+                        } => to_json_binary(&Coin::new(swap_amount.amount.into(), target_denom))
+                            .unwrap(),
+                        _ => panic!("Unsupported exchange query"),
+                    }
+                }))
+            }
+            _ => panic!("Unsupported query"),
+        });
+
+        save_cw20_denom(deps.as_mut().storage, CW20_TOKEN).unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(TEN.into(), CW20_TOKEN),
+                deposited_amount: Coin::new(TEN.into(), CW20_TOKEN),
+                swapped_amount: Coin::new(0, CW20_TOKEN),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: CW20_TOKEN.to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                        contract: config.exchange_contract_address.to_string(),
+                        amount: vault.swap_amount,
+                        msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                            minimum_receive_amount: Coin {
+                                amount: Uint128::zero(),
+                                denom: vault.target_denom.clone(),
+                            },
+                            route: vault.route.clone()
+                        })
+                        .unwrap(),
+                    })
+                    .unwrap(),
+                    funds: vec![]
+                },
+                AFTER_SWAP_REPLY_ID,
+            )
+        );
+
+        assert_eq!(
+            SWAP_CACHE
+                .load(deps.as_ref().storage, vault.id.into())
+                .unwrap()
+                .swap_denom_balance,
+            Coin::new(TEN.into(), CW20_TOKEN)
+        );
+    }
+
+    #[test]
+    fn with_max_executions_reached_should_refund_balance_and_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(3),
+                execution_count: 3,
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn with_end_time_reached_should_refund_balance_and_make_vault_inactive() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn with_end_condition_reached_should_publish_vault_ended_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(2),
+                execution_count: 2,
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert_eq!(
+            events,
+            vec![Event {
+                id: 1,
+                resource_id: vault.id,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                data: EventData::DcaVaultEnded {
+                    reason: VaultEndReason::MaxExecutionsReached,
+                    execution_count: 2,
+                    refunded: vault.balance,
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn with_end_condition_reached_and_escrowed_funds_should_save_disburse_escrow_task() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                end_time: Some(env.block.time.minus_seconds(10)),
+                escrow_level: Decimal::percent(5),
+                escrowed_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                        received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        assert_eq!(
+            get_disburse_escrow_task_due_date(deps.as_ref().storage, vault.id)
+                .unwrap()
+                .map(|due_date| due_date.seconds()),
+            Some(env.block.time.seconds())
+        );
+    }
+
+    #[test]
+    fn with_max_executions_not_reached_should_create_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                max_executions: Some(3),
+                execution_count: 2,
+                end_time: Some(env.block.time.plus_seconds(10)),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, AFTER_SWAP_REPLY_ID);
+        assert!(get_vault(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .trigger
+            .is_some());
+    }
+
+    #[test]
+    fn with_stop_loss_triggered_and_cancel_action_should_refund_balance_and_cancel_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(ExitCondition {
+                    price: Decimal::percent(110),
+                    action: ExitAction::CancelVault,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: vault.owner.to_string(),
+                amount: vec![vault.balance.clone()],
+            })]
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Cancelled);
+        assert_eq!(updated_vault.balance, Coin::new(0, vault.get_swap_denom()));
+        assert_eq!(updated_vault.trigger, None);
+    }
+
+    #[test]
+    fn with_stop_loss_triggered_should_publish_stop_loss_triggered_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                stop_loss: Some(ExitCondition {
+                    price: Decimal::percent(110),
+                    action: ExitAction::CancelVault,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultStopLossTriggered {
+                price: Decimal::one(),
+                action: ExitAction::CancelVault,
+            }
+        }));
+        assert!(events.contains(&Event {
+            id: 3,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultCancelled {}
+        }));
+    }
+
+    #[test]
+    fn with_take_profit_triggered_and_swap_action_should_swap_remaining_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                slippage_tolerance: Decimal::percent(5),
+                take_profit: Some(ExitCondition {
+                    price: Decimal::percent(90),
+                    action: ExitAction::SwapRemainingBalance,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: Coin {
+                            amount: vault.balance.amount * Decimal::percent(95),
+                            denom: vault.target_denom.clone(),
+                        },
+                        route: vault.route.clone()
+                    })
+                    .unwrap(),
+                    funds: vec![vault.balance.clone()]
+                },
+                AFTER_SWAP_REPLY_ID,
+            )]
+        );
+    }
+
+    #[test]
+    fn with_take_profit_triggered_and_swap_action_at_zero_belief_price_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                slippage_tolerance: Decimal::percent(5),
+                take_profit: Some(ExitCondition {
+                    price: Decimal::percent(90),
+                    action: ExitAction::SwapRemainingBalance,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        const ZERO_PRICE: Decimal = Decimal::zero();
+        deps.querier.update_fin_price(&ZERO_PRICE);
+
+        let err = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot calculate minimum receive amount at belief price 0"
+        );
+    }
+
+    #[test]
+    fn with_take_profit_triggered_should_publish_take_profit_triggered_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                take_profit: Some(ExitCondition {
+                    price: Decimal::percent(90),
+                    action: ExitAction::SwapRemainingBalance,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultTakeProfitTriggered {
+                price: Decimal::one(),
+                action: ExitAction::SwapRemainingBalance,
+            }
+        }));
+    }
+
+    #[test]
+    fn with_exit_conditions_not_triggered_should_create_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: ONE,
+                stop_loss: Some(ExitCondition {
+                    price: Decimal::percent(90),
+                    action: ExitAction::CancelVault,
+                }),
+                take_profit: Some(ExitCondition {
+                    price: Decimal::percent(110),
+                    action: ExitAction::SwapRemainingBalance,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages[0].id, AFTER_SWAP_REPLY_ID);
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+        assert!(updated_vault.trigger.is_some());
+    }
+
+    #[test]
+    fn with_allowance_funded_vault_should_create_allowance_pull_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::Allowance {
+                    swap_denom: DENOM_UKUJI.to_string(),
+                },
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                get_allowance_pull_message(
+                    deps.as_ref().storage,
+                    &env,
+                    &vault.owner,
+                    Coin::new(vault.swap_amount.into(), DENOM_UKUJI)
+                ),
+                get_vault_reply_id(AFTER_ALLOWANCE_PULL_REPLY_ID, vault.id),
+            )]
+        );
+    }
+
+    #[test]
+    fn with_allowance_funded_vault_and_partial_balance_should_only_pull_shortfall() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE_MICRON.into(), DENOM_UKUJI),
+                swap_amount: ONE,
+                funding_mode: FundingMode::Allowance {
+                    swap_denom: DENOM_UKUJI.to_string(),
+                },
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env, vault.id, None).unwrap();
+
+        let pending_swap = PENDING_SWAP_CACHE
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        assert_eq!(
+            pending_swap.pull_amount,
+            Coin::new((ONE - ONE_MICRON).into(), DENOM_UKUJI)
+        );
+        assert_eq!(pending_swap.swap_amount, Coin::new(ONE.into(), DENOM_UKUJI));
+    }
+
+    #[test]
+    fn with_allowance_funded_cw20_vault_should_create_transfer_from_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        save_cw20_denom(deps.as_mut().storage, CW20_TOKEN).unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, CW20_TOKEN),
+                deposited_amount: Coin::new(0, CW20_TOKEN),
+                swapped_amount: Coin::new(0, CW20_TOKEN),
+                funding_mode: FundingMode::Allowance {
+                    swap_denom: CW20_TOKEN.to_string(),
+                },
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        assert_eq!(
+            response.messages.first().unwrap().msg,
+            WasmMsg::Execute {
+                contract_addr: CW20_TOKEN.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: vault.owner.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount: vault.swap_amount,
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()
+        );
+    }
+
+    #[test]
+    fn after_successful_allowance_pull_should_deposit_and_create_swap_message() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::Allowance {
+                    swap_denom: DENOM_UKUJI.to_string(),
+                },
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let response = swap_after_allowance_pull(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: get_vault_reply_id(AFTER_ALLOWANCE_PULL_REPLY_ID, vault.id),
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            updated_vault.balance,
+            Coin::new(vault.swap_amount.into(), DENOM_UKUJI)
+        );
+        assert_eq!(
+            response.messages.first().unwrap(),
+            &SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: config.exchange_contract_address.to_string(),
+                    msg: to_json_binary(&ExchangeExecuteMsg::Swap {
+                        minimum_receive_amount: Coin::new(0, vault.target_denom.clone()),
+                        route: vault.route.clone()
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(vault.swap_amount.into(), DENOM_UKUJI)]
+                },
+                get_vault_reply_id(AFTER_SWAP_REPLY_ID, vault.id),
+            )
+        );
+        assert!(PENDING_SWAP_CACHE
+            .may_load(deps.as_ref().storage, vault.id.into())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn after_failed_allowance_pull_should_publish_execution_skipped_event() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::Allowance {
+                    swap_denom: DENOM_UKUJI.to_string(),
+                },
+                ..Vault::default()
+            },
+        );
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let response = swap_after_allowance_pull(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: get_vault_reply_id(AFTER_ALLOWANCE_PULL_REPLY_ID, vault.id),
+                result: SubMsgResult::Err(String::from("insufficient allowance")),
+            },
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(response.messages.is_empty());
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::AllowancePullFailed
+            }
+        }));
+        assert!(get_vault(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .balance
+            .amount
+            .is_zero());
+    }
+
+    #[test]
+    fn with_staking_rewards_funded_vault_should_claim_rewards_from_all_validators() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::StakingRewards,
+                ..Vault::default()
+            },
+        );
+
+        with_delegations(&mut deps, vec![VALIDATOR, "validator-2"]);
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                get_withdraw_delegator_rewards_message(
+                    &env,
+                    &vault.owner,
+                    vec![VALIDATOR.to_string(), "validator-2".to_string()]
+                ),
+                get_vault_reply_id(AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, vault.id),
+            )]
+        );
+    }
+
+    #[test]
+    fn with_staking_rewards_funded_vault_and_no_delegations_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::StakingRewards,
+                ..Vault::default()
+            },
+        );
+
+        let response = execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(response.messages.is_empty());
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::NoStakingRewardsClaimed
+            }
+        }));
+    }
+
+    #[test]
+    fn after_staking_rewards_claim_should_pull_claimed_rewards() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::StakingRewards,
+                ..Vault::default()
+            },
+        );
+
+        with_delegations(&mut deps, vec![VALIDATOR]);
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let claimed_amount = Uint128::new(234567);
+
+        let response = swap_claimed_staking_rewards(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: get_vault_reply_id(AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, vault.id),
+                result: staking_rewards_response(claimed_amount),
+            },
+        )
+        .unwrap();
+
+        let pending_swap = PENDING_SWAP_CACHE
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        assert_eq!(
+            response.messages,
+            vec![SubMsg::reply_always(
+                get_allowance_pull_message(
+                    deps.as_ref().storage,
+                    &env,
+                    &vault.owner,
+                    Coin::new(claimed_amount.into(), DENOM_UKUJI)
+                ),
+                get_vault_reply_id(AFTER_ALLOWANCE_PULL_REPLY_ID, vault.id),
+            )]
+        );
+        assert_eq!(
+            pending_swap.swap_amount,
+            Coin::new(claimed_amount.into(), DENOM_UKUJI)
+        );
+    }
+
+    #[test]
+    fn after_staking_rewards_claim_with_no_rewards_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::StakingRewards,
+                ..Vault::default()
+            },
+        );
+
+        with_delegations(&mut deps, vec![VALIDATOR]);
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        let response = swap_claimed_staking_rewards(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: get_vault_reply_id(AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, vault.id),
+                result: staking_rewards_response(Uint128::zero()),
+            },
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(response.messages.is_empty());
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::NoStakingRewardsClaimed
+            }
+        }));
+    }
+
+    #[test]
+    fn after_failed_staking_rewards_claim_should_skip_execution() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(0, DENOM_UKUJI),
+                funding_mode: FundingMode::StakingRewards,
+                ..Vault::default()
+            },
+        );
+
+        with_delegations(&mut deps, vec![VALIDATOR]);
+
+        execute_trigger_handler(deps.as_mut(), env.clone(), vault.id, None).unwrap();
+
+        swap_claimed_staking_rewards(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: get_vault_reply_id(AFTER_STAKING_REWARDS_CLAIM_REPLY_ID, vault.id),
+                result: SubMsgResult::Err(String::from("authorization not found")),
+            },
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(&Event {
+            id: 2,
+            resource_id: vault.id,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            data: EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::StakingRewardsClaimFailed
+            }
+        }));
+        assert!(STAKING_REWARDS_CLAIM_CACHE
+            .may_load(deps.as_ref().storage, vault.id.into())
+            .unwrap()
+            .is_none());
     }
 }
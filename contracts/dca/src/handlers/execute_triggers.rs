@@ -0,0 +1,173 @@
+use crate::constants::AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID;
+use crate::error::ContractError;
+use crate::helpers::reply::{get_vault_id_from_reply_id, get_vault_reply_id};
+use crate::helpers::validation::assert_contract_is_not_paused;
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{
+    to_json_binary, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+
+pub fn execute_triggers_handler(
+    deps: DepsMut,
+    env: Env,
+    trigger_ids: Vec<Uint128>,
+) -> Result<Response, ContractError> {
+    assert_contract_is_not_paused(deps.storage)?;
+
+    if trigger_ids.is_empty() {
+        return Err(ContractError::CustomError {
+            val: String::from("must provide at least one trigger id"),
+        });
+    }
+
+    let sub_msgs = trigger_ids
+        .iter()
+        .map(|trigger_id| {
+            Ok(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
+                        trigger_id: *trigger_id,
+                        route: None,
+                    })?,
+                    funds: vec![],
+                },
+                get_vault_reply_id(AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID, *trigger_id),
+            ))
+        })
+        .collect::<Result<Vec<SubMsg>, ContractError>>()?;
+
+    Ok(Response::new()
+        .add_attribute("execute_triggers", "true")
+        .add_attribute("trigger_count", trigger_ids.len().to_string())
+        .add_submessages(sub_msgs))
+}
+
+pub fn log_failed_trigger_execution(reply: Reply) -> Result<Response, ContractError> {
+    let error = match reply.result {
+        SubMsgResult::Ok(_) => String::new(),
+        SubMsgResult::Err(err) => err,
+    };
+
+    Ok(Response::new()
+        .add_attribute("trigger_execution_failed", "true")
+        .add_attribute("vault_id", get_vault_id_from_reply_id(reply.id).to_string())
+        .add_attribute("error", error))
+}
+
+#[cfg(test)]
+mod execute_triggers_tests {
+    use super::*;
+    use crate::state::config::update_config;
+    use crate::tests::helpers::instantiate_contract;
+    use crate::tests::mocks::ADMIN;
+    use crate::types::config::Config;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::SubMsgResponse;
+
+    #[test]
+    fn with_no_trigger_ids_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = execute_triggers_handler(deps.as_mut(), env, vec![]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: must provide at least one trigger id"
+        );
+    }
+
+    #[test]
+    fn when_contract_is_paused_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                paused: true,
+                ..Config::default()
+            },
+        )
+        .unwrap();
+
+        let err = execute_triggers_handler(deps.as_mut(), env, vec![Uint128::one()]).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: contract is paused");
+    }
+
+    #[test]
+    fn executes_each_trigger_in_its_own_sub_message() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let response = execute_triggers_handler(
+            deps.as_mut(),
+            env.clone(),
+            vec![Uint128::new(3), Uint128::new(7)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages,
+            [Uint128::new(3), Uint128::new(7)]
+                .into_iter()
+                .map(|trigger_id| SubMsg::reply_on_error(
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_json_binary(&ExecuteMsg::ExecuteTrigger {
+                            trigger_id,
+                            route: None,
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    get_vault_reply_id(AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID, trigger_id),
+                ))
+                .collect::<Vec<SubMsg>>()
+        );
+    }
+
+    #[test]
+    fn failed_trigger_execution_logs_vault_id_and_error() {
+        let response = log_failed_trigger_execution(Reply {
+            id: get_vault_reply_id(AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID, Uint128::new(7)),
+            result: SubMsgResult::Err("trigger execution time has not yet elapsed".to_string()),
+        })
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "vault_id" && attribute.value == "7"));
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "error"
+                && attribute.value == "trigger execution time has not yet elapsed"));
+    }
+
+    #[test]
+    fn successful_trigger_execution_reply_logs_no_error() {
+        let response = log_failed_trigger_execution(Reply {
+            id: get_vault_reply_id(AFTER_FAILED_TRIGGER_EXECUTION_REPLY_ID, Uint128::new(7)),
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        })
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .iter()
+            .any(|attribute| attribute.key == "error" && attribute.value.is_empty()));
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{msg::BaseDenomsResponse, state::base_denoms::get_base_denom_configs};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_base_denoms_handler(deps: Deps) -> StdResult<BaseDenomsResponse> {
+    Ok(BaseDenomsResponse {
+        base_denoms: get_base_denom_configs(deps.storage)?,
+    })
+}
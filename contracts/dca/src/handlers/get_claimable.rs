@@ -0,0 +1,10 @@
+use crate::{msg::ClaimableResponse, state::claimable_balances::get_claimable_balance};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_claimable_handler(deps: Deps, address: Addr) -> StdResult<ClaimableResponse> {
+    deps.api.addr_validate(address.as_str())?;
+
+    Ok(ClaimableResponse {
+        claimable: get_claimable_balance(deps.storage, address)?,
+    })
+}
@@ -0,0 +1,8 @@
+use crate::{msg::CustomFeesResponse, state::custom_swap_fees::get_custom_swap_fees};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_custom_swap_fees_handler(deps: Deps) -> StdResult<CustomFeesResponse> {
+    Ok(CustomFeesResponse {
+        custom_fees: get_custom_swap_fees(deps.storage)?,
+    })
+}
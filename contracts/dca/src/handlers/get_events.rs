@@ -1,34 +1,26 @@
 use crate::state::config::get_config;
-use crate::state::events::event_store;
-use crate::types::event::Event;
+use crate::state::events::get_events;
+use crate::types::event::EventFilter;
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::EventsResponse};
-use cosmwasm_std::{from_json, Deps, Order, StdResult};
-use cw_storage_plus::Bound;
+use cosmwasm_std::{Deps, StdResult};
 
 pub fn get_events_handler(
     deps: Deps,
     start_after: Option<u64>,
     limit: Option<u16>,
     reverse: Option<bool>,
+    filter: Option<EventFilter>,
 ) -> StdResult<EventsResponse> {
     assert_page_limit_is_valid(limit)?;
 
-    let events = event_store()
-        .range(
-            deps.storage,
-            reverse.map_or(start_after.map(Bound::exclusive), |_| None),
-            reverse.and_then(|_| start_after.map(Bound::exclusive)),
-            reverse.map_or(Order::Ascending, |reverse| match reverse {
-                true => Order::Descending,
-                false => Order::Ascending,
-            }),
-        )
-        .take(
-            limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
-        )
-        .flat_map(|result| result.map(|(_, data)| from_json(data)))
-        .flatten()
-        .collect::<Vec<Event>>();
+    let events = get_events(
+        deps.storage,
+        None,
+        filter.unwrap_or_default(),
+        start_after,
+        limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
+        reverse,
+    )?;
 
     Ok(EventsResponse { events })
 }
@@ -37,18 +29,45 @@ pub fn get_events_handler(
 mod get_events_tests {
     use super::*;
     use crate::{
-        state::events::create_events,
-        tests::{helpers::instantiate_contract, mocks::ADMIN},
-        types::event::EventBuilder,
+        state::{events::create_events, vaults::update_vault},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, USER},
+        },
+        types::{
+            event::{EventBuilder, EventData, ExecutionSkippedReason},
+            vault::Vault,
+        },
     };
-    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, BlockInfo, Timestamp, Uint128,
+    };
+
+    fn event_at(resource_id: Uint128, height: u64, data: EventData) -> EventBuilder {
+        EventBuilder::new(
+            resource_id,
+            BlockInfo {
+                height,
+                time: Timestamp::from_seconds(height * 6),
+                chain_id: "test".to_string(),
+            },
+            data,
+        )
+    }
+
+    fn skipped() -> EventData {
+        EventData::DcaVaultExecutionSkipped {
+            reason: ExecutionSkippedReason::SlippageToleranceExceeded,
+        }
+    }
 
     #[test]
     fn events_are_empty() {
         let mut deps = mock_dependencies();
         instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .events;
 
@@ -70,7 +89,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .events;
 
@@ -92,7 +111,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, None)
+        let events = get_events_handler(deps.as_ref(), None, None, None, None)
             .unwrap()
             .events;
 
@@ -115,7 +134,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, Some(false))
+        let events = get_events_handler(deps.as_ref(), None, None, Some(false), None)
             .unwrap()
             .events;
 
@@ -130,7 +149,7 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, Some(30), None)
+        let events = get_events_handler(deps.as_ref(), None, Some(30), None, None)
             .unwrap()
             .events;
 
@@ -152,7 +171,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(2), None, None)
+        let events = get_events_handler(deps.as_ref(), Some(2), None, None, None)
             .unwrap()
             .events;
 
@@ -174,7 +193,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), None, None, Some(true))
+        let events = get_events_handler(deps.as_ref(), None, None, Some(true), None)
             .unwrap()
             .events;
 
@@ -189,7 +208,7 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(1), Some(30), None)
+        let events = get_events_handler(deps.as_ref(), Some(1), Some(30), None, None)
             .unwrap()
             .events;
 
@@ -212,7 +231,7 @@ mod get_events_tests {
         )
         .unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(3), None, Some(true))
+        let events = get_events_handler(deps.as_ref(), Some(3), None, Some(true), None)
             .unwrap()
             .events;
 
@@ -227,11 +246,202 @@ mod get_events_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events = get_events_handler(deps.as_ref(), Some(36), Some(30), Some(true))
+        let events = get_events_handler(deps.as_ref(), Some(36), Some(30), Some(true), None)
             .unwrap()
             .events;
 
         assert_eq!(events.len(), 30);
         assert_eq!(events.first().unwrap().id, 35);
     }
+
+    #[test]
+    fn events_are_filtered_by_event_type() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        create_events(
+            deps.as_mut().storage,
+            vec![
+                event_at(Uint128::one(), 1, EventData::DcaVaultCancelled {}),
+                event_at(Uint128::one(), 2, skipped()),
+                event_at(Uint128::one(), 3, EventData::DcaVaultCancelled {}),
+            ],
+        )
+        .unwrap();
+
+        let events = get_events_handler(
+            deps.as_ref(),
+            None,
+            None,
+            None,
+            Some(EventFilter {
+                event_type: Some("dca_vault_execution_skipped".to_string()),
+                ..EventFilter::default()
+            }),
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.first().unwrap().id, 2);
+    }
+
+    #[test]
+    fn events_are_filtered_by_time_and_block_range() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        create_events(
+            deps.as_mut().storage,
+            (1..=5)
+                .map(|height| event_at(Uint128::one(), height * 100, skipped()))
+                .collect(),
+        )
+        .unwrap();
+
+        let events = get_events_handler(
+            deps.as_ref(),
+            None,
+            None,
+            None,
+            Some(EventFilter {
+                from_timestamp: Some(Timestamp::from_seconds(1200)),
+                to_block_height: Some(400),
+                ..EventFilter::default()
+            }),
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<u64>>(),
+            vec![2, 3, 4]
+        );
+
+        let events = get_events_handler(
+            deps.as_ref(),
+            None,
+            None,
+            None,
+            Some(EventFilter {
+                from_timestamp: Some(Timestamp::from_seconds(6000)),
+                ..EventFilter::default()
+            }),
+        )
+        .unwrap()
+        .events;
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn events_are_filtered_by_owner_and_event_type_with_pagination() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let other_vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                owner: Addr::unchecked("other-owner"),
+                ..Vault::default()
+            },
+        );
+
+        create_events(
+            deps.as_mut().storage,
+            vec![
+                event_at(vault.id, 1, skipped()),
+                event_at(other_vault.id, 2, skipped()),
+                event_at(vault.id, 3, EventData::DcaVaultCancelled {}),
+                event_at(vault.id, 4, skipped()),
+                event_at(vault.id, 5, skipped()),
+            ],
+        )
+        .unwrap();
+
+        let filter = EventFilter {
+            owner: Some(Addr::unchecked(USER)),
+            event_type: Some("dca_vault_execution_skipped".to_string()),
+            ..EventFilter::default()
+        };
+
+        let events = get_events_handler(deps.as_ref(), None, Some(2), None, Some(filter.clone()))
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<u64>>(),
+            vec![1, 4]
+        );
+
+        let events = get_events_handler(deps.as_ref(), Some(4), Some(2), None, Some(filter))
+            .unwrap()
+            .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<u64>>(),
+            vec![5]
+        );
+    }
+
+    #[test]
+    fn events_filtered_by_owner_follow_vault_ownership_transfers() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+        let other_vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                owner: Addr::unchecked("new-owner"),
+                ..Vault::default()
+            },
+        );
+
+        create_events(
+            deps.as_mut().storage,
+            vec![
+                event_at(vault.id, 1, skipped()),
+                event_at(other_vault.id, 2, skipped()),
+                event_at(vault.id, 3, skipped()),
+            ],
+        )
+        .unwrap();
+
+        update_vault(
+            deps.as_mut().storage,
+            Vault {
+                owner: Addr::unchecked("new-owner"),
+                ..vault
+            },
+        )
+        .unwrap();
+
+        let events_for = |owner: &str, reverse: Option<bool>| {
+            get_events_handler(
+                deps.as_ref(),
+                None,
+                None,
+                reverse,
+                Some(EventFilter {
+                    owner: Some(Addr::unchecked(owner)),
+                    ..EventFilter::default()
+                }),
+            )
+            .unwrap()
+            .events
+            .iter()
+            .map(|event| event.id)
+            .collect::<Vec<u64>>()
+        };
+
+        assert_eq!(events_for("new-owner", None), vec![1, 2, 3]);
+        assert_eq!(events_for("new-owner", Some(true)), vec![3, 2, 1]);
+        assert!(events_for(USER, None).is_empty());
+    }
 }
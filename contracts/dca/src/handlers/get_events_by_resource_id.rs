@@ -1,9 +1,8 @@
 use crate::state::config::get_config;
-use crate::state::events::event_store;
-use crate::types::event::Event;
+use crate::state::events::get_events;
+use crate::types::event::EventFilter;
 use crate::{helpers::validation::assert_page_limit_is_valid, msg::EventsResponse};
-use cosmwasm_std::{from_json, Deps, Order, StdResult, Uint128};
-use cw_storage_plus::Bound;
+use cosmwasm_std::{Deps, StdResult, Uint128};
 
 pub fn get_events_by_resource_id_handler(
     deps: Deps,
@@ -11,28 +10,18 @@ pub fn get_events_by_resource_id_handler(
     start_after: Option<u64>,
     limit: Option<u16>,
     reverse: Option<bool>,
+    filter: Option<EventFilter>,
 ) -> StdResult<EventsResponse> {
     assert_page_limit_is_valid(limit)?;
 
-    let events = event_store()
-        .idx
-        .resource_id
-        .prefix(resource_id.into())
-        .range(
-            deps.storage,
-            reverse.map_or(start_after.map(Bound::exclusive), |_| None),
-            reverse.and_then(|_| start_after.map(Bound::exclusive)),
-            reverse.map_or(Order::Ascending, |reverse| match reverse {
-                true => Order::Descending,
-                false => Order::Ascending,
-            }),
-        )
-        .take(
-            limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
-        )
-        .flat_map(|result| result.map(|(_, data)| from_json(data)))
-        .flatten()
-        .collect::<Vec<Event>>();
+    let events = get_events(
+        deps.storage,
+        Some(resource_id),
+        filter.unwrap_or_default(),
+        start_after,
+        limit.unwrap_or_else(|| get_config(deps.storage).unwrap().default_page_limit) as usize,
+        reverse,
+    )?;
 
     Ok(EventsResponse { events })
 }
@@ -52,10 +41,16 @@ mod get_events_by_resource_id_tests {
         let mut deps = mock_dependencies();
         instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert_eq!(events.len(), 0);
     }
@@ -77,10 +72,16 @@ mod get_events_by_resource_id_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert_eq!(events.len(), 3);
     }
@@ -100,10 +101,16 @@ mod get_events_by_resource_id_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert_eq!(events.first().unwrap().id, 1);
         assert_eq!(events.last().unwrap().id, 3);
@@ -130,6 +137,7 @@ mod get_events_by_resource_id_tests {
             None,
             None,
             Some(false),
+            None,
         )
         .unwrap()
         .events;
@@ -145,10 +153,16 @@ mod get_events_by_resource_id_tests {
 
         create_events(deps.as_mut().storage, vec![EventBuilder::default(); 40]).unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), None, Some(30), None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            Some(30),
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert_eq!(events.len(), 30);
     }
@@ -168,10 +182,16 @@ mod get_events_by_resource_id_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(deps.as_ref(), Uint128::one(), Some(2), None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            Some(2),
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert_eq!(events.len(), 1);
     }
@@ -197,6 +217,7 @@ mod get_events_by_resource_id_tests {
             None,
             None,
             Some(true),
+            None,
         )
         .unwrap()
         .events;
@@ -218,6 +239,7 @@ mod get_events_by_resource_id_tests {
             Some(1),
             Some(30),
             None,
+            None,
         )
         .unwrap()
         .events;
@@ -247,6 +269,7 @@ mod get_events_by_resource_id_tests {
             Some(3),
             None,
             Some(true),
+            None,
         )
         .unwrap()
         .events;
@@ -268,6 +291,7 @@ mod get_events_by_resource_id_tests {
             Some(36),
             Some(30),
             Some(true),
+            None,
         )
         .unwrap()
         .events;
@@ -275,4 +299,52 @@ mod get_events_by_resource_id_tests {
         assert_eq!(events.len(), 30);
         assert_eq!(events.first().unwrap().id, 35);
     }
+
+    #[test]
+    fn events_are_filtered_by_event_type() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        create_events(
+            deps.as_mut().storage,
+            vec![
+                EventBuilder::default(),
+                EventBuilder::new(
+                    Uint128::one(),
+                    mock_env().block,
+                    EventData::DcaVaultPaused {},
+                ),
+                EventBuilder::new(
+                    Uint128::new(2),
+                    mock_env().block,
+                    EventData::DcaVaultPaused {},
+                ),
+                EventBuilder::new(
+                    Uint128::one(),
+                    mock_env().block,
+                    EventData::DcaVaultPaused {},
+                ),
+            ],
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            Uint128::one(),
+            None,
+            None,
+            None,
+            Some(EventFilter {
+                event_type: Some("dca_vault_paused".to_string()),
+                ..EventFilter::default()
+            }),
+        )
+        .unwrap()
+        .events;
+
+        assert_eq!(
+            events.iter().map(|event| event.id).collect::<Vec<u64>>(),
+            vec![2, 4]
+        );
+    }
 }
@@ -0,0 +1,13 @@
+use crate::{msg::ReferralVolumeResponse, state::referrals::get_referral_volume};
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+pub fn get_referral_volume_handler(
+    deps: Deps,
+    referrer: Addr,
+) -> StdResult<ReferralVolumeResponse> {
+    deps.api.addr_validate(referrer.as_str())?;
+
+    Ok(ReferralVolumeResponse {
+        referral_volume: get_referral_volume(deps.storage, referrer)?,
+    })
+}
@@ -0,0 +1,122 @@
+use crate::{
+    msg::StatisticsResponse,
+    state::statistics::{get_active_vault_count, get_pair_statistics, get_total_value_locked},
+};
+use cosmwasm_std::{Deps, StdResult};
+
+pub fn get_statistics_handler(
+    deps: Deps,
+    swap_denom: Option<String>,
+    target_denom: Option<String>,
+) -> StdResult<StatisticsResponse> {
+    Ok(StatisticsResponse {
+        active_vault_count: get_active_vault_count(deps.storage)?,
+        total_value_locked: get_total_value_locked(deps.storage)?,
+        pairs: get_pair_statistics(deps.storage, swap_denom, target_denom)?,
+    })
+}
+
+#[cfg(test)]
+mod get_statistics_tests {
+    use super::*;
+    use crate::{
+        constants::{ONE, TEN},
+        handlers::{cancel_vault::cancel_vault_handler, deposit::deposit_handler},
+        state::statistics::{record_execution_statistics, PairStatistics},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER},
+        },
+        types::vault::{Vault, VaultStatus},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Coin, Uint128,
+    };
+
+    #[test]
+    fn tracks_active_vault_count_and_total_value_locked() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                ..Vault::default()
+            },
+        );
+
+        deposit_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[Coin::new(ONE.into(), DENOM_UKUJI)]),
+            Addr::unchecked(USER),
+            vault.id,
+        )
+        .unwrap();
+
+        let statistics = get_statistics_handler(deps.as_ref(), None, None).unwrap();
+
+        assert_eq!(statistics.active_vault_count, 1);
+        assert_eq!(
+            statistics.total_value_locked,
+            vec![Coin::new((TEN + TEN + ONE).into(), DENOM_UKUJI)]
+        );
+
+        cancel_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let statistics = get_statistics_handler(deps.as_ref(), None, None).unwrap();
+
+        assert_eq!(statistics.active_vault_count, 0);
+        assert_eq!(
+            statistics.total_value_locked,
+            vec![Coin::new(TEN.into(), DENOM_UKUJI)]
+        );
+    }
+
+    #[test]
+    fn filters_pair_statistics() {
+        let mut deps = mock_dependencies();
+
+        record_execution_statistics(
+            deps.as_mut().storage,
+            &Coin::new(100, DENOM_UKUJI),
+            &Coin::new(90, DENOM_UUSK),
+            Uint128::new(2),
+        )
+        .unwrap();
+
+        record_execution_statistics(
+            deps.as_mut().storage,
+            &Coin::new(100, DENOM_UUSK),
+            &Coin::new(110, DENOM_UKUJI),
+            Uint128::new(3),
+        )
+        .unwrap();
+
+        let statistics = get_statistics_handler(
+            deps.as_ref(),
+            Some(DENOM_UKUJI.to_string()),
+            Some(DENOM_UUSK.to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            statistics.pairs,
+            vec![PairStatistics {
+                swap_denom: DENOM_UKUJI.to_string(),
+                target_denom: DENOM_UUSK.to_string(),
+                execution_count: 1,
+                swapped_amount: Uint128::new(100),
+                received_amount: Uint128::new(90),
+                fee_amount: Uint128::new(2),
+            }]
+        );
+    }
+}
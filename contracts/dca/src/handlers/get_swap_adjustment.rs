@@ -1,5 +1,5 @@
 use crate::{
-    state::swap_adjustments::get_swap_adjustment,
+    state::{config::get_config, swap_adjustments::get_swap_adjustment},
     types::swap_adjustment_strategy::SwapAdjustmentStrategy,
 };
 use cosmwasm_std::{Decimal, Deps, Env, StdResult};
@@ -9,5 +9,10 @@ pub fn get_swap_adjustment_handler(
     env: &Env,
     strategy: SwapAdjustmentStrategy,
 ) -> StdResult<Decimal> {
-    Ok(get_swap_adjustment(deps.storage, strategy, env.block.time))
+    Ok(get_swap_adjustment(
+        deps.storage,
+        strategy,
+        &get_config(deps.storage)?,
+        env.block.time,
+    ))
 }
@@ -0,0 +1,25 @@
+use crate::{
+    msg::SwapAdjustmentSubmissionsResponse,
+    state::{
+        config::get_config,
+        swap_adjustments::{get_swap_adjustment, get_swap_adjustment_submissions},
+    },
+    types::swap_adjustment_strategy::SwapAdjustmentStrategy,
+};
+use cosmwasm_std::{Deps, Env, StdResult};
+
+pub fn get_swap_adjustment_submissions_handler(
+    deps: Deps,
+    env: Env,
+    strategy: SwapAdjustmentStrategy,
+) -> StdResult<SwapAdjustmentSubmissionsResponse> {
+    Ok(SwapAdjustmentSubmissionsResponse {
+        submissions: get_swap_adjustment_submissions(deps.storage, strategy.clone())?,
+        value: get_swap_adjustment(
+            deps.storage,
+            strategy,
+            &get_config(deps.storage)?,
+            env.block.time,
+        ),
+    })
+}
@@ -31,7 +31,7 @@ pub fn get_vault_performance_handler(
         }),
         |_| {
             Ok(VaultPerformanceResponse {
-                fee: get_performance_fee(&vault, current_price)?,
+                fee: get_performance_fee(&vault, current_price, &config)?,
                 factor: get_performance_factor(&vault, current_price)?,
             })
         },
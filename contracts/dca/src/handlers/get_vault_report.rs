@@ -0,0 +1,174 @@
+use crate::{
+    helpers::price::get_twap_to_now,
+    msg::VaultReportResponse,
+    state::{config::get_config, execution_summaries::get_execution_summary, vaults::get_vault},
+};
+use cosmwasm_std::{Coin, Decimal, Deps, Int128, StdError, StdResult, Uint128};
+
+pub fn get_vault_report_handler(deps: Deps, vault_id: Uint128) -> StdResult<VaultReportResponse> {
+    let vault = get_vault(deps.storage, vault_id)?;
+    let config = get_config(deps.storage)?;
+    let summary = get_execution_summary(deps.storage, vault.id)?;
+
+    let current_price = get_twap_to_now(
+        &deps.querier,
+        config.exchange_contract_address,
+        vault.get_swap_denom(),
+        vault.target_denom.clone(),
+        config.twap_period,
+        vault.route.clone(),
+    )
+    .ok();
+
+    let average_price = if summary.received_amount.is_zero() {
+        None
+    } else {
+        Some(Decimal::from_ratio(
+            summary.swapped_amount,
+            summary.received_amount,
+        ))
+    };
+
+    let current_value = current_price.map(|current_price| {
+        Coin::new(
+            ((summary.received_amount - summary.fee_amount) * current_price).into(),
+            vault.get_swap_denom(),
+        )
+    });
+
+    let unrealised_pnl = current_value
+        .as_ref()
+        .map(|current_value| -> StdResult<Int128> {
+            Ok(Int128::new(
+                i128::try_from(current_value.amount.u128())
+                    .map_err(|_| StdError::generic_err("current value overflow"))?,
+            )
+            .checked_sub(Int128::new(
+                i128::try_from(summary.swapped_amount.u128())
+                    .map_err(|_| StdError::generic_err("total swapped overflow"))?,
+            ))?)
+        })
+        .transpose()?;
+
+    Ok(VaultReportResponse {
+        vault_id,
+        executions_completed: summary.executions_completed,
+        executions_skipped: summary.executions_skipped,
+        total_swapped: Coin::new(summary.swapped_amount.into(), vault.get_swap_denom()),
+        total_received: Coin::new(summary.received_amount.into(), vault.target_denom.clone()),
+        fees_paid: Coin::new(summary.fee_amount.into(), vault.target_denom),
+        average_price,
+        minimum_price: summary.minimum_price,
+        maximum_price: summary.maximum_price,
+        current_price,
+        current_value,
+        unrealised_pnl,
+    })
+}
+
+#[cfg(test)]
+mod get_vault_report_tests {
+    use super::*;
+    use crate::{
+        constants::TWO_DECIMAL,
+        state::execution_summaries::{record_completed_execution, record_skipped_execution},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
+        },
+        types::vault::Vault,
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use std::str::FromStr;
+
+    #[test]
+    fn with_no_executions_returns_empty_report() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let report = get_vault_report_handler(deps.as_ref(), vault.id).unwrap();
+
+        assert_eq!(report.executions_completed, 0);
+        assert_eq!(report.average_price, None);
+        assert_eq!(report.minimum_price, None);
+        assert_eq!(report.unrealised_pnl, Some(Int128::zero()));
+    }
+
+    #[test]
+    fn with_unavailable_price_omits_valuation() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        record_completed_execution(
+            deps.as_mut().storage,
+            vault.id,
+            &Coin::new(100, DENOM_UKUJI),
+            &Coin::new(50, DENOM_UUSK),
+            &Coin::new(1, DENOM_UUSK),
+        )
+        .unwrap();
+
+        let report = get_vault_report_handler(deps.as_ref(), vault.id).unwrap();
+
+        assert_eq!(report.executions_completed, 1);
+        assert_eq!(report.current_price, None);
+        assert_eq!(report.current_value, None);
+        assert_eq!(report.unrealised_pnl, None);
+    }
+
+    #[test]
+    fn aggregates_execution_history() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let mut record_completed = |sent: u128, received: u128| {
+            record_completed_execution(
+                deps.as_mut().storage,
+                vault.id,
+                &Coin::new(sent, DENOM_UKUJI),
+                &Coin::new(received, DENOM_UUSK),
+                &Coin::new(1, DENOM_UUSK),
+            )
+            .unwrap()
+        };
+
+        record_completed(100, 50);
+        record_completed(100, 40);
+
+        record_skipped_execution(deps.as_mut().storage, vault.id).unwrap();
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let report = get_vault_report_handler(deps.as_ref(), vault.id).unwrap();
+
+        assert_eq!(
+            report,
+            VaultReportResponse {
+                vault_id: vault.id,
+                executions_completed: 2,
+                executions_skipped: 1,
+                total_swapped: Coin::new(200, DENOM_UKUJI),
+                total_received: Coin::new(90, DENOM_UUSK),
+                fees_paid: Coin::new(2, DENOM_UUSK),
+                average_price: Some(Decimal::from_ratio(200u128, 90u128)),
+                minimum_price: Some(Decimal::from_str("2").unwrap()),
+                maximum_price: Some(Decimal::from_str("2.5").unwrap()),
+                current_price: Some(TWO_DECIMAL),
+                current_value: Some(Coin::new(176, DENOM_UKUJI)),
+                unrealised_pnl: Some(Int128::new(-24)),
+            }
+        );
+    }
+}
@@ -262,4 +262,43 @@ mod get_vaults_by_address_tests {
             .iter()
             .for_each(|v| assert!(v.status == VaultStatus::Active));
     }
+
+    #[test]
+    fn with_paused_status_filter_should_return_only_paused_vaults() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info);
+
+        setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let paused_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        let vaults = from_json::<VaultsResponse>(
+            &query(
+                deps.as_ref(),
+                env,
+                QueryMsg::GetVaultsByAddress {
+                    address: Vault::default().owner,
+                    status: Some(VaultStatus::Paused),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .vaults;
+
+        assert_eq!(vaults, vec![paused_vault]);
+    }
 }
@@ -1,60 +1,119 @@
 use crate::{
     error::ContractError,
+    helpers::{
+        ibc::{get_ibc_transfer_channel, get_ibc_transfer_sequence},
+        reply::get_vault_id_from_reply_id,
+    },
     state::{
-        cache::{POST_EXECUTION_ACTION_CACHE, VAULT_ID_CACHE},
+        cache::POST_EXECUTION_ACTION_CACHE,
+        claimable_balances::add_claimable_funds,
         events::create_event,
+        ibc_transfers::{save_pending_ibc_transfer, PendingIbcTransfer},
         vaults::get_vault,
     },
-    types::event::{EventBuilder, EventData},
+    types::{
+        event::{EventBuilder, EventData},
+        vault::Vault,
+    },
+};
+use cosmwasm_std::{
+    BlockInfo, Coin, DepsMut, Env, Reply, Response, StdResult, Storage, SubMsg, SubMsgResult,
 };
-use cosmwasm_std::{BankMsg, DepsMut, Env, Reply, Response, SubMsg, SubMsgResult};
 
 pub fn handle_failed_automation_handler(
     deps: DepsMut,
     env: Env,
     reply: Reply,
 ) -> Result<Response, ContractError> {
-    let vault_id = VAULT_ID_CACHE.load(deps.storage)?;
+    let vault_id = get_vault_id_from_reply_id(reply.id);
     let vault = get_vault(deps.storage, vault_id)?;
 
     let mut cache = POST_EXECUTION_ACTION_CACHE.load(deps.storage, vault_id.into())?;
     let entry = cache.pop_front().unwrap();
     POST_EXECUTION_ACTION_CACHE.save(deps.storage, vault_id.into(), &cache)?;
 
-    let destination_num = vault.destinations.len() - cache.len();
+    let destination_msg = entry.destination_num.map_or_else(
+        || "retried_msg".to_string(),
+        |destination_num| format!("destination_msg_{}", destination_num),
+    );
 
     Ok(match reply.result {
-        SubMsgResult::Ok(_) => Response::new()
-            .add_attribute(format!("destination_msg_{}", destination_num), "succeeded"),
+        SubMsgResult::Ok(result) => {
+            let response = Response::new().add_attribute(destination_msg, "succeeded");
+
+            match get_ibc_transfer_channel(&entry.msg.msg) {
+                Some(channel_id) => match get_ibc_transfer_sequence(result.data) {
+                    Ok(sequence) => {
+                        save_pending_ibc_transfer(
+                            deps.storage,
+                            channel_id.clone(),
+                            sequence,
+                            &PendingIbcTransfer {
+                                vault_id,
+                                msg: entry.msg,
+                                funds: entry.funds,
+                            },
+                        )?;
+
+                        response
+                            .add_attribute("ibc_transfer_channel", channel_id)
+                            .add_attribute("ibc_transfer_sequence", sequence.to_string())
+                    }
+                    Err(_) => response.add_attribute("ibc_transfer_untracked", channel_id),
+                },
+                None => response,
+            }
+        }
         SubMsgResult::Err(_) => {
-            create_event(
+            let event_id = record_failed_post_execution_action(
                 deps.storage,
-                EventBuilder::new(
-                    vault_id,
-                    env.block,
-                    EventData::DcaVaultPostExecutionActionFailed {
-                        msg: entry.msg,
-                        funds: entry.funds.clone(),
-                    },
-                ),
+                env.block,
+                &vault,
+                entry.msg,
+                entry.funds,
             )?;
 
             Response::new()
-                .add_attribute(format!("destination_msg_{}", destination_num), "failed")
-                .add_submessage(SubMsg::new(BankMsg::Send {
-                    to_address: vault.owner.to_string(),
-                    amount: entry.funds,
-                }))
+                .add_attribute(destination_msg, "failed")
+                .add_attribute("claimable_by", vault.owner)
+                .add_attribute("retryable_event_id", event_id.to_string())
         }
     })
 }
 
+pub fn record_failed_post_execution_action(
+    storage: &mut dyn Storage,
+    block: BlockInfo,
+    vault: &Vault,
+    msg: SubMsg,
+    funds: Vec<Coin>,
+) -> StdResult<u64> {
+    let event_id = create_event(
+        storage,
+        EventBuilder::new(
+            vault.id,
+            block,
+            EventData::DcaVaultPostExecutionActionFailed {
+                msg,
+                funds: funds.clone(),
+            },
+        ),
+    )?;
+
+    add_claimable_funds(storage, vault.owner.clone(), funds)?;
+
+    Ok(event_id)
+}
+
 #[cfg(test)]
 mod handle_failed_automation_handler_tests {
     use super::handle_failed_automation_handler;
     use crate::{
         constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
-        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        handlers::{
+            get_claimable::get_claimable_handler,
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+        },
         helpers::disbursement::get_disbursement_messages,
         state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
         tests::{
@@ -69,8 +128,8 @@ mod handle_failed_automation_handler_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        to_json_binary, Addr, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse, SubMsgResult,
-        WasmMsg,
+        to_json_binary, Addr, Attribute, BankMsg, Coin, Decimal, Reply, SubMsg, SubMsgResponse,
+        SubMsgResult, WasmMsg,
     };
     use std::collections::VecDeque;
 
@@ -84,6 +143,8 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -96,6 +157,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -108,7 +171,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -145,6 +208,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination_num: Some(2),
             }])
         );
     }
@@ -159,6 +223,8 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -171,6 +237,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -183,7 +251,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -201,6 +269,49 @@ mod handle_failed_automation_handler_tests {
         assert!(response.messages.is_empty());
     }
 
+    #[test]
+    fn reports_result_against_destination_number_of_cached_message() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        POST_EXECUTION_ACTION_CACHE
+            .save(
+                deps.as_mut().storage,
+                vault.id.into(),
+                &VecDeque::from(vec![PostExecutionActionCacheEntry {
+                    msg: SubMsg::reply_always(
+                        BankMsg::Send {
+                            to_address: vault.owner.to_string(),
+                            amount: vec![Coin::new(100, vault.target_denom.clone())],
+                        },
+                        AFTER_FAILED_AUTOMATION_REPLY_ID,
+                    ),
+                    funds: vec![Coin::new(100, vault.target_denom.clone())],
+                    destination_num: Some(3),
+                }]),
+            )
+            .unwrap();
+
+        let response = handle_failed_automation_handler(
+            deps.as_mut(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("destination_msg_3", "succeeded")));
+    }
+
     #[test]
     fn removes_appropriate_post_execution_action_cache_entry_on_failure() {
         let mut deps = mock_dependencies();
@@ -211,6 +322,8 @@ mod handle_failed_automation_handler_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -223,6 +336,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -235,7 +350,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -269,6 +384,7 @@ mod handle_failed_automation_handler_tests {
                     (vault.swap_amount * destinations[1].allocation).into(),
                     vault.target_denom
                 )],
+                destination_num: Some(2),
             }])
         );
     }
@@ -292,6 +408,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract2"),
@@ -304,6 +422,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -316,7 +436,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         handle_failed_automation_handler(
             deps.as_mut(),
@@ -328,9 +448,10 @@ mod handle_failed_automation_handler_tests {
         )
         .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(
             events[0],
@@ -360,7 +481,7 @@ mod handle_failed_automation_handler_tests {
     }
 
     #[test]
-    fn sends_funds_to_vault_owner_on_failure() {
+    fn adds_funds_to_vault_owner_claimable_balance_on_failure() {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
@@ -378,6 +499,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract2"),
@@ -390,6 +513,8 @@ mod handle_failed_automation_handler_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -402,7 +527,7 @@ mod handle_failed_automation_handler_tests {
             },
         );
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, vault.swap_amount).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
 
         let response = handle_failed_automation_handler(
             deps.as_mut(),
@@ -414,15 +539,18 @@ mod handle_failed_automation_handler_tests {
         )
         .unwrap();
 
+        assert!(response.messages.is_empty());
+
+        let claimable = get_claimable_handler(deps.as_ref(), vault.owner)
+            .unwrap()
+            .claimable;
+
         assert_eq!(
-            response.messages,
-            vec![SubMsg::new(BankMsg::Send {
-                to_address: vault.owner.to_string(),
-                amount: vec![Coin::new(
-                    (vault.swap_amount * destinations[0].allocation).into(),
-                    vault.target_denom
-                )],
-            })]
+            claimable,
+            vec![Coin::new(
+                (vault.swap_amount * destinations[0].allocation).into(),
+                vault.target_denom
+            )]
         );
     }
 }
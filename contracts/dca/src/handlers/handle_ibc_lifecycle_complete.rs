@@ -0,0 +1,236 @@
+use super::handle_failed_automation::record_failed_post_execution_action;
+use crate::{
+    error::ContractError,
+    msg::IbcLifecycleComplete,
+    state::{ibc_transfers::remove_pending_ibc_transfer, vaults::get_vault},
+};
+use cosmwasm_std::{DepsMut, Env, Response};
+
+pub fn handle_ibc_lifecycle_complete_handler(
+    deps: DepsMut,
+    env: Env,
+    lifecycle: IbcLifecycleComplete,
+) -> Result<Response, ContractError> {
+    let (channel, sequence, outcome) = match lifecycle {
+        IbcLifecycleComplete::IbcAck {
+            channel,
+            sequence,
+            success: true,
+            ..
+        } => (channel, sequence, "succeeded"),
+        IbcLifecycleComplete::IbcAck {
+            channel, sequence, ..
+        } => (channel, sequence, "failed"),
+        IbcLifecycleComplete::IbcTimeout { channel, sequence } => (channel, sequence, "timed_out"),
+    };
+
+    let transfer = remove_pending_ibc_transfer(deps.storage, channel.clone(), sequence)?
+        .ok_or_else(|| ContractError::CustomError {
+            val: format!(
+                "no pending ibc transfer found for channel {} and sequence {}",
+                channel, sequence
+            ),
+        })?;
+
+    let response = Response::new()
+        .add_attribute("ibc_lifecycle_complete", "true")
+        .add_attribute("vault_id", transfer.vault_id)
+        .add_attribute("channel", channel)
+        .add_attribute("sequence", sequence.to_string())
+        .add_attribute("outcome", outcome);
+
+    if outcome == "succeeded" {
+        return Ok(response);
+    }
+
+    let vault = get_vault(deps.storage, transfer.vault_id)?;
+
+    let event_id = record_failed_post_execution_action(
+        deps.storage,
+        env.block,
+        &vault,
+        transfer.msg,
+        transfer.funds,
+    )?;
+
+    Ok(response
+        .add_attribute("claimable_by", vault.owner)
+        .add_attribute("retryable_event_id", event_id.to_string()))
+}
+
+#[cfg(test)]
+mod handle_ibc_lifecycle_complete_tests {
+    use super::*;
+    use crate::{
+        constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
+        handlers::{
+            get_claimable::get_claimable_handler,
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+            handle_failed_automation::handle_failed_automation_handler,
+        },
+        helpers::{disbursement::get_disbursement_messages, ibc::MsgTransferResponse},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::ADMIN,
+        },
+        types::{
+            destination::{Destination, IbcTransfer},
+            event::EventData,
+            vault::Vault,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Binary, Coin, Decimal, Reply, SubMsgResponse, SubMsgResult,
+    };
+    use prost::Message;
+
+    fn setup_pending_ibc_transfer(mut deps: DepsMut) -> Vault {
+        let env = mock_env();
+
+        instantiate_contract(deps.branch(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.branch(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("osmo1receiver"),
+                    allocation: Decimal::percent(100),
+                    msg: None,
+                    ibc_transfer: Some(IbcTransfer {
+                        channel_id: "channel-3".to_string(),
+                        memo: None,
+                        timeout_seconds: None,
+                    }),
+                    vault_id: None,
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.storage, &env, &vault, vault.swap_amount).unwrap();
+
+        handle_failed_automation_handler(
+            deps.branch(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(Binary::from(
+                        MsgTransferResponse { sequence: 7 }.encode_to_vec(),
+                    )),
+                }),
+            },
+        )
+        .unwrap();
+
+        vault
+    }
+
+    #[test]
+    fn with_unknown_transfer_fails() {
+        let mut deps = mock_dependencies();
+
+        let err = handle_ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            mock_env(),
+            IbcLifecycleComplete::IbcTimeout {
+                channel: "channel-3".to_string(),
+                sequence: 7,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: no pending ibc transfer found for channel channel-3 and sequence 7"
+        );
+    }
+
+    #[test]
+    fn with_successful_ack_clears_pending_transfer() {
+        let mut deps = mock_dependencies();
+        let vault = setup_pending_ibc_transfer(deps.as_mut());
+
+        let lifecycle = IbcLifecycleComplete::IbcAck {
+            channel: "channel-3".to_string(),
+            sequence: 7,
+            ack: "AQ==".to_string(),
+            success: true,
+        };
+
+        handle_ibc_lifecycle_complete_handler(deps.as_mut(), mock_env(), lifecycle.clone())
+            .unwrap();
+
+        let claimable = get_claimable_handler(deps.as_ref(), vault.owner)
+            .unwrap()
+            .claimable;
+
+        assert!(claimable.is_empty());
+        assert!(
+            handle_ibc_lifecycle_complete_handler(deps.as_mut(), mock_env(), lifecycle).is_err()
+        );
+    }
+
+    #[test]
+    fn with_failed_ack_adds_funds_to_owner_claimable_balance() {
+        let mut deps = mock_dependencies();
+        let vault = setup_pending_ibc_transfer(deps.as_mut());
+
+        handle_ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            mock_env(),
+            IbcLifecycleComplete::IbcAck {
+                channel: "channel-3".to_string(),
+                sequence: 7,
+                ack: "error".to_string(),
+                success: false,
+            },
+        )
+        .unwrap();
+
+        let claimable = get_claimable_handler(deps.as_ref(), vault.owner)
+            .unwrap()
+            .claimable;
+
+        assert_eq!(
+            claimable,
+            vec![Coin::new(vault.swap_amount.into(), vault.target_denom)]
+        );
+    }
+
+    #[test]
+    fn with_timeout_publishes_post_execution_action_failed_event() {
+        let mut deps = mock_dependencies();
+        let vault = setup_pending_ibc_transfer(deps.as_mut());
+
+        handle_ibc_lifecycle_complete_handler(
+            deps.as_mut(),
+            mock_env(),
+            IbcLifecycleComplete::IbcTimeout {
+                channel: "channel-3".to_string(),
+                sequence: 7,
+            },
+        )
+        .unwrap();
+
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            Some(true),
+            None,
+        )
+        .unwrap()
+        .events;
+
+        assert!(matches!(
+            &events[0].data,
+            EventData::DcaVaultPostExecutionActionFailed { funds, .. }
+                if funds == &vec![Coin::new(vault.swap_amount.into(), vault.target_denom.clone())]
+        ));
+    }
+}
@@ -9,10 +9,13 @@ use crate::{
         assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_twap_period_is_valid,
     },
     msg::InstantiateMsg,
-    state::config::update_config,
+    state::{
+        base_denoms::{bitcoin_base_denom_config, save_base_denom_config},
+        config::update_config,
+    },
     types::config::Config,
 };
-use cosmwasm_std::{DepsMut, Response};
+use cosmwasm_std::{Decimal, DepsMut, Response};
 use cw2::set_contract_version;
 
 pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Response, ContractError> {
@@ -48,9 +51,21 @@ pub fn instantiate_handler(deps: DepsMut, msg: InstantiateMsg) -> Result<Respons
             default_slippage_tolerance: msg.default_slippage_tolerance,
             old_staking_router_address: msg.old_staking_router_address,
             exchange_contract_address: msg.admin.clone(),
+            referral_fee_share: Decimal::zero(),
+            value_averaging_swap_fee_percent: None,
+            swap_adjustment_submitters: vec![],
+            swap_adjustment_quorum: 1,
+            performance_fee_percent: None,
+            escrow_crystallisation_period_seconds: None,
+            hook_gas_limit: None,
         },
     )?;
 
+    save_base_denom_config(
+        deps.storage,
+        &bitcoin_base_denom_config(msg.risk_weighted_average_escrow_level),
+    )?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::new()
@@ -2,6 +2,16 @@ use crate::{
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
     msg::MigrateMsg,
+    state::{
+        base_denoms::{
+            bitcoin_base_denom_config, is_base_denom_registered, save_base_denom_config,
+        },
+        config::get_config,
+        events::start_event_index_migration,
+        swap_adjustments::migrate_legacy_swap_adjustments,
+        vaults::start_vault_statistics_migration,
+    },
+    types::swap_adjustment_strategy::BaseDenom,
 };
 use cosmwasm_std::{DepsMut, Response, StdError};
 use cw2::{get_contract_version, set_contract_version};
@@ -18,6 +28,19 @@ pub fn migrate_handler(deps: DepsMut, msg: MigrateMsg) -> Result<Response, Contr
         return Err(StdError::generic_err("Cannot upgrade from a newer version").into());
     }
 
+    let config = get_config(deps.storage)?;
+
+    if !is_base_denom_registered(deps.storage, &BaseDenom::Bitcoin) {
+        save_base_denom_config(
+            deps.storage,
+            &bitcoin_base_denom_config(config.risk_weighted_average_escrow_level),
+        )?;
+    }
+
+    migrate_legacy_swap_adjustments(deps.storage, &config.admin)?;
+    start_vault_statistics_migration(deps.storage)?;
+    start_event_index_migration(deps.storage)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::new()
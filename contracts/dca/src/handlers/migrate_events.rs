@@ -0,0 +1,175 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_page_limit_is_valid, assert_sender_is_admin},
+    state::{config::get_config, events::migrate_event_indexes},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn migrate_events_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_page_limit_is_valid(limit)?;
+
+    let limit = limit.unwrap_or(get_config(deps.storage)?.default_page_limit);
+    let migration = migrate_event_indexes(deps.storage, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("migrate_events", "true")
+        .add_attribute("next_event_id", migration.next_event_id.to_string())
+        .add_attribute("end_event_id", migration.end_event_id.to_string())
+        .add_attribute(
+            "complete",
+            (migration.next_event_id >= migration.end_event_id).to_string(),
+        ))
+}
+
+#[cfg(test)]
+mod migrate_events_tests {
+    use super::*;
+    use crate::{
+        handlers::get_events::get_events_handler,
+        state::{events::start_event_index_migration, execution_summaries::get_execution_summary},
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::event::{EventBuilder, EventData, EventFilter, ExecutionSkippedReason},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_json_binary, Attribute, Binary, Coin, Deps, Uint128,
+    };
+    use cw_storage_plus::{Item, Map};
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+        start_event_index_migration(deps.as_mut().storage).unwrap();
+
+        let err =
+            migrate_events_handler(deps.as_mut(), mock_info("not-admin", &[]), None).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn indexes_events_saved_before_indexes_existed() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let legacy_events = Map::<u64, Binary>::new("serialised_events_v8");
+
+        for id in 1..=3 {
+            legacy_events
+                .save(
+                    deps.as_mut().storage,
+                    id,
+                    &to_json_binary(
+                        &EventBuilder::new(
+                            Uint128::one(),
+                            env.block.clone(),
+                            EventData::DcaVaultCancelled {},
+                        )
+                        .build(id),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+
+        Item::<u64>::new("event_counter_v8")
+            .save(deps.as_mut().storage, &3)
+            .unwrap();
+
+        let cancelled_events = |deps: Deps| {
+            get_events_handler(
+                deps,
+                None,
+                None,
+                None,
+                Some(EventFilter {
+                    event_type: Some("dca_vault_cancelled".to_string()),
+                    ..EventFilter::default()
+                }),
+            )
+            .unwrap()
+            .events
+            .len()
+        };
+
+        assert_eq!(cancelled_events(deps.as_ref()), 0);
+
+        start_event_index_migration(deps.as_mut().storage).unwrap();
+
+        let response =
+            migrate_events_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("complete", "false")));
+        assert_eq!(cancelled_events(deps.as_ref()), 2);
+
+        let response =
+            migrate_events_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("complete", "true")));
+        assert_eq!(cancelled_events(deps.as_ref()), 3);
+    }
+
+    #[test]
+    fn backfills_execution_summaries() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let legacy_events = Map::<u64, Binary>::new("serialised_events_v8");
+
+        let event_data = vec![
+            EventData::DcaVaultExecutionCompleted {
+                sent: Coin::new(100, "ukuji"),
+                received: Coin::new(50, "uusk"),
+                fee: Coin::new(1, "uusk"),
+            },
+            EventData::DcaVaultExecutionSkipped {
+                reason: ExecutionSkippedReason::SlippageToleranceExceeded,
+            },
+            EventData::DcaVaultCancelled {},
+        ];
+
+        for (id, data) in (1..).zip(event_data) {
+            legacy_events
+                .save(
+                    deps.as_mut().storage,
+                    id,
+                    &to_json_binary(
+                        &EventBuilder::new(Uint128::one(), env.block.clone(), data).build(id),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+        }
+
+        Item::<u64>::new("event_counter_v8")
+            .save(deps.as_mut().storage, &3)
+            .unwrap();
+
+        start_event_index_migration(deps.as_mut().storage).unwrap();
+
+        migrate_events_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap();
+
+        let summary = get_execution_summary(deps.as_ref().storage, Uint128::one()).unwrap();
+
+        assert_eq!(summary.executions_completed, 1);
+        assert_eq!(summary.executions_skipped, 1);
+        assert_eq!(summary.swapped_amount, Uint128::new(100));
+        assert_eq!(summary.received_amount, Uint128::new(50));
+        assert_eq!(summary.fee_amount, Uint128::one());
+    }
+}
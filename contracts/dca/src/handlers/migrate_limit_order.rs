@@ -4,9 +4,11 @@ use exchange::msg::ExecuteMsg;
 use crate::{
     constants::{AFTER_ORDER_MIGRATION_REPLY_ID, FAIL_SILENTLY_REPLY_ID, TWO_MICRONS},
     error::ContractError,
-    helpers::message::get_attribute_in_event,
+    helpers::{
+        message::get_attribute_in_event,
+        reply::{get_vault_id_from_reply_id, get_vault_reply_id},
+    },
     state::{
-        cache::VAULT_ID_CACHE,
         config::get_config,
         triggers::{delete_trigger, save_trigger},
         vaults::get_vault,
@@ -26,8 +28,6 @@ pub fn migrate_limit_order(deps: DepsMut, vault_id: Uint128) -> Result<Response,
         order_idx,
     }) = vault.trigger
     {
-        VAULT_ID_CACHE.save(deps.storage, &vault_id)?;
-
         let config = get_config(deps.storage)?;
 
         response = response.add_submessage(SubMsg::reply_on_error(
@@ -66,7 +66,7 @@ pub fn migrate_limit_order(deps: DepsMut, vault_id: Uint128) -> Result<Response,
                 .unwrap(),
                 funds: vec![Coin::new(TWO_MICRONS.into(), vault.get_swap_denom())],
             },
-            AFTER_ORDER_MIGRATION_REPLY_ID,
+            get_vault_reply_id(AFTER_ORDER_MIGRATION_REPLY_ID, vault_id),
         ))
     }
 
@@ -82,7 +82,7 @@ pub fn save_new_limit_order_idx(deps: DepsMut, reply: Reply) -> Result<Response,
     .parse::<Uint128>()
     .expect("the order id of the new limit order");
 
-    let vault_id = VAULT_ID_CACHE.load(deps.storage)?;
+    let vault_id = get_vault_id_from_reply_id(reply.id);
     let vault = get_vault(deps.storage, vault_id)?;
 
     if let Some(TriggerConfiguration::Price { target_price, .. }) = vault.trigger {
@@ -0,0 +1,164 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_page_limit_is_valid, assert_sender_is_admin},
+    state::{config::get_config, vaults::migrate_vault_statistics},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn migrate_statistics_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<u16>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+    assert_page_limit_is_valid(limit)?;
+
+    let limit = limit.unwrap_or(get_config(deps.storage)?.default_page_limit);
+    let migration = migrate_vault_statistics(deps.storage, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("migrate_statistics", "true")
+        .add_attribute("next_vault_id", migration.next_vault_id)
+        .add_attribute("end_vault_id", migration.end_vault_id)
+        .add_attribute(
+            "complete",
+            (migration.next_vault_id >= migration.end_vault_id).to_string(),
+        ))
+}
+
+#[cfg(test)]
+mod migrate_statistics_tests {
+    use super::*;
+    use crate::{
+        state::{
+            statistics::{
+                get_active_vault_count, get_total_value_locked, start_statistics_migration,
+            },
+            vaults::{start_vault_statistics_migration, update_vault},
+        },
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_UUSK},
+        },
+        types::vault::{Vault, VaultStatus},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Attribute, Coin, Uint128,
+    };
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+        start_vault_statistics_migration(deps.as_mut().storage).unwrap();
+
+        let err = migrate_statistics_handler(deps.as_mut(), mock_info("not-admin", &[]), None)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_migration_not_started_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err =
+            migrate_statistics_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: statistics migration has not been started"
+        );
+    }
+
+    #[test]
+    fn backfills_statistics_from_existing_vaults_in_pages() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        for (id, status) in [
+            (1, VaultStatus::Active),
+            (2, VaultStatus::Inactive),
+            (3, VaultStatus::Active),
+        ] {
+            setup_vault(
+                deps.as_mut(),
+                env.clone(),
+                Vault {
+                    id: Uint128::new(id),
+                    status,
+                    balance: Coin::new(100, DENOM_UUSK),
+                    ..Vault::default()
+                },
+            );
+        }
+
+        start_statistics_migration(deps.as_mut().storage, Uint128::new(4)).unwrap();
+
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 0);
+
+        let response =
+            migrate_statistics_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("complete", "false")));
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 1);
+
+        let response =
+            migrate_statistics_handler(deps.as_mut(), mock_info(ADMIN, &[]), Some(2)).unwrap();
+
+        assert!(response
+            .attributes
+            .contains(&Attribute::new("complete", "true")));
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 2);
+        assert_eq!(
+            get_total_value_locked(deps.as_ref().storage).unwrap(),
+            vec![Coin::new(300, DENOM_UUSK)]
+        );
+    }
+
+    #[test]
+    fn ignores_updates_to_vaults_not_yet_backfilled() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                id: Uint128::one(),
+                balance: Coin::new(100, DENOM_UUSK),
+                ..Vault::default()
+            },
+        );
+
+        start_statistics_migration(deps.as_mut().storage, vault.id + Uint128::one()).unwrap();
+
+        update_vault(
+            deps.as_mut().storage,
+            Vault {
+                status: VaultStatus::Inactive,
+                balance: Coin::new(0, DENOM_UUSK),
+                ..vault
+            },
+        )
+        .unwrap();
+
+        migrate_statistics_handler(deps.as_mut(), mock_info(ADMIN, &[]), None).unwrap();
+
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 0);
+        assert_eq!(
+            get_total_value_locked(deps.as_ref().storage).unwrap(),
+            vec![Coin::new(0, DENOM_UUSK)]
+        );
+    }
+}
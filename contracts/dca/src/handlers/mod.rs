@@ -1,26 +1,48 @@
 pub mod cancel_vault;
+pub mod claim;
+pub mod create_custom_swap_fee;
 pub mod create_vault;
 pub mod deposit;
 pub mod disburse_escrow;
 pub mod disburse_funds;
 pub mod execute_trigger;
+pub mod execute_triggers;
+pub mod get_base_denoms;
+pub mod get_claimable;
 pub mod get_config;
+pub mod get_custom_swap_fees;
 pub mod get_disburse_escrow_tasks;
 pub mod get_events;
 pub mod get_events_by_resource_id;
 pub mod get_pairs;
+pub mod get_referral_volume;
+pub mod get_statistics;
+pub mod get_swap_adjustment_submissions;
 pub mod get_time_trigger_ids;
 pub mod get_trigger_id_by_fin_limit_order_idx;
 pub mod get_vault;
 pub mod get_vault_performance;
+pub mod get_vault_report;
 pub mod get_vaults;
 pub mod get_vaults_by_address;
 pub mod handle_failed_automation;
+pub mod handle_ibc_lifecycle_complete;
 pub mod instantiate;
 pub mod migrate;
+pub mod migrate_events;
 pub mod migrate_limit_order;
+pub mod migrate_statistics;
 pub mod old_z_delegate_handler;
+pub mod pause_vault;
+pub mod receive_cw20;
+pub mod register_base_denom;
+pub mod register_cw20_denom;
+pub mod remove_custom_swap_fee;
+pub mod resume_vault;
+pub mod retry_post_execution_action;
+pub mod transfer_vault_ownership;
 pub mod update_config;
 pub mod update_swap_adjustment_handler;
 pub mod update_vault;
+pub mod withdraw_from_vault;
 pub mod z_delegate;
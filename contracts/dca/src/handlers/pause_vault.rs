@@ -0,0 +1,186 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{assert_vault_can_be_paused, asset_sender_is_vault_owner};
+use crate::state::events::create_event;
+use crate::state::triggers::{delete_trigger, save_paused_trigger};
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::trigger::TriggerConfiguration;
+use crate::types::vault::{Vault, VaultStatus};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn pause_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_can_be_paused(&vault)?;
+
+    if let Some(TriggerConfiguration::Price { .. }) = vault.trigger {
+        return Err(ContractError::CustomError {
+            val: "cannot pause a vault with a price trigger".to_string(),
+        });
+    }
+
+    if let Some(trigger) = &vault.trigger {
+        save_paused_trigger(deps.storage, vault.id, trigger)?;
+    }
+
+    delete_trigger(deps.storage, vault.id)?;
+
+    update_vault(
+        deps.storage,
+        Vault {
+            status: VaultStatus::Paused,
+            ..vault.clone()
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(vault.id, env.block, EventData::DcaVaultPaused {}),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("pause_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner))
+}
+
+#[cfg(test)]
+mod pause_vault_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::state::triggers::get_trigger;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Decimal, Uint128};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            pause_vault_handler(deps.as_mut(), env, mock_info("not-owner", &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            pause_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: vault with status Cancelled cannot be paused"
+        );
+    }
+
+    #[test]
+    fn with_price_trigger_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                trigger: Some(TriggerConfiguration::Price {
+                    target_price: Decimal::percent(200),
+                    order_idx: Uint128::new(28),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            pause_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot pause a vault with a price trigger"
+        );
+    }
+
+    #[test]
+    fn should_update_vault_status_to_paused() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        pause_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Paused);
+    }
+
+    #[test]
+    fn should_delete_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        pause_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        assert!(vault.trigger.is_some());
+        assert!(get_trigger(deps.as_ref().storage, vault.id)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_publish_vault_paused_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        pause_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(vault.id, env.block, EventData::DcaVaultPaused {}).build(1)
+        ));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::contract::execute;
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use crate::state::cw20_denoms::is_cw20_denom;
+use cosmwasm_std::{from_json, Coin, DepsMut, Env, MessageInfo, Response};
+use cw20::Cw20ReceiveMsg;
+
+pub fn receive_cw20_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    if !info.funds.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "cannot send native funds with a cw20 receive msg".to_string(),
+        });
+    }
+
+    let msg = from_json::<ExecuteMsg>(&receive_msg.msg)?;
+
+    if !matches!(
+        msg,
+        ExecuteMsg::CreateVault { .. } | ExecuteMsg::Deposit { .. }
+    ) {
+        return Err(ContractError::CustomError {
+            val: "only CreateVault and Deposit can be funded with cw20 tokens".to_string(),
+        });
+    }
+
+    if !is_cw20_denom(deps.storage, info.sender.as_str()) {
+        return Err(ContractError::CustomError {
+            val: format!("cw20 token {} is not registered", info.sender),
+        });
+    }
+
+    let sender = deps.api.addr_validate(&receive_msg.sender)?;
+
+    execute(
+        deps,
+        env,
+        MessageInfo {
+            sender,
+            funds: vec![Coin::new(receive_msg.amount.into(), info.sender)],
+        },
+        msg,
+    )
+}
+
+#[cfg(test)]
+mod receive_cw20_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN};
+    use crate::handlers::get_vault::get_vault_handler;
+    use crate::state::cw20_denoms::save_cw20_denom;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{calc_mock_dependencies, ADMIN, DENOM_UUSK, USER};
+    use crate::types::time_interval::TimeInterval;
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{to_json_binary, Addr, Uint128};
+
+    const CW20_TOKEN: &str = "cw20-token";
+
+    fn create_vault_msg() -> ExecuteMsg {
+        ExecuteMsg::CreateVault {
+            owner: None,
+            label: None,
+            destinations: None,
+            target_denom: DENOM_UUSK.to_string(),
+            route: None,
+            slippage_tolerance: None,
+            minimum_receive_amount: None,
+            swap_amount: ONE,
+            time_interval: TimeInterval::Daily,
+            target_start_time_utc_seconds: None,
+            target_receive_amount: None,
+            performance_assessment_strategy: None,
+            swap_adjustment_strategy: None,
+            end_time_utc_seconds: None,
+            max_executions: None,
+            stop_loss: None,
+            take_profit: None,
+            maximum_receive_amount: None,
+            price_band: None,
+            referrer: None,
+            hook: None,
+            funding_mode: None,
+        }
+    }
+
+    #[test]
+    fn with_native_funds_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = receive_cw20_handler(
+            deps.as_mut(),
+            env,
+            mock_info(CW20_TOKEN, &[Coin::new(ONE.into(), DENOM_UUSK)]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: TEN,
+                msg: to_json_binary(&create_vault_msg()).unwrap(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cannot send native funds with a cw20 receive msg"
+        );
+    }
+
+    #[test]
+    fn with_unsupported_msg_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = receive_cw20_handler(
+            deps.as_mut(),
+            env,
+            mock_info(CW20_TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: TEN,
+                msg: to_json_binary(&ExecuteMsg::CancelVault {
+                    vault_id: Uint128::one(),
+                })
+                .unwrap(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: only CreateVault and Deposit can be funded with cw20 tokens"
+        );
+    }
+
+    #[test]
+    fn with_unregistered_cw20_token_fails() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let err = receive_cw20_handler(
+            deps.as_mut(),
+            env,
+            mock_info(CW20_TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: TEN,
+                msg: to_json_binary(&create_vault_msg()).unwrap(),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: cw20 token cw20-token is not registered"
+        );
+        assert!(!is_cw20_denom(deps.as_ref().storage, CW20_TOKEN));
+    }
+
+    #[test]
+    fn creates_vault_with_cw20_balance_for_sender() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+        save_cw20_denom(deps.as_mut().storage, CW20_TOKEN).unwrap();
+
+        receive_cw20_handler(
+            deps.as_mut(),
+            env,
+            mock_info(CW20_TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: TEN,
+                msg: to_json_binary(&create_vault_msg()).unwrap(),
+            },
+        )
+        .unwrap();
+
+        let vault = get_vault_handler(deps.as_ref(), Uint128::one())
+            .unwrap()
+            .vault;
+
+        assert_eq!(vault.owner, Addr::unchecked(USER));
+        assert_eq!(vault.balance, Coin::new(TEN.into(), CW20_TOKEN));
+        assert_eq!(vault.deposited_amount, Coin::new(TEN.into(), CW20_TOKEN));
+    }
+
+    #[test]
+    fn deposits_cw20_into_existing_vault() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+        save_cw20_denom(deps.as_mut().storage, CW20_TOKEN).unwrap();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(ONE.into(), CW20_TOKEN),
+                deposited_amount: Coin::new(ONE.into(), CW20_TOKEN),
+                swapped_amount: Coin::new(0, CW20_TOKEN),
+                ..Vault::default()
+            },
+        );
+
+        receive_cw20_handler(
+            deps.as_mut(),
+            env,
+            mock_info(CW20_TOKEN, &[]),
+            Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: TEN,
+                msg: to_json_binary(&ExecuteMsg::Deposit {
+                    address: vault.owner.clone(),
+                    vault_id: vault.id,
+                })
+                .unwrap(),
+            },
+        )
+        .unwrap();
+
+        let updated_vault = get_vault_handler(deps.as_ref(), vault.id).unwrap().vault;
+
+        assert_eq!(
+            updated_vault.balance,
+            Coin::new((ONE + TEN).into(), CW20_TOKEN)
+        );
+    }
+}
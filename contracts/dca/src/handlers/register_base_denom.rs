@@ -0,0 +1,192 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::{assert_base_denom_config_is_valid, assert_sender_is_admin},
+    state::base_denoms::{save_base_denom_config, BaseDenomConfig, ModelIdBucket},
+    types::swap_adjustment_strategy::BaseDenom,
+};
+use cosmwasm_std::{Decimal, DepsMut, MessageInfo, Response};
+
+pub fn register_base_denom_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_denom: BaseDenom,
+    model_id_buckets: Vec<ModelIdBucket>,
+    escrow_level: Decimal,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    let base_denom_config = BaseDenomConfig {
+        base_denom,
+        model_id_buckets,
+        escrow_level,
+    };
+
+    assert_base_denom_config_is_valid(&base_denom_config)?;
+
+    save_base_denom_config(deps.storage, &base_denom_config)?;
+
+    Ok(Response::new()
+        .add_attribute("register_base_denom", "true")
+        .add_attribute("base_denom", format!("{:?}", base_denom_config.base_denom))
+        .add_attribute(
+            "model_id_buckets",
+            format!("{:?}", base_denom_config.model_id_buckets),
+        )
+        .add_attribute("escrow_level", escrow_level.to_string()))
+}
+
+#[cfg(test)]
+mod register_base_denom_tests {
+    use super::*;
+    use crate::{
+        handlers::get_base_denoms::get_base_denoms_handler,
+        state::base_denoms::bitcoin_base_denom_config,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+    };
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    fn model_id_buckets() -> Vec<ModelIdBucket> {
+        vec![
+            ModelIdBucket {
+                max_days: 30,
+                model_id: 30,
+            },
+            ModelIdBucket {
+                max_days: 60,
+                model_id: 60,
+            },
+        ]
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_base_denom_handler(
+            deps.as_mut(),
+            mock_info("not-admin", &[]),
+            BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets(),
+            Decimal::percent(5),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_model_id_buckets_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_base_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Custom("ethereum".to_string()),
+            vec![],
+            Decimal::percent(5),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: base denom must have at least one model id bucket"
+        );
+    }
+
+    #[test]
+    fn with_unordered_model_id_buckets_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_base_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets().into_iter().rev().collect(),
+            Decimal::percent(5),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: model id bucket max days must be strictly increasing"
+        );
+    }
+
+    #[test]
+    fn with_escrow_level_more_than_100_percent_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_base_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets(),
+            Decimal::percent(101),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: risk_weighted_average_escrow_level cannot be greater than 100%"
+        );
+    }
+
+    #[test]
+    fn registers_base_denom() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        register_base_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets(),
+            Decimal::percent(10),
+        )
+        .unwrap();
+
+        let base_denoms = get_base_denoms_handler(deps.as_ref()).unwrap().base_denoms;
+
+        assert_eq!(base_denoms.len(), 2);
+        assert!(base_denoms.contains(&bitcoin_base_denom_config(Decimal::percent(5))));
+        assert!(base_denoms.contains(&BaseDenomConfig {
+            base_denom: BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets: model_id_buckets(),
+            escrow_level: Decimal::percent(10),
+        }));
+    }
+
+    #[test]
+    fn overwrites_registered_base_denom() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        register_base_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            BaseDenom::Bitcoin,
+            model_id_buckets(),
+            Decimal::percent(10),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_base_denoms_handler(deps.as_ref()).unwrap().base_denoms,
+            vec![BaseDenomConfig {
+                base_denom: BaseDenom::Bitcoin,
+                model_id_buckets: model_id_buckets(),
+                escrow_level: Decimal::percent(10),
+            }]
+        );
+    }
+}
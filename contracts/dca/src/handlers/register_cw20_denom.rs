@@ -0,0 +1,119 @@
+use crate::{
+    error::ContractError, helpers::validation::assert_sender_is_admin,
+    state::cw20_denoms::save_cw20_denom,
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
+
+pub fn register_cw20_denom_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    let token_address = deps.api.addr_validate(&denom)?;
+
+    deps.querier
+        .query_wasm_smart::<TokenInfoResponse>(&token_address, &Cw20QueryMsg::TokenInfo {})
+        .map_err(|_| ContractError::CustomError {
+            val: format!("{} is not a cw20 token contract", denom),
+        })?;
+
+    save_cw20_denom(deps.storage, token_address.as_str())?;
+
+    Ok(Response::new()
+        .add_attribute("register_cw20_denom", "true")
+        .add_attribute("denom", token_address))
+}
+
+#[cfg(test)]
+mod register_cw20_denom_tests {
+    use super::*;
+    use crate::{
+        state::cw20_denoms::is_cw20_denom,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+    };
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_json_binary, ContractResult, QuerierResult, SystemError, SystemResult, Uint128,
+        WasmQuery,
+    };
+
+    const CW20_TOKEN: &str = "cw20-token";
+
+    fn mock_cw20_token_info(query: &WasmQuery) -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == CW20_TOKEN => {
+                match from_json::<Cw20QueryMsg>(msg).unwrap() {
+                    Cw20QueryMsg::TokenInfo {} => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&TokenInfoResponse {
+                            name: "Token".to_string(),
+                            symbol: "TKN".to_string(),
+                            decimals: 6,
+                            total_supply: Uint128::new(1000000),
+                        })
+                        .unwrap(),
+                    )),
+                    _ => unimplemented!(),
+                }
+            }
+            _ => SystemResult::Err(SystemError::NoSuchContract {
+                addr: "unknown".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(mock_cw20_token_info);
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_cw20_denom_handler(
+            deps.as_mut(),
+            mock_info("not-admin", &[]),
+            CW20_TOKEN.to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+        assert!(!is_cw20_denom(deps.as_ref().storage, CW20_TOKEN));
+    }
+
+    #[test]
+    fn with_non_cw20_contract_fails() {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(mock_cw20_token_info);
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = register_cw20_denom_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "not-a-token".to_string(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: not-a-token is not a cw20 token contract"
+        );
+        assert!(!is_cw20_denom(deps.as_ref().storage, "not-a-token"));
+    }
+
+    #[test]
+    fn registers_cw20_denom() {
+        let mut deps = mock_dependencies();
+        deps.querier.update_wasm(mock_cw20_token_info);
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        register_cw20_denom_handler(deps.as_mut(), mock_info(ADMIN, &[]), CW20_TOKEN.to_string())
+            .unwrap();
+
+        assert!(is_cw20_denom(deps.as_ref().storage, CW20_TOKEN));
+    }
+}
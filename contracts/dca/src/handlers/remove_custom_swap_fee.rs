@@ -0,0 +1,81 @@
+use crate::{
+    error::ContractError,
+    helpers::validation::assert_sender_is_admin,
+    state::custom_swap_fees::{get_custom_swap_fee_key, remove_custom_swap_fee},
+};
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+pub fn remove_custom_swap_fee_handler(
+    deps: DepsMut,
+    info: MessageInfo,
+    denom: String,
+    paired_denom: Option<String>,
+) -> Result<Response, ContractError> {
+    assert_sender_is_admin(deps.storage, info.sender)?;
+
+    let key = get_custom_swap_fee_key(denom, paired_denom);
+
+    remove_custom_swap_fee(deps.storage, key.clone());
+
+    Ok(Response::new()
+        .add_attribute("remove_custom_swap_fee", "true")
+        .add_attribute("key", key))
+}
+
+#[cfg(test)]
+mod remove_custom_swap_fee_tests {
+    use super::*;
+    use crate::{
+        handlers::get_custom_swap_fees::get_custom_swap_fees_handler,
+        state::custom_swap_fees::save_custom_swap_fee,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Decimal,
+    };
+
+    #[test]
+    fn with_non_admin_sender_fails() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let err = remove_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info("not-admin", &[]),
+            "ukuji".to_string(),
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn removes_pair_fee() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            "ukuji+uusk".to_string(),
+            Decimal::zero(),
+        )
+        .unwrap();
+
+        remove_custom_swap_fee_handler(
+            deps.as_mut(),
+            mock_info(ADMIN, &[]),
+            "uusk".to_string(),
+            Some("ukuji".to_string()),
+        )
+        .unwrap();
+
+        assert!(get_custom_swap_fees_handler(deps.as_ref())
+            .unwrap()
+            .custom_fees
+            .is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+use crate::error::ContractError;
+use crate::helpers::time::get_next_target_time;
+use crate::helpers::validation::{assert_vault_is_paused, asset_sender_is_vault_owner};
+use crate::state::events::create_event;
+use crate::state::triggers::{save_trigger, take_paused_trigger};
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::trigger::{Trigger, TriggerConfiguration};
+use crate::types::vault::{Vault, VaultStatus};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn resume_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_is_paused(&vault)?;
+
+    let vault = update_vault(
+        deps.storage,
+        Vault {
+            status: if vault.started_at.is_some() {
+                VaultStatus::Active
+            } else {
+                VaultStatus::Scheduled
+            },
+            ..vault
+        },
+    )?;
+
+    let target_time = match (
+        vault.started_at,
+        take_paused_trigger(deps.storage, vault.id)?,
+    ) {
+        (None, Some(TriggerConfiguration::Time { target_time })) => target_time,
+        _ => get_next_target_time(
+            env.block.time,
+            vault.started_at.unwrap_or(env.block.time),
+            vault.time_interval.clone(),
+        ),
+    };
+
+    save_trigger(
+        deps.storage,
+        Trigger {
+            vault_id: vault.id,
+            configuration: TriggerConfiguration::Time { target_time },
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(vault.id, env.block, EventData::DcaVaultResumed {}),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("resume_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner)
+        .add_attribute("target_time", target_time.to_string()))
+}
+
+#[cfg(test)]
+mod resume_vault_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::handlers::pause_vault::pause_vault_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info("not-owner", &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_vault_that_is_not_paused_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is not paused");
+    }
+
+    #[test]
+    fn with_started_vault_should_update_status_to_active() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                started_at: Some(env.block.time.minus_seconds(100)),
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+    }
+
+    #[test]
+    fn with_unstarted_vault_should_update_status_to_scheduled() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                started_at: None,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Scheduled);
+    }
+
+    #[test]
+    fn should_save_next_time_trigger() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let started_at = env.block.time.minus_seconds(100);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                started_at: Some(started_at),
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: get_next_target_time(
+                    env.block.time,
+                    started_at,
+                    vault.time_interval
+                )
+            })
+        );
+    }
+
+    #[test]
+    fn with_unstarted_vault_should_restore_paused_target_start_time() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let target_start_time = env.block.time.plus_seconds(3 * 24 * 60 * 60);
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Scheduled,
+                started_at: None,
+                trigger: Some(TriggerConfiguration::Time {
+                    target_time: target_start_time,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        pause_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+        resume_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id).unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.status, VaultStatus::Scheduled);
+        assert_eq!(
+            updated_vault.trigger,
+            Some(TriggerConfiguration::Time {
+                target_time: target_start_time
+            })
+        );
+    }
+
+    #[test]
+    fn should_publish_vault_resumed_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Paused,
+                trigger: None,
+                ..Vault::default()
+            },
+        );
+
+        resume_vault_handler(deps.as_mut(), env.clone(), mock_info(USER, &[]), vault.id).unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(vault.id, env.block, EventData::DcaVaultResumed {}).build(1)
+        ));
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{
+    error::ContractError,
+    helpers::{
+        ibc::{get_ibc_transfer_channel, refresh_ibc_transfer_timeout},
+        validation::asset_sender_is_vault_owner,
+    },
+    state::{
+        cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+        claimable_balances::{
+            mark_post_execution_action_retried, post_execution_action_was_retried,
+            subtract_claimable_funds,
+        },
+        events::get_event,
+        vaults::get_vault,
+    },
+    types::event::EventData,
+};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+
+pub fn retry_post_execution_action_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+    event_id: u64,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+
+    let event = get_event(deps.storage, event_id)?;
+
+    if event.resource_id != vault.id {
+        return Err(ContractError::CustomError {
+            val: format!("event {} does not belong to vault {}", event_id, vault.id),
+        });
+    }
+
+    let (msg, funds) = match event.data {
+        EventData::DcaVaultPostExecutionActionFailed { msg, funds } => (msg, funds),
+        _ => {
+            return Err(ContractError::CustomError {
+                val: format!("event {} is not a failed post execution action", event_id),
+            })
+        }
+    };
+
+    if post_execution_action_was_retried(deps.storage, event_id) {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "post execution action {} has already been retried",
+                event_id
+            ),
+        });
+    }
+
+    subtract_claimable_funds(deps.storage, vault.owner.clone(), funds.clone()).map_err(|_| {
+        ContractError::CustomError {
+            val: format!(
+                "{} does not have enough claimable balance to retry post execution action {}",
+                vault.owner, event_id
+            ),
+        }
+    })?;
+
+    mark_post_execution_action_retried(deps.storage, event_id)?;
+
+    let timeout_seconds = get_ibc_transfer_channel(&msg.msg).and_then(|channel| {
+        vault
+            .destinations
+            .iter()
+            .filter_map(|destination| destination.ibc_transfer.as_ref())
+            .find(|ibc_transfer| ibc_transfer.channel_id == channel)
+            .and_then(|ibc_transfer| ibc_transfer.timeout_seconds)
+    });
+
+    let msg = SubMsg {
+        msg: refresh_ibc_transfer_timeout(&env, msg.msg, timeout_seconds),
+        ..msg
+    };
+
+    let mut cache = POST_EXECUTION_ACTION_CACHE
+        .may_load(deps.storage, vault.id.into())?
+        .unwrap_or_default();
+
+    cache.push_back(PostExecutionActionCacheEntry {
+        msg: msg.clone(),
+        funds,
+        destination_num: None,
+    });
+
+    POST_EXECUTION_ACTION_CACHE.save(deps.storage, vault.id.into(), &cache)?;
+
+    Ok(Response::new()
+        .add_attribute("retry_post_execution_action", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("event_id", event_id.to_string())
+        .add_submessage(msg))
+}
+
+#[cfg(test)]
+mod retry_post_execution_action_tests {
+    use super::*;
+    use crate::{
+        constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
+        handlers::{
+            claim::claim_handler, get_claimable::get_claimable_handler,
+            get_events_by_resource_id::get_events_by_resource_id_handler,
+            handle_failed_automation::handle_failed_automation_handler,
+        },
+        helpers::{disbursement::get_disbursement_messages, ibc::get_ibc_transfer_message},
+        state::events::create_event,
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, USER},
+        },
+        types::{
+            destination::{Destination, IbcTransfer},
+            event::EventBuilder,
+            vault::Vault,
+        },
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_json_binary, Addr, Coin, Decimal, Reply, SubMsgResult, WasmMsg,
+    };
+
+    fn setup_failed_post_execution_action(mut deps: DepsMut) -> (Vault, u64) {
+        let env = mock_env();
+
+        instantiate_contract(deps.branch(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.branch(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("contract"),
+                    allocation: Decimal::percent(100),
+                    msg: Some(
+                        to_json_binary(&WasmMsg::Execute {
+                            contract_addr: "contract".to_string(),
+                            msg: to_json_binary("test").unwrap(),
+                            funds: vec![],
+                        })
+                        .unwrap(),
+                    ),
+                    ibc_transfer: None,
+                    vault_id: None,
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.storage, &env, &vault, vault.swap_amount).unwrap();
+
+        handle_failed_automation_handler(
+            deps.branch(),
+            env,
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Err("error".to_string()),
+            },
+        )
+        .unwrap();
+
+        let event_id = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            Some(true),
+            None,
+        )
+        .unwrap()
+        .events[0]
+            .id;
+
+        (vault, event_id)
+    }
+
+    #[test]
+    fn with_non_owner_sender_fails() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-owner", &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_event_from_another_vault_fails() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        let other_vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            other_vault.id,
+            event_id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: event {} does not belong to vault {}",
+                event_id, other_vault.id
+            )
+        );
+        assert_ne!(vault.id, other_vault.id);
+    }
+
+    #[test]
+    fn with_other_event_type_fails() {
+        let mut deps = mock_dependencies();
+        let (vault, _) = setup_failed_post_execution_action(deps.as_mut());
+
+        let event_id = create_event(
+            deps.as_mut().storage,
+            EventBuilder::new(vault.id, mock_env().block, EventData::DcaVaultCancelled {}),
+        )
+        .unwrap();
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: event {} is not a failed post execution action",
+                event_id
+            )
+        );
+    }
+
+    #[test]
+    fn resends_failed_post_execution_action() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        let response = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap();
+
+        let cache = POST_EXECUTION_ACTION_CACHE
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        assert_eq!(response.messages, vec![cache[0].msg.clone()]);
+    }
+
+    #[test]
+    fn refreshes_ibc_transfer_timeout() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let ibc_transfer = IbcTransfer {
+            channel_id: "channel-3".to_string(),
+            memo: None,
+            timeout_seconds: Some(60),
+        };
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: Addr::unchecked("osmo1receiver"),
+                    allocation: Decimal::percent(100),
+                    msg: None,
+                    ibc_transfer: Some(ibc_transfer.clone()),
+                    vault_id: None,
+                }],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, vault.swap_amount).unwrap();
+
+        handle_failed_automation_handler(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: AFTER_FAILED_AUTOMATION_REPLY_ID,
+                result: SubMsgResult::Err("error".to_string()),
+            },
+        )
+        .unwrap();
+
+        let event_id = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            Some(true),
+            None,
+        )
+        .unwrap()
+        .events[0]
+            .id;
+
+        let mut retry_env = env;
+        retry_env.block.time = retry_env.block.time.plus_seconds(3600);
+
+        let response = retry_post_execution_action_handler(
+            deps.as_mut(),
+            retry_env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.messages[0].msg,
+            get_ibc_transfer_message(
+                &retry_env,
+                "osmo1receiver".to_string(),
+                &ibc_transfer,
+                Coin::new(vault.swap_amount.into(), vault.target_denom.clone()),
+            )
+        );
+    }
+
+    #[test]
+    fn deducts_funds_from_claimable_balance() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap();
+
+        let claimable = get_claimable_handler(deps.as_ref(), vault.owner)
+            .unwrap()
+            .claimable;
+
+        assert!(claimable.is_empty());
+    }
+
+    #[test]
+    fn retrying_twice_fails() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap();
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: post execution action {} has already been retried",
+                event_id
+            )
+        );
+    }
+
+    #[test]
+    fn after_claiming_funds_fails() {
+        let mut deps = mock_dependencies();
+        let (vault, event_id) = setup_failed_post_execution_action(deps.as_mut());
+
+        claim_handler(deps.as_mut(), mock_info(USER, &[])).unwrap();
+
+        let err = retry_post_execution_action_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            event_id,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: {} does not have enough claimable balance to retry post execution action {}",
+                vault.owner, event_id
+            )
+        );
+    }
+}
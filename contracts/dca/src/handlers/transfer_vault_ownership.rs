@@ -0,0 +1,444 @@
+use crate::error::ContractError;
+use crate::helpers::validation::{
+    assert_address_is_valid, assert_vault_is_not_cancelled, asset_sender_is_vault_owner,
+};
+use crate::state::events::create_event;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::vault::Vault;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response, Uint128};
+
+pub fn transfer_vault_ownership_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+    new_owner: Addr,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_is_not_cancelled(&vault)?;
+    assert_address_is_valid(deps.as_ref(), &new_owner, "new owner")?;
+
+    if new_owner == vault.owner {
+        return Err(ContractError::CustomError {
+            val: "new owner must be different to the current owner".to_string(),
+        });
+    }
+
+    let vault = update_vault(
+        deps.storage,
+        Vault {
+            pending_owner: Some(new_owner.clone()),
+            ..vault
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block,
+            EventData::DcaVaultOwnershipTransferProposed {
+                owner: vault.owner.clone(),
+                pending_owner: new_owner.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("transfer_vault_ownership", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner)
+        .add_attribute("pending_owner", new_owner))
+}
+
+pub fn accept_vault_ownership_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    if vault.pending_owner != Some(info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    assert_vault_is_not_cancelled(&vault)?;
+
+    let previous_owner = vault.owner.clone();
+
+    let vault = update_vault(
+        deps.storage,
+        Vault {
+            owner: info.sender.clone(),
+            pending_owner: None,
+            ..vault
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block,
+            EventData::DcaVaultOwnershipTransferred {
+                previous_owner: previous_owner.clone(),
+                new_owner: vault.owner.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("accept_vault_ownership", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("previous_owner", previous_owner)
+        .add_attribute("owner", vault.owner))
+}
+
+#[cfg(test)]
+mod transfer_vault_ownership_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not-owner", &[]),
+            vault.id,
+            Addr::unchecked("new-owner"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env,
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked("new-owner"),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is already cancelled");
+    }
+
+    #[test]
+    fn with_current_owner_as_new_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        let err = transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            vault.owner.clone(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: new owner must be different to the current owner"
+        );
+    }
+
+    #[test]
+    fn should_publish_ownership_transfer_proposed_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        transfer_vault_ownership_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked("new-owner"),
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert_eq!(
+            events.last().map(|event| event.data.clone()),
+            Some(EventData::DcaVaultOwnershipTransferProposed {
+                owner: vault.owner,
+                pending_owner: Addr::unchecked("new-owner"),
+            })
+        );
+    }
+
+    #[test]
+    fn should_save_pending_owner_without_changing_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env, Vault::default());
+
+        transfer_vault_ownership_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            Addr::unchecked("new-owner"),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.owner, vault.owner);
+        assert_eq!(
+            updated_vault.pending_owner,
+            Some(Addr::unchecked("new-owner"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod accept_vault_ownership_tests {
+    use super::*;
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::state::vaults::get_vaults_by_address;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, USER};
+    use crate::types::destination::Destination;
+    use crate::types::vault::{Vault, VaultStatus};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{to_json_binary, Decimal};
+
+    const NEW_OWNER: &str = "new-owner";
+
+    #[test]
+    fn with_sender_that_is_not_pending_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        let err = accept_vault_ownership_handler(
+            deps.as_mut(),
+            env,
+            mock_info("someone-else", &[]),
+            vault.id,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_no_pending_owner_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err =
+            accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn should_update_owner_and_clear_pending_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.owner, Addr::unchecked(NEW_OWNER));
+        assert_eq!(updated_vault.pending_owner, None);
+    }
+
+    #[test]
+    fn should_reindex_vault_under_new_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let previous_owner_vaults =
+            get_vaults_by_address(deps.as_ref().storage, vault.owner, None, None, None).unwrap();
+
+        let new_owner_vaults = get_vaults_by_address(
+            deps.as_ref().storage,
+            Addr::unchecked(NEW_OWNER),
+            Some(VaultStatus::Active),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(previous_owner_vaults.is_empty());
+        assert_eq!(new_owner_vaults.len(), 1);
+        assert_eq!(new_owner_vaults[0].id, vault.id);
+    }
+
+    #[test]
+    fn should_not_change_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let callback_destination = Destination {
+            allocation: Decimal::percent(50),
+            address: Addr::unchecked(USER),
+            msg: Some(to_json_binary("callback").unwrap()),
+            ibc_transfer: None,
+            vault_id: None,
+        };
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                destinations: vec![
+                    Destination {
+                        allocation: Decimal::percent(50),
+                        address: Addr::unchecked(USER),
+                        msg: None,
+                        ibc_transfer: None,
+                        vault_id: None,
+                    },
+                    callback_destination,
+                ],
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(deps.as_mut(), env, mock_info(NEW_OWNER, &[]), vault.id)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.destinations, vault.destinations);
+    }
+
+    #[test]
+    fn should_publish_ownership_transferred_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                pending_owner: Some(Addr::unchecked(NEW_OWNER)),
+                ..Vault::default()
+            },
+        );
+
+        accept_vault_ownership_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(NEW_OWNER, &[]),
+            vault.id,
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultOwnershipTransferred {
+                    previous_owner: vault.owner,
+                    new_owner: Addr::unchecked(NEW_OWNER),
+                },
+            )
+            .build(1)
+        ));
+    }
+}
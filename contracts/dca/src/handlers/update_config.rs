@@ -4,11 +4,16 @@ use crate::{
         assert_addresses_are_valid, assert_fee_collector_addresses_are_valid,
         assert_fee_collector_allocations_add_up_to_one, assert_fee_level_is_valid,
         assert_no_more_than_10_fee_collectors, assert_page_limit_is_valid,
+        assert_performance_fee_percent_is_no_greater_than_100_percent,
+        assert_referral_fee_share_is_no_greater_than_one,
         assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent,
         assert_sender_is_admin, assert_slippage_tolerance_is_less_than_or_equal_to_one,
-        assert_twap_period_is_valid,
+        assert_swap_adjustment_quorum_is_valid, assert_twap_period_is_valid,
+    },
+    state::{
+        config::{get_config, update_config},
+        swap_adjustments::remove_swap_adjustment_submissions_from_former_submitters,
     },
-    state::config::{get_config, update_config},
     types::{config::Config, fee_collector::FeeCollector},
 };
 use cosmwasm_std::{Addr, Decimal, DepsMut, MessageInfo, Response};
@@ -27,10 +32,19 @@ pub fn update_config_handler(
     twap_period: Option<u64>,
     default_slippage_tolerance: Option<Decimal>,
     exchange_contract_address: Option<Addr>,
+    referral_fee_share: Option<Decimal>,
+    value_averaging_swap_fee_percent: Option<Decimal>,
+    swap_adjustment_submitters: Option<Vec<Addr>>,
+    swap_adjustment_quorum: Option<u64>,
+    performance_fee_percent: Option<Decimal>,
+    escrow_crystallisation_period_seconds: Option<u64>,
+    hook_gas_limit: Option<u64>,
 ) -> Result<Response, ContractError> {
     assert_sender_is_admin(deps.storage, info.sender)?;
     let existing_config = get_config(deps.storage)?;
 
+    let submitters_changed = swap_adjustment_submitters.is_some() || executors.is_some();
+
     let config = Config {
         admin: existing_config.admin,
         executors: executors.unwrap_or(existing_config.executors),
@@ -51,15 +65,57 @@ pub fn update_config_handler(
         old_staking_router_address: existing_config.old_staking_router_address,
         exchange_contract_address: exchange_contract_address
             .unwrap_or(existing_config.exchange_contract_address),
+        referral_fee_share: referral_fee_share.unwrap_or(existing_config.referral_fee_share),
+        value_averaging_swap_fee_percent: match value_averaging_swap_fee_percent {
+            Some(fee_percent) if fee_percent.is_zero() => None,
+            Some(fee_percent) => Some(fee_percent),
+            None => existing_config.value_averaging_swap_fee_percent,
+        },
+        swap_adjustment_submitters: swap_adjustment_submitters
+            .unwrap_or(existing_config.swap_adjustment_submitters),
+        swap_adjustment_quorum: swap_adjustment_quorum
+            .unwrap_or(existing_config.swap_adjustment_quorum),
+        performance_fee_percent: match performance_fee_percent {
+            Some(fee_percent) if fee_percent.is_zero() => None,
+            Some(fee_percent) => Some(fee_percent),
+            None => existing_config.performance_fee_percent,
+        },
+        escrow_crystallisation_period_seconds: match escrow_crystallisation_period_seconds {
+            Some(0) => None,
+            Some(period) => Some(period),
+            None => existing_config.escrow_crystallisation_period_seconds,
+        },
+        hook_gas_limit: match hook_gas_limit {
+            Some(0) => None,
+            Some(gas_limit) => Some(gas_limit),
+            None => existing_config.hook_gas_limit,
+        },
     };
 
+    if let Some(value_averaging_swap_fee_percent) = config.value_averaging_swap_fee_percent {
+        assert_fee_level_is_valid(&value_averaging_swap_fee_percent)?;
+    }
+
     assert_fee_level_is_valid(&config.default_swap_fee_percent)?;
     assert_fee_level_is_valid(&config.weighted_scale_swap_fee_percent)?;
     assert_fee_level_is_valid(&config.automation_fee_percent)?;
+    assert_referral_fee_share_is_no_greater_than_one(config.referral_fee_share)?;
     assert_page_limit_is_valid(Some(config.default_page_limit))?;
     assert_slippage_tolerance_is_less_than_or_equal_to_one(config.default_slippage_tolerance)?;
     assert_twap_period_is_valid(config.twap_period)?;
     assert_addresses_are_valid(deps.as_ref(), &config.executors, "executor")?;
+    assert_addresses_are_valid(
+        deps.as_ref(),
+        &config.swap_adjustment_submitters,
+        "swap adjustment submitter",
+    )?;
+    if let Some(performance_fee_percent) = config.performance_fee_percent {
+        assert_performance_fee_percent_is_no_greater_than_100_percent(performance_fee_percent)?;
+    }
+    assert_swap_adjustment_quorum_is_valid(
+        config.swap_adjustment_quorum,
+        &config.swap_adjustment_submitters,
+    )?;
     assert_no_more_than_10_fee_collectors(&config.fee_collectors)?;
     assert_fee_collector_addresses_are_valid(deps.as_ref(), &config.fee_collectors)?;
     assert_fee_collector_allocations_add_up_to_one(&config.fee_collectors)?;
@@ -69,6 +125,10 @@ pub fn update_config_handler(
 
     let config = update_config(deps.storage, config)?;
 
+    if submitters_changed {
+        remove_swap_adjustment_submissions_from_former_submitters(deps.storage, &config)?;
+    }
+
     Ok(Response::default()
         .add_attribute("update_config", "true")
         .add_attribute("config", format!("{:?}", config)))
@@ -78,12 +138,16 @@ pub fn update_config_handler(
 mod update_config_tests {
     use super::*;
     use crate::{
-        state::config::get_config,
+        state::{
+            config::get_config,
+            swap_adjustments::{get_swap_adjustment_submissions, update_swap_adjustment},
+        },
         tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::swap_adjustment_strategy::SwapAdjustmentStrategy,
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Decimal,
+        Addr, Decimal,
     };
     use std::str::FromStr;
 
@@ -110,6 +174,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -147,6 +218,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -176,6 +254,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -205,6 +290,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -232,6 +324,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -261,6 +360,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -290,6 +396,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -333,6 +446,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -371,6 +491,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -401,6 +528,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -433,6 +567,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -463,6 +604,13 @@ mod update_config_tests {
             None,
             Some(Decimal::percent(150)),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -472,6 +620,79 @@ mod update_config_tests {
         )
     }
 
+    #[test]
+    fn update_referral_fee_share_with_valid_value_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(20)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.referral_fee_share, Decimal::percent(20));
+    }
+
+    #[test]
+    fn update_referral_fee_share_more_than_100_percent_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(101)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: referral fee share must be less than or equal to 1"
+        )
+    }
+
     #[test]
     fn with_more_than_10_fee_collectors_should_fail() {
         let mut deps = mock_dependencies();
@@ -499,6 +720,13 @@ mod update_config_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -507,4 +735,542 @@ mod update_config_tests {
             "Error: no more than 10 fee collectors are allowed"
         )
     }
+
+    #[test]
+    fn update_value_averaging_swap_fee_percent_with_valid_value_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(2)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            config.value_averaging_swap_fee_percent,
+            Some(Decimal::percent(2))
+        );
+    }
+
+    #[test]
+    fn update_value_averaging_swap_fee_percent_more_than_5_percent_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(6)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: fee level cannot be larger than 5%");
+    }
+
+    #[test]
+    fn update_swap_adjustment_submitters_and_quorum_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![
+                Addr::unchecked("submitter-1"),
+                Addr::unchecked("submitter-2"),
+            ]),
+            Some(2),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            config.swap_adjustment_submitters,
+            vec![
+                Addr::unchecked("submitter-1"),
+                Addr::unchecked("submitter-2")
+            ]
+        );
+        assert_eq!(config.swap_adjustment_quorum, 2);
+    }
+
+    #[test]
+    fn update_swap_adjustment_submitters_should_remove_submissions_from_former_submitters() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        for submitter in ["submitter-1", "submitter-2"] {
+            update_swap_adjustment(
+                deps.as_mut().storage,
+                strategy.clone(),
+                &Addr::unchecked(submitter),
+                Decimal::percent(90),
+                env.block.time,
+            )
+            .unwrap();
+        }
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![
+                Addr::unchecked("submitter-2"),
+                Addr::unchecked("submitter-3"),
+            ]),
+            Some(1),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let submissions = get_swap_adjustment_submissions(deps.as_ref().storage, strategy).unwrap();
+
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].submitter, Addr::unchecked("submitter-2"));
+    }
+
+    #[test]
+    fn update_swap_adjustment_quorum_more_than_submitters_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Addr::unchecked("submitter-1")]),
+            Some(2),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: swap adjustment quorum cannot be greater than the number of submitters (1)"
+        );
+    }
+
+    #[test]
+    fn update_performance_fee_percent_with_valid_value_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(15)),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.performance_fee_percent, Some(Decimal::percent(15)));
+    }
+
+    #[test]
+    fn update_performance_fee_percent_more_than_100_percent_should_fail() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        let err = update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(101)),
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: performance_fee_percent cannot be greater than 100%"
+        );
+    }
+
+    #[test]
+    fn update_escrow_crystallisation_period_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(60 * 60 * 24 * 30),
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            config.escrow_crystallisation_period_seconds,
+            Some(60 * 60 * 24 * 30)
+        );
+    }
+
+    #[test]
+    fn update_escrow_crystallisation_period_to_zero_should_disable_crystallisation() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(60 * 60 * 24 * 30),
+            None,
+        )
+        .unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.escrow_crystallisation_period_seconds, None);
+    }
+
+    #[test]
+    fn update_hook_gas_limit_should_succeed() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(100_000),
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.hook_gas_limit, Some(100_000));
+    }
+
+    #[test]
+    fn update_performance_fee_percent_to_zero_should_reset_to_default() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(15)),
+            None,
+            None,
+        )
+        .unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::zero()),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.performance_fee_percent, None);
+    }
+
+    #[test]
+    fn update_value_averaging_swap_fee_percent_to_zero_should_reset_to_default() {
+        let mut deps = mock_dependencies();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), mock_env(), info.clone());
+
+        update_config_handler(
+            deps.as_mut(),
+            info.clone(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::percent(2)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        update_config_handler(
+            deps.as_mut(),
+            info,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Decimal::zero()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(config.value_averaging_swap_fee_percent, None);
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{
     error::ContractError,
-    helpers::validation::{assert_sender_is_executor, assert_swap_adjustment_value_is_valid},
+    helpers::validation::{
+        assert_sender_is_swap_adjustment_submitter, assert_swap_adjustment_value_is_valid,
+    },
     state::swap_adjustments::update_swap_adjustment,
     types::swap_adjustment_strategy::SwapAdjustmentStrategy,
 };
@@ -13,12 +15,19 @@ pub fn update_swap_adjustment_handler(
     strategy: SwapAdjustmentStrategy,
     value: Decimal,
 ) -> Result<Response, ContractError> {
-    assert_sender_is_executor(deps.storage, &env, &info.sender)?;
+    assert_sender_is_swap_adjustment_submitter(deps.storage, &env, &info.sender)?;
     assert_swap_adjustment_value_is_valid(&strategy, value)?;
-    update_swap_adjustment(deps.storage, strategy.clone(), value, env.block.time)?;
+    update_swap_adjustment(
+        deps.storage,
+        strategy.clone(),
+        &info.sender,
+        value,
+        env.block.time,
+    )?;
 
     Ok(Response::new()
         .add_attribute("strategy", format!("{:?}", strategy))
+        .add_attribute("submitter", info.sender)
         .add_attribute("value", value.to_string()))
 }
 
@@ -26,16 +35,21 @@ pub fn update_swap_adjustment_handler(
 mod update_swap_adjustments_tests {
     use super::*;
     use crate::{
-        state::swap_adjustments::get_swap_adjustment,
+        handlers::get_swap_adjustment_submissions::get_swap_adjustment_submissions_handler,
+        state::{
+            config::{get_config, update_config},
+            swap_adjustments::get_swap_adjustment,
+        },
         tests::{helpers::instantiate_contract, mocks::ADMIN},
         types::{
+            config::Config,
             position_type::PositionType,
             swap_adjustment_strategy::{BaseDenom, SwapAdjustmentStrategy},
         },
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Decimal,
+        Addr, Decimal,
     };
 
     #[test]
@@ -138,10 +152,112 @@ mod update_swap_adjustments_tests {
         )
         .unwrap();
 
-        let stored_adjustment =
-            get_swap_adjustment(deps.as_ref().storage, strategy, env.block.time);
+        let stored_adjustment = get_swap_adjustment(
+            deps.as_ref().storage,
+            strategy,
+            &get_config(deps.as_ref().storage).unwrap(),
+            env.block.time,
+        );
 
         assert_ne!(stored_adjustment, old_value);
         assert_eq!(stored_adjustment, new_value);
     }
+
+    #[test]
+    fn with_configured_submitters_and_non_submitter_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(ADMIN, &[]);
+
+        instantiate_contract(deps.as_mut(), env.clone(), info.clone());
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                swap_adjustment_submitters: vec![Addr::unchecked("submitter-1")],
+                ..config
+            },
+        )
+        .unwrap();
+
+        let err = update_swap_adjustment_handler(
+            deps.as_mut(),
+            env,
+            info,
+            SwapAdjustmentStrategy::default(),
+            Decimal::percent(125),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_configured_submitters_aggregates_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                swap_adjustment_submitters: vec![
+                    Addr::unchecked("submitter-1"),
+                    Addr::unchecked("submitter-2"),
+                    Addr::unchecked("submitter-3"),
+                ],
+                swap_adjustment_quorum: 2,
+                ..config
+            },
+        )
+        .unwrap();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        update_swap_adjustment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("submitter-1", &[]),
+            strategy.clone(),
+            Decimal::percent(90),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_adjustment_submissions_handler(deps.as_ref(), env.clone(), strategy.clone())
+                .unwrap()
+                .value,
+            Decimal::one()
+        );
+
+        update_swap_adjustment_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("submitter-2", &[]),
+            strategy.clone(),
+            Decimal::percent(130),
+        )
+        .unwrap();
+
+        let response =
+            get_swap_adjustment_submissions_handler(deps.as_ref(), env, strategy).unwrap();
+
+        assert_eq!(response.value, Decimal::percent(110));
+        assert_eq!(
+            response
+                .submissions
+                .iter()
+                .map(|submission| (submission.submitter.to_string(), submission.value))
+                .collect::<Vec<_>>(),
+            vec![
+                ("submitter-1".to_string(), Decimal::percent(90)),
+                ("submitter-2".to_string(), Decimal::percent(130)),
+            ]
+        );
+    }
 }
@@ -3,14 +3,18 @@ use crate::{
     helpers::{
         time::get_next_target_time,
         validation::{
-            assert_destination_allocations_add_up_to_one,
+            assert_address_is_valid, assert_destination_allocations_add_up_to_one,
             assert_destination_callback_addresses_are_valid,
-            assert_destinations_limit_is_not_breached,
+            assert_destinations_limit_is_not_breached, assert_end_time_is_after_start_time,
+            assert_exit_conditions_are_valid, assert_ibc_transfer_destinations_are_valid,
             assert_label_is_no_longer_than_100_characters,
-            assert_no_destination_allocations_are_zero,
-            assert_slippage_tolerance_is_less_than_or_equal_to_one, assert_time_interval_is_valid,
-            assert_vault_is_not_cancelled, assert_weighted_scale_multiplier_is_no_more_than_10,
-            asset_sender_is_vault_owner,
+            assert_max_executions_is_greater_than_execution_count,
+            assert_maximum_receive_amount_is_greater_than_minimum_receive_amount,
+            assert_no_destination_allocations_are_zero, assert_price_band_is_valid,
+            assert_slippage_tolerance_is_less_than_or_equal_to_one,
+            assert_swap_adjustment_strategy_params_are_valid, assert_time_interval_is_valid,
+            assert_vault_destinations_are_valid, assert_vault_is_not_cancelled,
+            assert_weighted_scale_multiplier_is_no_more_than_10, asset_sender_is_vault_owner,
         },
     },
     state::{
@@ -21,13 +25,17 @@ use crate::{
     types::{
         destination::Destination,
         event::{EventBuilder, EventData},
+        exit_condition::ExitCondition,
+        price_band::PriceBand,
         swap_adjustment_strategy::{SwapAdjustmentStrategy, SwapAdjustmentStrategyParams},
         time_interval::TimeInterval,
         trigger::{Trigger, TriggerConfiguration},
         update::Update,
     },
 };
-use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    Addr, Decimal, DepsMut, Env, MessageInfo, Response, Timestamp, Uint128, Uint64,
+};
 
 pub fn update_vault_handler(
     deps: DepsMut,
@@ -41,6 +49,13 @@ pub fn update_vault_handler(
     time_interval: Option<TimeInterval>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
     swap_amount: Option<Uint128>,
+    end_time_utc_seconds: Option<Uint64>,
+    max_executions: Option<u64>,
+    stop_loss: Option<ExitCondition>,
+    take_profit: Option<ExitCondition>,
+    maximum_receive_amount: Option<Uint128>,
+    price_band: Option<PriceBand>,
+    hook: Option<Addr>,
 ) -> Result<Response, ContractError> {
     let mut vault = get_vault(deps.storage, vault_id)?;
 
@@ -62,6 +77,13 @@ pub fn update_vault_handler(
             });
         }
 
+        if maximum_receive_amount.is_some() {
+            return Err(ContractError::CustomError {
+                val: "cannot update swap amount and maximum receive amount at the same time."
+                    .to_string(),
+            });
+        }
+
         if swap_adjustment_strategy.is_some() {
             return Err(ContractError::CustomError {
                 val: "cannot update swap amount and swap adjustment strategy at the same time."
@@ -86,6 +108,23 @@ pub fn update_vault_handler(
             );
         }
 
+        if let Some(maximum_receive_amount) = vault.maximum_receive_amount {
+            let updated_maximum_receive_amount =
+                Some(maximum_receive_amount * Decimal::from_ratio(swap_amount, vault.swap_amount));
+
+            updates.push(Update {
+                field: "maximum_receive_amount".to_string(),
+                old_value: format!("{:?}", vault.maximum_receive_amount),
+                new_value: format!("{:?}", updated_maximum_receive_amount),
+            });
+
+            vault.maximum_receive_amount = updated_maximum_receive_amount;
+            response = response.add_attribute(
+                "maximum_receive_amount",
+                format!("{:?}", vault.maximum_receive_amount),
+            );
+        }
+
         if let Some(SwapAdjustmentStrategy::WeightedScale {
             base_receive_amount,
             multiplier,
@@ -141,11 +180,24 @@ pub fn update_vault_handler(
                 allocation: Decimal::percent(100),
                 address: vault.owner.clone(),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             });
         }
 
         assert_destinations_limit_is_not_breached(&destinations)?;
         assert_destination_callback_addresses_are_valid(deps.as_ref(), &destinations)?;
+        assert_ibc_transfer_destinations_are_valid(
+            deps.storage,
+            &destinations,
+            &vault.target_denom,
+        )?;
+        assert_vault_destinations_are_valid(
+            deps.storage,
+            &destinations,
+            &vault.target_denom,
+            Some(vault.id),
+        )?;
         assert_no_destination_allocations_are_zero(&destinations)?;
         assert_destination_allocations_add_up_to_one(&destinations)?;
 
@@ -183,6 +235,50 @@ pub fn update_vault_handler(
         response = response.add_attribute("minimum_receive_amount", minimum_receive_amount);
     }
 
+    if minimum_receive_amount.is_some() || maximum_receive_amount.is_some() {
+        assert_maximum_receive_amount_is_greater_than_minimum_receive_amount(
+            minimum_receive_amount.or(vault.minimum_receive_amount),
+            maximum_receive_amount.or(vault.maximum_receive_amount),
+        )?;
+    }
+
+    if let Some(maximum_receive_amount) = maximum_receive_amount {
+        updates.push(Update {
+            field: "maximum_receive_amount".to_string(),
+            old_value: format!("{:?}", vault.maximum_receive_amount),
+            new_value: format!("{:?}", Some(maximum_receive_amount)),
+        });
+
+        vault.maximum_receive_amount = Some(maximum_receive_amount);
+        response = response.add_attribute("maximum_receive_amount", maximum_receive_amount);
+    }
+
+    if let Some(price_band) = price_band {
+        assert_price_band_is_valid(&price_band)?;
+
+        updates.push(Update {
+            field: "price_band".to_string(),
+            old_value: format!("{:?}", vault.price_band),
+            new_value: format!("{:?}", Some(price_band.clone())),
+        });
+
+        vault.price_band = Some(price_band);
+        response = response.add_attribute("price_band", format!("{:?}", vault.price_band));
+    }
+
+    if let Some(hook) = hook {
+        assert_address_is_valid(deps.as_ref(), &hook, "hook")?;
+
+        updates.push(Update {
+            field: "hook".to_string(),
+            old_value: format!("{:?}", vault.hook),
+            new_value: format!("{:?}", Some(hook.clone())),
+        });
+
+        vault.hook = Some(hook.clone());
+        response = response.add_attribute("hook", hook);
+    }
+
     if let Some(time_interval) = time_interval {
         assert_time_interval_is_valid(&time_interval)?;
 
@@ -224,6 +320,67 @@ pub fn update_vault_handler(
         }
     }
 
+    if let Some(end_time_utc_seconds) = end_time_utc_seconds {
+        let end_time = Timestamp::from_seconds(end_time_utc_seconds.u64());
+
+        assert_end_time_is_after_start_time(env.block.time, end_time)?;
+
+        updates.push(Update {
+            field: "end_time".to_string(),
+            old_value: format!("{:?}", vault.end_time),
+            new_value: format!("{:?}", Some(end_time)),
+        });
+
+        vault.end_time = Some(end_time);
+        response = response.add_attribute("end_time", end_time.to_string());
+    }
+
+    if let Some(max_executions) = max_executions {
+        assert_max_executions_is_greater_than_execution_count(
+            max_executions,
+            vault.execution_count,
+        )?;
+
+        updates.push(Update {
+            field: "max_executions".to_string(),
+            old_value: format!("{:?}", vault.max_executions),
+            new_value: format!("{:?}", Some(max_executions)),
+        });
+
+        vault.max_executions = Some(max_executions);
+        response = response.add_attribute("max_executions", max_executions.to_string());
+    }
+
+    if stop_loss.is_some() || take_profit.is_some() {
+        assert_exit_conditions_are_valid(
+            &stop_loss.clone().or(vault.stop_loss.clone()),
+            &take_profit.clone().or(vault.take_profit.clone()),
+            &vault.swap_adjustment_strategy,
+        )?;
+    }
+
+    if let Some(stop_loss) = stop_loss {
+        updates.push(Update {
+            field: "stop_loss".to_string(),
+            old_value: format!("{:?}", vault.stop_loss),
+            new_value: format!("{:?}", Some(stop_loss.clone())),
+        });
+
+        vault.stop_loss = Some(stop_loss);
+        response = response.add_attribute("stop_loss", format!("{:?}", vault.stop_loss));
+    }
+
+    if let Some(take_profit) = take_profit {
+        updates.push(Update {
+            field: "take_profit".to_string(),
+            old_value: format!("{:?}", vault.take_profit),
+            new_value: format!("{:?}", Some(take_profit.clone())),
+        });
+
+        vault.take_profit = Some(take_profit);
+        response = response.add_attribute("take_profit", format!("{:?}", vault.take_profit));
+    }
+
     match swap_adjustment_strategy {
         Some(SwapAdjustmentStrategyParams::WeightedScale {
             base_receive_amount,
@@ -259,6 +416,78 @@ pub fn update_vault_handler(
                 })
             }
         },
+        Some(SwapAdjustmentStrategyParams::ValueAveraging {
+            target_growth_per_execution,
+        }) => match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::ValueAveraging { target_value, .. }) => {
+                assert_swap_adjustment_strategy_params_are_valid(
+                    &SwapAdjustmentStrategyParams::ValueAveraging {
+                        target_growth_per_execution,
+                    },
+                )?;
+
+                updates.push(Update {
+                    field: "swap_adjustment_strategy".to_string(),
+                    old_value: format!("{:?}", vault.swap_adjustment_strategy),
+                    new_value: format!("{:?}", swap_adjustment_strategy),
+                });
+
+                vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution,
+                    target_value,
+                });
+
+                response = response.add_attribute(
+                    "swap_adjustment_strategy",
+                    format!("{:?}", vault.swap_adjustment_strategy),
+                );
+            }
+            _ => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "cannot update swap adjustment strategy from {:?} to {:?}",
+                        vault.swap_adjustment_strategy, swap_adjustment_strategy
+                    ),
+                })
+            }
+        },
+        Some(SwapAdjustmentStrategyParams::MovingAverage {
+            periods,
+            multiplier,
+        }) => match vault.swap_adjustment_strategy {
+            Some(SwapAdjustmentStrategy::MovingAverage { .. }) => {
+                assert_swap_adjustment_strategy_params_are_valid(
+                    &SwapAdjustmentStrategyParams::MovingAverage {
+                        periods,
+                        multiplier,
+                    },
+                )?;
+
+                updates.push(Update {
+                    field: "swap_adjustment_strategy".to_string(),
+                    old_value: format!("{:?}", vault.swap_adjustment_strategy),
+                    new_value: format!("{:?}", swap_adjustment_strategy),
+                });
+
+                vault.swap_adjustment_strategy = Some(SwapAdjustmentStrategy::MovingAverage {
+                    periods,
+                    multiplier,
+                });
+
+                response = response.add_attribute(
+                    "swap_adjustment_strategy",
+                    format!("{:?}", vault.swap_adjustment_strategy),
+                );
+            }
+            _ => {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "cannot update swap adjustment strategy from {:?} to {:?}",
+                        vault.swap_adjustment_strategy, swap_adjustment_strategy
+                    ),
+                })
+            }
+        },
         Some(swap_adjustment_strategy) => {
             return Err(ContractError::CustomError {
                 val: format!(
@@ -296,7 +525,9 @@ mod update_vault_tests {
             config::Config,
             destination::Destination,
             event::{Event, EventData},
+            exit_condition::{ExitAction, ExitCondition},
             position_type::PositionType,
+            price_band::PriceBand,
             swap_adjustment_strategy::{
                 BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
             },
@@ -308,7 +539,7 @@ mod update_vault_tests {
     };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Decimal, Uint128,
+        Addr, Decimal, Timestamp, Uint128,
     };
 
     #[test]
@@ -331,6 +562,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -360,6 +598,13 @@ mod update_vault_tests {
             Some(TimeInterval::Custom { seconds: 12 }),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -391,6 +636,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -429,6 +681,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -462,6 +721,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -479,6 +745,8 @@ mod update_vault_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             };
             11
         ];
@@ -495,6 +763,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -515,11 +790,15 @@ mod update_vault_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(10),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -535,6 +814,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -555,11 +841,15 @@ mod update_vault_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(51),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -575,6 +865,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -595,11 +892,15 @@ mod update_vault_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(100),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::zero(),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -615,6 +916,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -660,6 +968,13 @@ mod update_vault_tests {
             None,
             Some(new_swap_adjustment_strategy.clone()),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -709,6 +1024,13 @@ mod update_vault_tests {
             None,
             new_swap_adjustment_strategy.clone(),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -745,6 +1067,13 @@ mod update_vault_tests {
             None,
             strategy.clone(),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -775,6 +1104,13 @@ mod update_vault_tests {
             None,
             None,
             Some(Uint128::new(3498473290)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -809,6 +1145,13 @@ mod update_vault_tests {
             })
             ,
             Some(Uint128::new(436753262)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap_err();
 
@@ -846,6 +1189,13 @@ mod update_vault_tests {
             None,
             None,
             Some(vault.swap_amount * Uint128::new(2)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -886,6 +1236,13 @@ mod update_vault_tests {
             None,
             None,
             Some(vault.swap_amount * Uint128::new(2)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -933,6 +1290,13 @@ mod update_vault_tests {
             None,
             None,
             Some(swap_amount),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -981,6 +1345,13 @@ mod update_vault_tests {
             None,
             strategy,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1016,6 +1387,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1035,11 +1413,15 @@ mod update_vault_tests {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("random"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -1055,6 +1437,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1082,6 +1471,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1094,6 +1490,8 @@ mod update_vault_tests {
                 address: vault.owner,
                 allocation: Decimal::percent(100),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             }]
         );
     }
@@ -1118,7 +1516,14 @@ mod update_vault_tests {
             None,
             None,
             None,
-        )
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
         .unwrap();
 
         let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
@@ -1146,6 +1551,13 @@ mod update_vault_tests {
             None,
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1154,6 +1566,417 @@ mod update_vault_tests {
         assert_eq!(updated_vault.minimum_receive_amount, minimum_receive_amount);
     }
 
+    #[test]
+    fn with_end_time_in_the_past_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(env.block.time.minus_seconds(10).seconds().into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: end_time_utc_seconds must be after the vault start time and the current time"
+        );
+    }
+
+    #[test]
+    fn with_max_executions_not_greater_than_execution_count_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_count: 5,
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(5),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: max_executions must be greater than the current execution count of 5"
+        );
+    }
+
+    #[test]
+    fn updates_end_conditions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                execution_count: 5,
+                ..Vault::default()
+            },
+        );
+
+        update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(env.block.time.plus_seconds(3600).seconds().into()),
+            Some(10),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.end_time,
+            Some(Timestamp::from_seconds(
+                env.block.time.plus_seconds(3600).seconds()
+            ))
+        );
+        assert_eq!(updated_vault.max_executions, Some(10));
+    }
+
+    #[test]
+    fn with_stop_loss_price_above_existing_take_profit_price_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                take_profit: Some(ExitCondition {
+                    price: Decimal::percent(110),
+                    action: ExitAction::SwapRemainingBalance,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(120),
+                action: ExitAction::CancelVault,
+            }),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: stop loss price must be less than take profit price"
+        );
+    }
+
+    #[test]
+    fn updates_exit_conditions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                    model_id: 30,
+                    base_denom: BaseDenom::Bitcoin,
+                    position_type: PositionType::Exit,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            }),
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.stop_loss,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            })
+        );
+        assert_eq!(
+            updated_vault.take_profit,
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            })
+        );
+    }
+
+    #[test]
+    fn with_exit_conditions_on_non_exit_position_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            }),
+            Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            None,
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: stop loss and take profit can only be set on exit position vaults"
+        );
+    }
+
+    #[test]
+    fn with_maximum_receive_amount_below_existing_minimum_receive_amount_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                minimum_receive_amount: Some(Uint128::new(1000)),
+                ..Vault::default()
+            },
+        );
+
+        let err = update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::new(500)),
+            None,
+            None,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: maximum receive amount must be greater than minimum receive amount"
+        );
+    }
+
+    #[test]
+    fn updates_maximum_receive_amount_and_price_band() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::new(1000)),
+            Some(PriceBand {
+                lower_deviation: Decimal::percent(5),
+                upper_deviation: Decimal::percent(10),
+            }),
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.maximum_receive_amount,
+            Some(Uint128::new(1000))
+        );
+        assert_eq!(
+            updated_vault.price_band,
+            Some(PriceBand {
+                lower_deviation: Decimal::percent(5),
+                upper_deviation: Decimal::percent(10),
+            })
+        );
+    }
+
+    #[test]
+    fn updating_swap_amount_updates_maximum_receive_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_amount: Uint128::new(100),
+                maximum_receive_amount: Some(Uint128::new(200)),
+                ..Vault::default()
+            },
+        );
+
+        update_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::new(50)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.maximum_receive_amount,
+            Some(Uint128::new(100))
+        );
+    }
+
     #[test]
     fn updates_time_interval() {
         let mut deps = mock_dependencies();
@@ -1174,6 +1997,13 @@ mod update_vault_tests {
             Some(time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1203,6 +2033,13 @@ mod update_vault_tests {
             Some(time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
@@ -1223,6 +2060,39 @@ mod update_vault_tests {
         }
     }
 
+    #[test]
+    fn updates_the_vault_hook() {
+        let mut deps = mock_dependencies();
+
+        let vault = setup_vault(deps.as_mut(), mock_env(), Vault::default());
+
+        update_vault_handler(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(USER, &[]),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Addr::unchecked("hook")),
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(updated_vault.hook, Some(Addr::unchecked("hook")));
+    }
+
     #[test]
     fn publishes_vault_updated_event() {
         let mut deps = mock_dependencies();
@@ -1240,11 +2110,15 @@ mod update_vault_tests {
                 address: Addr::unchecked("random-1"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("random-2"),
                 allocation: Decimal::percent(50),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
         let new_slippage_tolerance = Decimal::percent(12);
@@ -1262,12 +2136,20 @@ mod update_vault_tests {
             Some(new_time_interval.clone()),
             None,
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(
             events.first().unwrap(),
@@ -0,0 +1,394 @@
+use crate::error::ContractError;
+use crate::helpers::coin::get_send_message;
+use crate::helpers::validation::{assert_vault_is_not_cancelled, asset_sender_is_vault_owner};
+use crate::helpers::vault::get_swap_adjustment_strategy_for_balance;
+use crate::state::events::create_event;
+use crate::state::vaults::{get_vault, update_vault};
+use crate::types::event::{EventBuilder, EventData};
+use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategy;
+use crate::types::vault::{Vault, VaultStatus};
+use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128};
+use shared::coin::subtract_from;
+
+pub fn withdraw_from_vault_handler(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_id: Uint128,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let vault = get_vault(deps.storage, vault_id)?;
+
+    asset_sender_is_vault_owner(vault.owner.clone(), info.sender)?;
+    assert_vault_is_not_cancelled(&vault)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::CustomError {
+            val: "withdrawal amount must be greater than 0".to_string(),
+        });
+    }
+
+    if amount > vault.balance.amount {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "cannot withdraw {} from vault with balance {}",
+                amount, vault.balance
+            ),
+        });
+    }
+
+    if let Some(PerformanceAssessmentStrategy::CompareToStandardDca {
+        swapped_amount, ..
+    }) = vault.performance_assessment_strategy.clone()
+    {
+        let standard_dca_balance = vault
+            .deposited_amount
+            .amount
+            .checked_sub(swapped_amount.amount)
+            .unwrap_or_default();
+
+        if amount > standard_dca_balance {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "cannot withdraw {} from vault with remaining standard dca balance {}",
+                    amount, standard_dca_balance
+                ),
+            });
+        }
+    }
+
+    let withdrawn_amount = Coin::new(amount.into(), vault.get_swap_denom());
+    let new_balance = subtract_from(vault.balance.clone(), amount);
+    let swap_adjustment_strategy =
+        get_swap_adjustment_strategy_for_balance(deps.storage, &env, &vault, &new_balance)?;
+
+    let vault = update_vault(
+        deps.storage,
+        Vault {
+            balance: new_balance.clone(),
+            deposited_amount: subtract_from(vault.deposited_amount.clone(), amount),
+            status: if new_balance.amount.is_zero() && vault.is_active() {
+                VaultStatus::Inactive
+            } else {
+                vault.status.clone()
+            },
+            swap_adjustment_strategy,
+            ..vault
+        },
+    )?;
+
+    create_event(
+        deps.storage,
+        EventBuilder::new(
+            vault.id,
+            env.block,
+            EventData::DcaVaultFundsWithdrawn {
+                amount: withdrawn_amount.clone(),
+            },
+        ),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("withdraw_from_vault", "true")
+        .add_attribute("vault_id", vault.id)
+        .add_attribute("owner", vault.owner.clone())
+        .add_attribute("withdrawn_amount", withdrawn_amount.to_string())
+        .add_submessage(SubMsg::new(get_send_message(
+            deps.storage,
+            vault.owner.to_string(),
+            withdrawn_amount,
+        ))))
+}
+
+#[cfg(test)]
+mod withdraw_from_vault_tests {
+    use super::*;
+    use crate::constants::{ONE, TEN};
+    use crate::handlers::get_events_by_resource_id::get_events_by_resource_id_handler;
+    use crate::tests::helpers::{instantiate_contract, setup_vault};
+    use crate::tests::mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK, USER};
+    use crate::types::vault::Vault;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::BankMsg;
+
+    #[test]
+    fn with_unauthorised_sender_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info("not-owner", &[]),
+            vault.id,
+            ONE,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Unauthorized");
+    }
+
+    #[test]
+    fn with_cancelled_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                ..Vault::default()
+            },
+        );
+
+        let err =
+            withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+                .unwrap_err();
+
+        assert_eq!(err.to_string(), "Error: vault is already cancelled");
+    }
+
+    #[test]
+    fn with_zero_amount_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            Uint128::zero(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Error: withdrawal amount must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn with_amount_larger_than_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            vault.balance.amount + Uint128::one(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: cannot withdraw {} from vault with balance {}",
+                vault.balance.amount + Uint128::one(),
+                vault.balance
+            )
+        );
+    }
+
+    #[test]
+    fn with_amount_larger_than_standard_dca_balance_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((TEN - ONE).into(), DENOM_UKUJI),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                performance_assessment_strategy: Some(
+                    PerformanceAssessmentStrategy::CompareToStandardDca {
+                        swapped_amount: Coin::new((ONE + ONE).into(), DENOM_UKUJI),
+                        received_amount: Coin::new((ONE + ONE).into(), DENOM_UUSK),
+                    },
+                ),
+                ..Vault::default()
+            },
+        );
+
+        let err = withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            TEN - ONE,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Error: cannot withdraw {} from vault with remaining standard dca balance {}",
+                TEN - ONE,
+                TEN - ONE - ONE
+            )
+        );
+    }
+
+    #[test]
+    fn should_reduce_balance_and_deposited_amount() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.balance,
+            subtract_from(vault.balance.clone(), ONE)
+        );
+        assert_eq!(
+            updated_vault.deposited_amount,
+            subtract_from(vault.deposited_amount, ONE)
+        );
+        assert_eq!(updated_vault.status, VaultStatus::Active);
+    }
+
+    #[test]
+    fn should_send_withdrawn_amount_to_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        let response =
+            withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+                .unwrap();
+
+        assert!(response.messages.contains(&SubMsg::new(BankMsg::Send {
+            to_address: vault.owner.to_string(),
+            amount: vec![Coin::new(ONE.into(), vault.get_swap_denom())],
+        })));
+    }
+
+    #[test]
+    fn with_entire_balance_should_make_vault_inactive() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env,
+            mock_info(USER, &[]),
+            vault.id,
+            vault.balance.amount,
+        )
+        .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert!(updated_vault.balance.amount.is_zero());
+        assert_eq!(updated_vault.status, VaultStatus::Inactive);
+    }
+
+    #[test]
+    fn should_keep_performance_assessment_comparable() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let performance_assessment_strategy = PerformanceAssessmentStrategy::CompareToStandardDca {
+            swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+            received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+        };
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new((TEN - ONE).into(), DENOM_UKUJI),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UKUJI),
+                swapped_amount: Coin::new(ONE.into(), DENOM_UKUJI),
+                performance_assessment_strategy: Some(performance_assessment_strategy.clone()),
+                ..Vault::default()
+            },
+        );
+
+        withdraw_from_vault_handler(deps.as_mut(), env, mock_info(USER, &[]), vault.id, ONE)
+            .unwrap();
+
+        let updated_vault = get_vault(deps.as_ref().storage, vault.id).unwrap();
+
+        assert_eq!(
+            updated_vault.performance_assessment_strategy,
+            Some(performance_assessment_strategy)
+        );
+        assert_eq!(
+            updated_vault.deposited_amount.amount - updated_vault.swapped_amount.amount,
+            updated_vault.balance.amount
+        );
+    }
+
+    #[test]
+    fn should_publish_funds_withdrawn_event() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(deps.as_mut(), env.clone(), Vault::default());
+
+        withdraw_from_vault_handler(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(USER, &[]),
+            vault.id,
+            ONE,
+        )
+        .unwrap();
+
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
+
+        assert!(events.contains(
+            &EventBuilder::new(
+                vault.id,
+                env.block,
+                EventData::DcaVaultFundsWithdrawn {
+                    amount: Coin::new(ONE.into(), vault.get_swap_denom()),
+                },
+            )
+            .build(1)
+        ));
+    }
+}
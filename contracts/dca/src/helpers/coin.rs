@@ -0,0 +1,215 @@
+use super::authz::create_authz_exec_message;
+use crate::state::cw20_denoms::is_cw20_denom;
+use cosmos_sdk_proto::cosmos::{bank::v1beta1::MsgSend, base::v1beta1::Coin as ProtoCoin};
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Env, QuerierWrapper, StdResult,
+    Storage, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+
+pub fn get_send_message(storage: &dyn Storage, to_address: String, coin: Coin) -> CosmosMsg {
+    if is_cw20_denom(storage, &coin.denom) {
+        return CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: coin.denom,
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: to_address,
+                amount: coin.amount,
+            })
+            .expect("serialised cw20 transfer msg"),
+            funds: vec![],
+        });
+    }
+
+    CosmosMsg::Bank(BankMsg::Send {
+        to_address,
+        amount: vec![coin],
+    })
+}
+
+pub fn get_execute_message(
+    storage: &dyn Storage,
+    contract_addr: String,
+    msg: Binary,
+    coin: Coin,
+) -> CosmosMsg {
+    if is_cw20_denom(storage, &coin.denom) {
+        return CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: coin.denom,
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: contract_addr,
+                amount: coin.amount,
+                msg,
+            })
+            .expect("serialised cw20 send msg"),
+            funds: vec![],
+        });
+    }
+
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr,
+        msg,
+        funds: vec![coin],
+    })
+}
+
+pub fn get_allowance_pull_message(
+    storage: &dyn Storage,
+    env: &Env,
+    owner: &Addr,
+    coin: Coin,
+) -> CosmosMsg {
+    if is_cw20_denom(storage, &coin.denom) {
+        return CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: coin.denom,
+            msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: owner.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: coin.amount,
+            })
+            .expect("serialised cw20 transfer from msg"),
+            funds: vec![],
+        });
+    }
+
+    create_authz_exec_message(
+        env.contract.address.clone(),
+        String::from("/cosmos.bank.v1beta1.MsgSend"),
+        MsgSend {
+            from_address: owner.to_string(),
+            to_address: env.contract.address.to_string(),
+            amount: vec![ProtoCoin {
+                denom: coin.denom,
+                amount: coin.amount.to_string(),
+            }],
+        },
+    )
+}
+
+pub fn query_balance(
+    querier: &QuerierWrapper,
+    storage: &dyn Storage,
+    address: &Addr,
+    denom: String,
+) -> StdResult<Coin> {
+    if is_cw20_denom(storage, &denom) {
+        let response = querier.query_wasm_smart::<BalanceResponse>(
+            denom.clone(),
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )?;
+
+        return Ok(Coin::new(response.balance.into(), denom));
+    }
+
+    querier.query_balance(address, denom)
+}
+
+#[cfg(test)]
+mod get_send_message_tests {
+    use super::get_send_message;
+    use crate::{state::cw20_denoms::save_cw20_denom, tests::mocks::DENOM_UKUJI};
+    use cosmwasm_std::{
+        testing::mock_dependencies, to_json_binary, BankMsg, Coin, CosmosMsg, Uint128, WasmMsg,
+    };
+    use cw20::Cw20ExecuteMsg;
+
+    #[test]
+    fn sends_native_denoms_via_bank_send() {
+        let deps = mock_dependencies();
+
+        let coin = Coin::new(100, DENOM_UKUJI);
+
+        assert_eq!(
+            get_send_message(deps.as_ref().storage, "recipient".to_string(), coin.clone()),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "recipient".to_string(),
+                amount: vec![coin],
+            })
+        );
+    }
+
+    #[test]
+    fn sends_cw20_denoms_via_cw20_transfer() {
+        let mut deps = mock_dependencies();
+
+        save_cw20_denom(deps.as_mut().storage, "cw20-token").unwrap();
+
+        assert_eq!(
+            get_send_message(
+                deps.as_ref().storage,
+                "recipient".to_string(),
+                Coin::new(100, "cw20-token")
+            ),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20-token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: "recipient".to_string(),
+                    amount: Uint128::new(100),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+mod get_execute_message_tests {
+    use super::get_execute_message;
+    use crate::{state::cw20_denoms::save_cw20_denom, tests::mocks::DENOM_UKUJI};
+    use cosmwasm_std::{
+        testing::mock_dependencies, to_json_binary, Coin, CosmosMsg, Uint128, WasmMsg,
+    };
+    use cw20::Cw20ExecuteMsg;
+
+    #[test]
+    fn attaches_native_denoms_as_funds() {
+        let deps = mock_dependencies();
+
+        let msg = to_json_binary("test").unwrap();
+        let coin = Coin::new(100, DENOM_UKUJI);
+
+        assert_eq!(
+            get_execute_message(
+                deps.as_ref().storage,
+                "contract".to_string(),
+                msg.clone(),
+                coin.clone()
+            ),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "contract".to_string(),
+                msg,
+                funds: vec![coin],
+            })
+        );
+    }
+
+    #[test]
+    fn wraps_msg_in_cw20_send_for_cw20_denoms() {
+        let mut deps = mock_dependencies();
+
+        save_cw20_denom(deps.as_mut().storage, "cw20-token").unwrap();
+
+        let msg = to_json_binary("test").unwrap();
+
+        assert_eq!(
+            get_execute_message(
+                deps.as_ref().storage,
+                "contract".to_string(),
+                msg.clone(),
+                Coin::new(100, "cw20-token")
+            ),
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "cw20-token".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: "contract".to_string(),
+                    amount: Uint128::new(100),
+                    msg,
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use crate::error::ContractError;
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, TimeZone, Timelike, Utc};
+
+const MAX_SEARCH_DAYS: i64 = 366 * 8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    days_of_month_restricted: bool,
+    days_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<CronSchedule, ContractError> {
+        let fields = expression.split_whitespace().collect::<Vec<&str>>();
+
+        if fields.len() != 5 {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "cron expression {} must have 5 fields (minute hour day-of-month month day-of-week)",
+                    expression
+                ),
+            });
+        }
+
+        let days_of_month = parse_field(fields[2], 1, 31, "day-of-month")?;
+        let mut days_of_week = parse_field(fields[4], 0, 7, "day-of-week")?;
+
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(fields[0], 0, 59, "minute")?,
+            hours: parse_field(fields[1], 0, 23, "hour")?,
+            days_of_month,
+            months: parse_field(fields[3], 1, 12, "month")?,
+            days_of_week,
+            days_of_month_restricted: is_restricted(fields[2], days_of_month, 1, 31),
+            days_of_week_restricted: is_restricted(fields[4], days_of_week, 0, 6),
+        })
+    }
+
+    pub fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = time.with_second(0).unwrap() + Duration::minutes(1);
+        let mut date = start.date_naive();
+
+        while (date - start.date_naive()).num_days() < MAX_SEARCH_DAYS {
+            if !is_set(self.months, date.month()) {
+                date = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?
+                    .checked_add_months(Months::new(1))?;
+                continue;
+            }
+
+            if self.matches_date(date) {
+                let (first_hour, first_minute) = if date == start.date_naive() {
+                    (start.hour(), start.minute())
+                } else {
+                    (0, 0)
+                };
+
+                for hour in first_hour..24 {
+                    if !is_set(self.hours, hour) {
+                        continue;
+                    }
+
+                    let minute_floor = if hour == first_hour { first_minute } else { 0 };
+
+                    if let Some(minute) = (minute_floor..60).find(|m| is_set(self.minutes, *m)) {
+                        return Some(Utc.from_utc_datetime(&date.and_hms_opt(hour, minute, 0)?));
+                    }
+                }
+            }
+
+            date = date.succ_opt()?;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !is_set(self.months, date.month()) {
+            return false;
+        }
+
+        let day_of_month_matches = is_set(self.days_of_month, date.day());
+        let day_of_week_matches = is_set(self.days_of_week, date.weekday().num_days_from_sunday());
+
+        match (self.days_of_month_restricted, self.days_of_week_restricted) {
+            (true, true) => day_of_month_matches || day_of_week_matches,
+            _ => day_of_month_matches && day_of_week_matches,
+        }
+    }
+}
+
+fn is_set(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+fn full_mask(min: u32, max: u32) -> u64 {
+    (min..=max).fold(0, |mask, value| mask | (1 << value))
+}
+
+// Day fields only combine with OR when both are restricted. As in vixie cron, a field
+// is unrestricted when it starts with a wildcard (e.g. */2) or covers every value.
+fn is_restricted(field: &str, mask: u64, min: u32, max: u32) -> bool {
+    !field.starts_with('*') && mask != full_mask(min, max)
+}
+
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, ContractError> {
+    let invalid = || ContractError::CustomError {
+        val: format!(
+            "invalid cron {} field {}, values must be between {} and {}",
+            name, field, min, max
+        ),
+    };
+
+    let parse_value = |value: &str| -> Result<u32, ContractError> {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|v| (min..=max).contains(v))
+            .ok_or_else(invalid)
+    };
+
+    field.split(',').try_fold(0u64, |mask, part| {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                step.parse::<u32>()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(invalid)?,
+            ),
+            None => (part, 1),
+        };
+
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+                None => {
+                    let value = parse_value(range)?;
+                    (value, if step > 1 { max } else { value })
+                }
+            },
+        };
+
+        if start > end {
+            return Err(invalid());
+        }
+
+        Ok((start..=end)
+            .step_by(step as usize)
+            .fold(mask, |mask, value| mask | (1 << value)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(expression: &str, time: DateTime<Utc>) -> DateTime<Utc> {
+        CronSchedule::parse(expression)
+            .unwrap()
+            .next_after(time)
+            .unwrap()
+    }
+
+    #[test]
+    fn with_wrong_number_of_fields_fails() {
+        assert_eq!(
+            CronSchedule::parse("0 14 * *").unwrap_err().to_string(),
+            "Error: cron expression 0 14 * * must have 5 fields (minute hour day-of-month month day-of-week)"
+        );
+    }
+
+    #[test]
+    fn with_out_of_range_value_fails() {
+        assert_eq!(
+            CronSchedule::parse("0 24 * * *").unwrap_err().to_string(),
+            "Error: invalid cron hour field 24, values must be between 0 and 23"
+        );
+    }
+
+    #[test]
+    fn with_inverted_range_fails() {
+        assert!(CronSchedule::parse("0 14 * * 5-1").is_err());
+    }
+
+    #[test]
+    fn with_zero_step_fails() {
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+    }
+
+    #[test]
+    fn with_impossible_date_never_matches() {
+        assert_eq!(
+            CronSchedule::parse("0 0 30 2 *")
+                .unwrap()
+                .next_after(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn every_weekday_at_1400_skips_weekends() {
+        let friday_afternoon = Utc.with_ymd_and_hms(2023, 6, 2, 15, 0, 0).unwrap();
+
+        assert_eq!(
+            next("0 14 * * 1-5", friday_afternoon),
+            Utc.with_ymd_and_hms(2023, 6, 5, 14, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn every_weekday_at_1400_runs_later_the_same_day() {
+        let monday_morning = Utc.with_ymd_and_hms(2023, 6, 5, 9, 30, 0).unwrap();
+
+        assert_eq!(
+            next("0 14 * * 1-5", monday_morning),
+            Utc.with_ymd_and_hms(2023, 6, 5, 14, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn first_and_fifteenth_of_each_month() {
+        let schedule = CronSchedule::parse("0 0 1,15 * *").unwrap();
+
+        let first = schedule
+            .next_after(Utc.with_ymd_and_hms(2023, 1, 20, 0, 0, 0).unwrap())
+            .unwrap();
+        let second = schedule.next_after(first).unwrap();
+
+        assert_eq!(first, Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap());
+        assert_eq!(second, Utc.with_ymd_and_hms(2023, 2, 15, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn next_is_strictly_after_a_matching_time() {
+        let matching_time = Utc.with_ymd_and_hms(2023, 6, 5, 14, 0, 0).unwrap();
+
+        assert_eq!(
+            next("0 14 * * *", matching_time),
+            Utc.with_ymd_and_hms(2023, 6, 6, 14, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn steps_are_applied_from_range_start() {
+        assert_eq!(
+            next(
+                "5/20 * * * *",
+                Utc.with_ymd_and_hms(2023, 6, 5, 14, 26, 0).unwrap()
+            ),
+            Utc.with_ymd_and_hms(2023, 6, 5, 14, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn day_of_month_and_day_of_week_match_either() {
+        assert_eq!(
+            next(
+                "0 0 13 * 5",
+                Utc.with_ymd_and_hms(2023, 6, 10, 0, 0, 0).unwrap()
+            ),
+            Utc.with_ymd_and_hms(2023, 6, 13, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn day_of_month_with_wildcard_step_must_match_day_of_week() {
+        assert_eq!(
+            next(
+                "0 0 */2 * 1",
+                Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap()
+            ),
+            Utc.with_ymd_and_hms(2023, 6, 5, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn day_of_week_covering_every_day_is_unrestricted() {
+        assert_eq!(
+            next(
+                "0 0 13 * 0-6",
+                Utc.with_ymd_and_hms(2023, 6, 10, 0, 0, 0).unwrap()
+            ),
+            Utc.with_ymd_and_hms(2023, 6, 13, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn sunday_can_be_seven() {
+        assert_eq!(
+            CronSchedule::parse("0 0 * * 7").unwrap(),
+            CronSchedule::parse("0 0 * * 0").unwrap()
+        );
+    }
+
+    #[test]
+    fn leap_day_is_found() {
+        assert_eq!(
+            next(
+                "0 0 29 2 *",
+                Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap()
+            ),
+            Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()
+        );
+    }
+}
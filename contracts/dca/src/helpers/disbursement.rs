@@ -1,62 +1,87 @@
-use super::math::checked_mul;
+use super::{
+    coin::{get_execute_message, get_send_message},
+    ibc::get_ibc_transfer_message,
+    math::checked_mul,
+    reply::get_vault_reply_id,
+    vault::deposit_into_vault,
+};
 use crate::{
     constants::AFTER_FAILED_AUTOMATION_REPLY_ID,
-    state::cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+    state::{
+        cache::{PostExecutionActionCacheEntry, POST_EXECUTION_ACTION_CACHE},
+        vaults::get_vault,
+    },
     types::vault::Vault,
 };
-use cosmwasm_std::{BankMsg, Coin, StdResult, Storage, SubMsg, Uint128, WasmMsg};
+use cosmwasm_std::{Coin, Env, StdResult, Storage, SubMsg, Uint128};
 use std::collections::VecDeque;
 
 pub fn get_disbursement_messages(
     store: &mut dyn Storage,
+    env: &Env,
     vault: &Vault,
     amount_to_disburse: Uint128,
 ) -> StdResult<VecDeque<SubMsg>> {
     let mut post_execution_action_caches = VecDeque::<PostExecutionActionCacheEntry>::new();
+    let mut messages = VecDeque::<SubMsg>::new();
 
-    let messages = vault
-        .destinations
-        .iter()
-        .flat_map(|destination| {
-            let allocation_amount = Coin::new(
-                checked_mul(amount_to_disburse, destination.allocation)
-                    .expect("amount to be distributed should be valid")
-                    .into(),
-                vault.target_denom.clone(),
-            );
-
-            if allocation_amount.amount.gt(&Uint128::zero()) {
-                let msg = destination.msg.clone().map_or(
-                    SubMsg::reply_always(
-                        BankMsg::Send {
-                            to_address: destination.address.to_string(),
-                            amount: vec![allocation_amount.clone()],
-                        },
-                        AFTER_FAILED_AUTOMATION_REPLY_ID,
-                    ),
-                    |msg| {
-                        SubMsg::reply_always(
-                            WasmMsg::Execute {
-                                contract_addr: destination.address.to_string(),
-                                msg,
-                                funds: vec![allocation_amount.clone()],
-                            },
-                            AFTER_FAILED_AUTOMATION_REPLY_ID,
-                        )
-                    },
-                );
+    for (index, destination) in vault.destinations.iter().enumerate() {
+        let allocation_amount = Coin::new(
+            checked_mul(amount_to_disburse, destination.allocation)
+                .expect("amount to be distributed should be valid")
+                .into(),
+            vault.target_denom.clone(),
+        );
 
-                post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
-                    msg: msg.clone(),
-                    funds: vec![allocation_amount],
-                });
+        if allocation_amount.amount.is_zero() {
+            continue;
+        }
 
-                return Some(msg);
+        if let Some(destination_vault) = destination
+            .vault_id
+            .and_then(|vault_id| get_vault(store, vault_id).ok())
+            .filter(|destination_vault| {
+                !destination_vault.is_cancelled()
+                    && destination_vault.balance.denom == allocation_amount.denom
+            })
+        {
+            if deposit_into_vault(store, env, destination_vault, allocation_amount.clone()).is_ok()
+            {
+                continue;
             }
+        }
+
+        let msg = SubMsg::reply_always(
+            match (&destination.ibc_transfer, destination.msg.clone()) {
+                (Some(ibc_transfer), _) => get_ibc_transfer_message(
+                    env,
+                    destination.address.to_string(),
+                    ibc_transfer,
+                    allocation_amount.clone(),
+                ),
+                (None, Some(msg)) => get_execute_message(
+                    store,
+                    destination.address.to_string(),
+                    msg,
+                    allocation_amount.clone(),
+                ),
+                (None, None) => get_send_message(
+                    store,
+                    destination.address.to_string(),
+                    allocation_amount.clone(),
+                ),
+            },
+            get_vault_reply_id(AFTER_FAILED_AUTOMATION_REPLY_ID, vault.id),
+        );
 
-            None
-        })
-        .collect::<VecDeque<SubMsg>>();
+        post_execution_action_caches.push_back(PostExecutionActionCacheEntry {
+            msg: msg.clone(),
+            funds: vec![allocation_amount],
+            destination_num: Some(index + 1),
+        });
+
+        messages.push_back(msg);
+    }
 
     POST_EXECUTION_ACTION_CACHE.save(store, vault.id.into(), &post_execution_action_caches)?;
 
@@ -67,12 +92,23 @@ pub fn get_disbursement_messages(
 mod get_disbursement_messages_tests {
     use super::get_disbursement_messages;
     use crate::{
-        constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE},
-        state::cache::POST_EXECUTION_ACTION_CACHE,
-        types::{destination::Destination, vault::Vault},
+        constants::{AFTER_FAILED_AUTOMATION_REPLY_ID, ONE, TEN},
+        handlers::get_events_by_resource_id::get_events_by_resource_id_handler,
+        helpers::{ibc::get_ibc_transfer_message, reply::get_vault_reply_id},
+        state::{cache::POST_EXECUTION_ACTION_CACHE, vaults::get_vault},
+        tests::{
+            helpers::{instantiate_contract, setup_vault},
+            mocks::{ADMIN, DENOM_UKUJI, DENOM_UUSK},
+        },
+        types::{
+            destination::{Destination, IbcTransfer},
+            event::EventData,
+            vault::{Vault, VaultStatus},
+        },
     };
     use cosmwasm_std::{
-        testing::mock_dependencies, to_json_binary, Addr, BankMsg, Coin, Decimal, SubMsg, WasmMsg,
+        testing::{mock_dependencies, mock_env, mock_info},
+        to_json_binary, Addr, BankMsg, Coin, Decimal, SubMsg, Uint128, WasmMsg,
     };
 
     #[test]
@@ -83,6 +119,8 @@ mod get_disbursement_messages_tests {
             address: Addr::unchecked("test"),
             allocation: Decimal::percent(100),
             msg: None,
+            ibc_transfer: None,
+            vault_id: None,
         };
 
         let vault = Vault {
@@ -90,7 +128,8 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        let messages = get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        let messages =
+            get_disbursement_messages(deps.as_mut().storage, &mock_env(), &vault, ONE).unwrap();
 
         assert!(messages.contains(&SubMsg::reply_always(
             BankMsg::Send {
@@ -101,6 +140,257 @@ mod get_disbursement_messages_tests {
         )))
     }
 
+    #[test]
+    fn generates_ibc_transfers_for_ibc_transfer_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let ibc_transfer = IbcTransfer {
+            channel_id: "channel-3".to_string(),
+            memo: None,
+            timeout_seconds: None,
+        };
+
+        let destination = Destination {
+            address: Addr::unchecked("osmo1receiver"),
+            allocation: Decimal::percent(100),
+            msg: None,
+            ibc_transfer: Some(ibc_transfer.clone()),
+            vault_id: None,
+        };
+
+        let vault = Vault {
+            destinations: vec![destination.clone()],
+            ..Vault::default()
+        };
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                get_ibc_transfer_message(
+                    &env,
+                    destination.address.to_string(),
+                    &ibc_transfer,
+                    Coin::new(ONE.into(), vault.target_denom),
+                ),
+                AFTER_FAILED_AUTOMATION_REPLY_ID
+            )]
+        );
+    }
+
+    #[test]
+    fn deposits_into_vault_destinations() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(TEN.into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                target_denom: DENOM_UKUJI.to_string(),
+                ..Vault::default()
+            },
+        );
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: destination_vault.owner.clone(),
+                    allocation: Decimal::percent(100),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: Some(destination_vault.id),
+                }],
+                ..Vault::default()
+            },
+        );
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        let updated_destination_vault =
+            get_vault(deps.as_ref().storage, destination_vault.id).unwrap();
+
+        let events = get_events_by_resource_id_handler(
+            deps.as_ref(),
+            destination_vault.id,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
+
+        assert!(messages.is_empty());
+        assert_eq!(
+            updated_destination_vault.balance,
+            Coin::new((TEN + ONE).into(), DENOM_UUSK)
+        );
+        assert_eq!(
+            events[0].data,
+            EventData::DcaVaultFundsDeposited {
+                amount: Coin::new(ONE.into(), DENOM_UUSK)
+            }
+        );
+    }
+
+    #[test]
+    fn sends_funds_to_vault_destination_owner_when_destination_vault_is_cancelled() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                status: VaultStatus::Cancelled,
+                balance: Coin::new(0, DENOM_UUSK),
+                target_denom: DENOM_UKUJI.to_string(),
+                ..Vault::default()
+            },
+        );
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: destination_vault.owner.clone(),
+                    allocation: Decimal::percent(100),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: Some(destination_vault.id),
+                }],
+                ..Vault::default()
+            },
+        );
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: destination_vault.owner.to_string(),
+                    amount: vec![Coin::new(ONE.into(), DENOM_UUSK)],
+                },
+                get_vault_reply_id(AFTER_FAILED_AUTOMATION_REPLY_ID, vault.id)
+            )]
+        );
+    }
+
+    #[test]
+    fn sends_funds_to_vault_destination_owner_when_deposit_into_destination_vault_fails() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(Uint128::MAX.into(), DENOM_UUSK),
+                target_denom: DENOM_UKUJI.to_string(),
+                ..Vault::default()
+            },
+        );
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![Destination {
+                    address: destination_vault.owner.clone(),
+                    allocation: Decimal::percent(100),
+                    msg: None,
+                    ibc_transfer: None,
+                    vault_id: Some(destination_vault.id),
+                }],
+                ..Vault::default()
+            },
+        );
+
+        let messages = get_disbursement_messages(deps.as_mut().storage, &env, &vault, ONE).unwrap();
+
+        assert_eq!(
+            messages,
+            vec![SubMsg::reply_always(
+                BankMsg::Send {
+                    to_address: destination_vault.owner.to_string(),
+                    amount: vec![Coin::new(ONE.into(), DENOM_UUSK)],
+                },
+                get_vault_reply_id(AFTER_FAILED_AUTOMATION_REPLY_ID, vault.id)
+            )]
+        );
+        assert_eq!(
+            get_vault(deps.as_ref().storage, destination_vault.id)
+                .unwrap()
+                .balance,
+            destination_vault.balance
+        );
+    }
+
+    #[test]
+    fn numbers_cached_messages_by_destination_position() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let destination_vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                balance: Coin::new(TEN.into(), DENOM_UUSK),
+                deposited_amount: Coin::new(TEN.into(), DENOM_UUSK),
+                target_denom: DENOM_UKUJI.to_string(),
+                ..Vault::default()
+            },
+        );
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                destinations: vec![
+                    Destination {
+                        address: destination_vault.owner.clone(),
+                        allocation: Decimal::percent(50),
+                        msg: None,
+                        ibc_transfer: None,
+                        vault_id: Some(destination_vault.id),
+                    },
+                    Destination {
+                        address: Addr::unchecked("owner"),
+                        allocation: Decimal::percent(50),
+                        msg: None,
+                        ibc_transfer: None,
+                        vault_id: None,
+                    },
+                ],
+                ..Vault::default()
+            },
+        );
+
+        get_disbursement_messages(deps.as_mut().storage, &env, &vault, TEN).unwrap();
+
+        let cache = POST_EXECUTION_ACTION_CACHE
+            .load(deps.as_ref().storage, vault.id.into())
+            .unwrap();
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache[0].destination_num, Some(2));
+    }
+
     #[test]
     fn saves_disbursement_messages_to_cache_queue() {
         let mut deps = mock_dependencies();
@@ -110,6 +400,8 @@ mod get_disbursement_messages_tests {
                 address: Addr::unchecked("owner"),
                 allocation: Decimal::percent(30),
                 msg: None,
+                ibc_transfer: None,
+                vault_id: None,
             },
             Destination {
                 address: Addr::unchecked("contract"),
@@ -122,6 +414,8 @@ mod get_disbursement_messages_tests {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             },
         ];
 
@@ -130,7 +424,7 @@ mod get_disbursement_messages_tests {
             ..Vault::default()
         };
 
-        get_disbursement_messages(deps.as_mut().storage, &vault, ONE).unwrap();
+        get_disbursement_messages(deps.as_mut().storage, &mock_env(), &vault, ONE).unwrap();
 
         let mut cache = POST_EXECUTION_ACTION_CACHE
             .load(deps.as_ref().storage, vault.id.into())
@@ -1,8 +1,10 @@
-use super::math::checked_mul;
+use super::{coin::get_send_message, math::checked_mul};
 use crate::{
-    state::config::get_config,
+    state::{
+        config::get_config, custom_swap_fees::find_custom_swap_fee, cw20_denoms::is_cw20_denom,
+    },
     types::{
-        fee_collector::FeeCollector,
+        config::Config, fee_collector::FeeCollector,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
     },
@@ -12,7 +14,7 @@ use cosmos_sdk_proto::{
     traits::Message,
 };
 use cosmwasm_std::{
-    BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, Env, StdResult, Storage, SubMsg, Uint128,
+    Binary, Coin, CosmosMsg, Decimal, Deps, Env, StdResult, Storage, SubMsg, Uint128,
 };
 use std::cmp::min;
 
@@ -25,6 +27,8 @@ pub fn get_fee_messages(
 ) -> StdResult<Vec<SubMsg>> {
     let config = get_config(deps.storage)?;
 
+    let skip_community_pool = skip_community_pool || is_cw20_denom(deps.storage, &denom);
+
     let fee_collectors = config
         .fee_collectors
         .iter()
@@ -81,10 +85,11 @@ pub fn get_fee_messages(
                                 }))
                             }
                         }
-                        _ => Some(SubMsg::new(BankMsg::Send {
-                            to_address: fee_collector.address.to_string(),
-                            amount: vec![fee_allocation],
-                        })),
+                        _ => Some(SubMsg::new(get_send_message(
+                            deps.storage,
+                            fee_collector.address.to_string(),
+                            fee_allocation,
+                        ))),
                     }
                 } else {
                     None
@@ -107,49 +112,64 @@ pub fn get_automation_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResul
     )?)
 }
 
-pub fn get_swap_fee_rate(
-    storage: &dyn Storage,
-    swap_adjustment_strategy: &Option<SwapAdjustmentStrategy>,
-) -> StdResult<Decimal> {
+pub fn get_swap_fee_rate(storage: &dyn Storage, vault: &Vault) -> StdResult<Decimal> {
     let config = get_config(storage)?;
 
-    Ok(match swap_adjustment_strategy {
+    let default_swap_fee_percent = match vault.swap_adjustment_strategy {
         Some(SwapAdjustmentStrategy::WeightedScale { .. }) => {
             config.weighted_scale_swap_fee_percent
         }
-        Some(_) => Decimal::zero(),
-        None => config.default_swap_fee_percent,
-    })
+        Some(SwapAdjustmentStrategy::ValueAveraging { .. }) => config
+            .value_averaging_swap_fee_percent
+            .unwrap_or(config.default_swap_fee_percent),
+        Some(SwapAdjustmentStrategy::MovingAverage { .. }) | None => {
+            config.default_swap_fee_percent
+        }
+        Some(_) => return Ok(Decimal::zero()),
+    };
+
+    Ok(
+        find_custom_swap_fee(storage, vault.get_swap_denom(), vault.target_denom.clone())?
+            .unwrap_or(default_swap_fee_percent),
+    )
+}
+
+pub fn get_added_value(vault: &Vault, current_price: Decimal) -> Uint128 {
+    match vault.performance_assessment_strategy.clone() {
+        Some(PerformanceAssessmentStrategy::CompareToStandardDca {
+            swapped_amount,
+            received_amount,
+        }) => {
+            let vault_total_value = vault.deposited_amount.amount - vault.swapped_amount.amount
+                + vault.received_amount.amount * current_price;
+
+            let standard_dca_total_value = vault.deposited_amount.amount - swapped_amount.amount
+                + received_amount.amount * current_price;
+
+            vault_total_value
+                .checked_sub(standard_dca_total_value)
+                .unwrap_or(Uint128::zero())
+                * (Decimal::one() / current_price)
+        }
+        None => Uint128::zero(),
+    }
 }
 
-pub fn get_performance_fee(vault: &Vault, current_price: Decimal) -> StdResult<Coin> {
+pub fn get_performance_fee(
+    vault: &Vault,
+    current_price: Decimal,
+    config: &Config,
+) -> StdResult<Coin> {
     Ok(vault.performance_assessment_strategy.clone().map_or(
         Coin::new(0, vault.target_denom.clone()),
-        |strategy| match strategy.clone() {
-            PerformanceAssessmentStrategy::CompareToStandardDca {
-                swapped_amount,
-                received_amount,
-            } => {
-                let vault_total_value = vault.deposited_amount.amount - vault.swapped_amount.amount
-                    + vault.received_amount.amount * current_price;
-
-                let standard_dca_total_value = vault.deposited_amount.amount
-                    - swapped_amount.amount
-                    + received_amount.amount * current_price;
-
-                let added_value_in_terms_of_receive_denom = vault_total_value
-                    .checked_sub(standard_dca_total_value)
-                    .unwrap_or(Uint128::zero())
-                    * (Decimal::one() / current_price);
-
-                Coin {
-                    denom: vault.target_denom.clone(),
-                    amount: min(
-                        vault.escrowed_amount.amount,
-                        added_value_in_terms_of_receive_denom * strategy.performance_fee_rate(),
-                    ),
-                }
-            }
+        |strategy| Coin {
+            denom: vault.target_denom.clone(),
+            amount: min(
+                vault.escrowed_amount.amount,
+                get_added_value(vault, current_price)
+                    .saturating_sub(vault.performance_fee_high_water_mark)
+                    * strategy.performance_fee_rate(config),
+            ),
         },
     ))
 }
@@ -160,10 +180,13 @@ mod tests {
     use crate::{
         constants::{ONE, TEN},
         helpers::fees::get_performance_fee,
-        state::config::get_config,
+        state::{
+            config::{get_config, update_config},
+            custom_swap_fees::{get_custom_swap_fee_key, save_custom_swap_fee},
+        },
         tests::{helpers::instantiate_contract, mocks::ADMIN},
         types::{
-            performance_assessment_strategy::PerformanceAssessmentStrategy,
+            config::Config, performance_assessment_strategy::PerformanceAssessmentStrategy,
             swap_adjustment_strategy::SwapAdjustmentStrategy, vault::Vault,
         },
     };
@@ -229,7 +252,7 @@ mod tests {
             standard_dca_received_amount,
         );
 
-        let fee = get_performance_fee(&vault, current_price).unwrap();
+        let fee = get_performance_fee(&vault, current_price, &Config::default()).unwrap();
         assert_eq!(fee.amount, expected_fee);
     }
 
@@ -237,7 +260,7 @@ mod tests {
     fn non_zero_fee_is_in_vault_receive_denom() {
         let vault = get_vault(TEN, TEN, TEN, TEN + TEN, TEN);
 
-        let fee = get_performance_fee(&vault, Decimal::one()).unwrap();
+        let fee = get_performance_fee(&vault, Decimal::one(), &Config::default()).unwrap();
         assert_eq!(fee.denom, vault.target_denom);
     }
 
@@ -245,7 +268,7 @@ mod tests {
     fn zero_fee_is_in_vault_receive_denom() {
         let vault = get_vault(TEN, TEN, TEN, TEN, TEN);
 
-        let fee = get_performance_fee(&vault, Decimal::one()).unwrap();
+        let fee = get_performance_fee(&vault, Decimal::one(), &Config::default()).unwrap();
         assert_eq!(fee.denom, vault.target_denom);
     }
 
@@ -413,11 +436,101 @@ mod tests {
             ..Default::default()
         };
 
-        let fee_rate =
-            get_swap_fee_rate(deps.as_ref().storage, &vault.swap_adjustment_strategy).unwrap();
+        let fee_rate = get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap();
 
         let config = get_config(deps.as_ref().storage).unwrap();
 
         assert_eq!(config.weighted_scale_swap_fee_percent, fee_rate);
     }
+
+    #[test]
+    fn value_averaging_fee_level_is_used() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let mut config = get_config(deps.as_ref().storage).unwrap();
+        config.value_averaging_swap_fee_percent = Some(Decimal::percent(2));
+        update_config(deps.as_mut().storage, config).unwrap();
+
+        let vault = Vault {
+            swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                target_growth_per_execution: ONE,
+                target_value: Uint128::zero(),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap(),
+            Decimal::percent(2)
+        );
+    }
+
+    #[test]
+    fn value_averaging_fee_level_defaults_to_default_swap_fee() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault {
+            swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                target_growth_per_execution: ONE,
+                target_value: Uint128::zero(),
+            }),
+            ..Default::default()
+        };
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        assert_eq!(
+            get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap(),
+            config.default_swap_fee_percent
+        );
+    }
+
+    #[test]
+    fn custom_pair_fee_overrides_default_fee() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault::default();
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            get_custom_swap_fee_key(vault.get_swap_denom(), Some(vault.target_denom.clone())),
+            Decimal::zero(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap(),
+            Decimal::zero()
+        );
+    }
+
+    #[test]
+    fn custom_denom_fee_does_not_apply_to_risk_weighted_average_vaults() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault {
+            swap_adjustment_strategy: Some(SwapAdjustmentStrategy::default()),
+            ..Vault::default()
+        };
+
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            vault.target_denom.clone(),
+            Decimal::percent(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_swap_fee_rate(deps.as_ref().storage, &vault).unwrap(),
+            Decimal::zero()
+        );
+    }
 }
@@ -0,0 +1,186 @@
+use super::reply::{get_vault_id_from_reply_id, get_vault_reply_id};
+use crate::{
+    constants::{AFTER_FAILED_HOOK_REPLY_ID, DEFAULT_HOOK_GAS_LIMIT},
+    error::ContractError,
+    state::config::get_config,
+    types::{
+        hook::{HookExecuteMsg, VaultHookNotification},
+        vault::Vault,
+    },
+};
+use cosmwasm_std::{
+    to_json_binary, Reply, Response, StdResult, Storage, SubMsg, SubMsgResult, WasmMsg,
+};
+
+pub fn get_hook_message(
+    storage: &dyn Storage,
+    vault: &Vault,
+    notification: VaultHookNotification,
+) -> StdResult<Option<SubMsg>> {
+    vault
+        .hook
+        .clone()
+        .map(|hook| {
+            Ok(SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: hook.to_string(),
+                    msg: to_json_binary(&HookExecuteMsg::DcaVaultHook {
+                        vault_id: vault.id,
+                        owner: vault.owner.clone(),
+                        notification,
+                    })?,
+                    funds: vec![],
+                },
+                get_vault_reply_id(AFTER_FAILED_HOOK_REPLY_ID, vault.id),
+            )
+            .with_gas_limit(
+                get_config(storage)?
+                    .hook_gas_limit
+                    .unwrap_or(DEFAULT_HOOK_GAS_LIMIT),
+            ))
+        })
+        .transpose()
+}
+
+pub fn log_failed_hook_notification(reply: Reply) -> Result<Response, ContractError> {
+    let error = match reply.result {
+        SubMsgResult::Ok(_) => String::new(),
+        SubMsgResult::Err(err) => err,
+    };
+
+    Ok(Response::new()
+        .add_attribute("hook_notification_failed", "true")
+        .add_attribute("vault_id", get_vault_id_from_reply_id(reply.id).to_string())
+        .add_attribute("error", error))
+}
+
+#[cfg(test)]
+mod get_hook_message_tests {
+    use super::*;
+    use crate::{
+        state::config::update_config,
+        tests::{helpers::instantiate_contract, mocks::ADMIN},
+        types::{config::Config, event::ExecutionSkippedReason},
+    };
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Addr, Uint128,
+    };
+
+    #[test]
+    fn without_hook_returns_none() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault::default();
+
+        assert_eq!(
+            get_hook_message(
+                deps.as_ref().storage,
+                &vault,
+                VaultHookNotification::ExecutionSkipped {
+                    reason: ExecutionSkippedReason::SlippageQueryError,
+                },
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn with_hook_returns_gas_limited_reply_on_error_message() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let vault = Vault {
+            id: Uint128::new(3),
+            hook: Some(Addr::unchecked("hook")),
+            ..Vault::default()
+        };
+
+        let notification = VaultHookNotification::ExecutionSkipped {
+            reason: ExecutionSkippedReason::SlippageQueryError,
+        };
+
+        assert_eq!(
+            get_hook_message(deps.as_ref().storage, &vault, notification.clone()).unwrap(),
+            Some(
+                SubMsg::reply_on_error(
+                    WasmMsg::Execute {
+                        contract_addr: "hook".to_string(),
+                        msg: to_json_binary(&HookExecuteMsg::DcaVaultHook {
+                            vault_id: vault.id,
+                            owner: vault.owner.clone(),
+                            notification,
+                        })
+                        .unwrap(),
+                        funds: vec![],
+                    },
+                    get_vault_reply_id(AFTER_FAILED_HOOK_REPLY_ID, vault.id),
+                )
+                .with_gas_limit(DEFAULT_HOOK_GAS_LIMIT)
+            )
+        );
+    }
+
+    #[test]
+    fn with_configured_hook_gas_limit_uses_configured_gas_limit() {
+        let mut deps = mock_dependencies();
+        instantiate_contract(deps.as_mut(), mock_env(), mock_info(ADMIN, &[]));
+
+        let config = get_config(deps.as_ref().storage).unwrap();
+
+        update_config(
+            deps.as_mut().storage,
+            Config {
+                hook_gas_limit: Some(100_000),
+                ..config
+            },
+        )
+        .unwrap();
+
+        let vault = Vault {
+            hook: Some(Addr::unchecked("hook")),
+            ..Vault::default()
+        };
+
+        let message = get_hook_message(
+            deps.as_ref().storage,
+            &vault,
+            VaultHookNotification::ExecutionSkipped {
+                reason: ExecutionSkippedReason::SlippageQueryError,
+            },
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(message.gas_limit, Some(100_000));
+    }
+}
+
+#[cfg(test)]
+mod log_failed_hook_notification_tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn with_hook_out_of_gas_logs_failure() {
+        let vault_id = Uint128::new(3);
+
+        let response = log_failed_hook_notification(Reply {
+            id: get_vault_reply_id(AFTER_FAILED_HOOK_REPLY_ID, vault_id),
+            result: SubMsgResult::Err("out of gas in location: wasm contract".to_string()),
+        })
+        .unwrap();
+
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            response.attributes,
+            vec![
+                ("hook_notification_failed", "true"),
+                ("vault_id", "3"),
+                ("error", "out of gas in location: wasm contract"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{constants::DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS, types::destination::IbcTransfer};
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmwasm_std::{from_json, Addr, Binary, Coin, CosmosMsg, Env, StdError, StdResult};
+use prost::Message;
+use serde::de::IgnoredAny;
+use std::collections::BTreeMap;
+
+const MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Height {
+    #[prost(uint64, tag = "1")]
+    pub revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub revision_height: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<Height>,
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransferResponse {
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+}
+
+pub fn parse_ibc_transfer_memo(memo: &str) -> StdResult<BTreeMap<String, IgnoredAny>> {
+    from_json(memo.trim())
+        .map_err(|_| StdError::generic_err("ibc transfer memo must be a json object"))
+}
+
+pub fn get_ibc_transfer_memo(memo: Option<String>, callback_address: &Addr) -> String {
+    let callback = format!("\"ibc_callback\":\"{}\"", callback_address);
+
+    match memo.as_deref().map(str::trim) {
+        Some(memo) if parse_ibc_transfer_memo(memo).is_ok_and(|fields| !fields.is_empty()) => {
+            format!("{{{},{}", callback, &memo[1..])
+        }
+        _ => format!("{{{}}}", callback),
+    }
+}
+
+fn get_ibc_transfer_timeout(env: &Env, timeout_seconds: Option<u64>) -> u64 {
+    env.block
+        .time
+        .plus_seconds(timeout_seconds.unwrap_or(DEFAULT_IBC_TRANSFER_TIMEOUT_SECONDS))
+        .nanos()
+}
+
+pub fn get_ibc_transfer_message(
+    env: &Env,
+    receiver: String,
+    ibc_transfer: &IbcTransfer,
+    coin: Coin,
+) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: MSG_TRANSFER_TYPE_URL.to_string(),
+        value: Binary::from(
+            MsgTransfer {
+                source_port: "transfer".to_string(),
+                source_channel: ibc_transfer.channel_id.clone(),
+                token: Some(ProtoCoin {
+                    denom: coin.denom,
+                    amount: coin.amount.to_string(),
+                }),
+                sender: env.contract.address.to_string(),
+                receiver,
+                timeout_height: None,
+                timeout_timestamp: get_ibc_transfer_timeout(env, ibc_transfer.timeout_seconds),
+                memo: get_ibc_transfer_memo(ibc_transfer.memo.clone(), &env.contract.address),
+            }
+            .encode_to_vec(),
+        ),
+    }
+}
+
+pub fn get_ibc_transfer_channel(msg: &CosmosMsg) -> Option<String> {
+    match msg {
+        CosmosMsg::Stargate { type_url, value } if type_url == MSG_TRANSFER_TYPE_URL => {
+            MsgTransfer::decode(value.as_slice())
+                .ok()
+                .map(|transfer| transfer.source_channel)
+        }
+        _ => None,
+    }
+}
+
+pub fn refresh_ibc_transfer_timeout(
+    env: &Env,
+    msg: CosmosMsg,
+    timeout_seconds: Option<u64>,
+) -> CosmosMsg {
+    let transfer = match &msg {
+        CosmosMsg::Stargate { type_url, value } if type_url == MSG_TRANSFER_TYPE_URL => {
+            MsgTransfer::decode(value.as_slice()).ok()
+        }
+        _ => None,
+    };
+
+    match transfer {
+        Some(transfer) => CosmosMsg::Stargate {
+            type_url: MSG_TRANSFER_TYPE_URL.to_string(),
+            value: Binary::from(
+                MsgTransfer {
+                    timeout_timestamp: get_ibc_transfer_timeout(env, timeout_seconds),
+                    ..transfer
+                }
+                .encode_to_vec(),
+            ),
+        },
+        None => msg,
+    }
+}
+
+pub fn get_ibc_transfer_sequence(data: Option<Binary>) -> StdResult<u64> {
+    let data = data.ok_or_else(|| StdError::generic_err("no ibc transfer response data"))?;
+
+    MsgTransferResponse::decode(data.as_slice())
+        .map(|response| response.sequence)
+        .map_err(|e| StdError::parse_err("MsgTransferResponse", e))
+}
+
+#[cfg(test)]
+mod ibc_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_env;
+
+    #[test]
+    fn memo_without_user_memo_only_contains_callback() {
+        assert_eq!(
+            get_ibc_transfer_memo(None, &Addr::unchecked("contract")),
+            "{\"ibc_callback\":\"contract\"}"
+        );
+    }
+
+    #[test]
+    fn memo_merges_callback_into_user_memo() {
+        assert_eq!(
+            get_ibc_transfer_memo(
+                Some(" {\"forward\":{\"port\":\"transfer\"}} ".to_string()),
+                &Addr::unchecked("contract")
+            ),
+            "{\"ibc_callback\":\"contract\",\"forward\":{\"port\":\"transfer\"}}"
+        );
+    }
+
+    #[test]
+    fn memo_with_empty_user_memo_only_contains_callback() {
+        assert_eq!(
+            get_ibc_transfer_memo(Some("{ }".to_string()), &Addr::unchecked("contract")),
+            "{\"ibc_callback\":\"contract\"}"
+        );
+    }
+
+    #[test]
+    fn parse_memo_with_non_object_fails() {
+        assert!(parse_ibc_transfer_memo("{\"forward\"}").is_err());
+        assert!(parse_ibc_transfer_memo("[]").is_err());
+        assert!(parse_ibc_transfer_memo("{\"forward\":{}} {").is_err());
+    }
+
+    #[test]
+    fn refresh_timeout_updates_transfer_timeout_only() {
+        let mut env = mock_env();
+
+        let msg = get_ibc_transfer_message(
+            &env,
+            "osmo1receiver".to_string(),
+            &IbcTransfer {
+                channel_id: "channel-3".to_string(),
+                memo: Some("{\"forward\":{}}".to_string()),
+                timeout_seconds: Some(60),
+            },
+            Coin::new(100, "uosmo"),
+        );
+
+        env.block.time = env.block.time.plus_seconds(600);
+
+        let refreshed = refresh_ibc_transfer_timeout(&env, msg.clone(), Some(60));
+
+        match (msg, refreshed) {
+            (
+                CosmosMsg::Stargate {
+                    value: original, ..
+                },
+                CosmosMsg::Stargate { value, .. },
+            ) => {
+                let original = MsgTransfer::decode(original.as_slice()).unwrap();
+                let transfer = MsgTransfer::decode(value.as_slice()).unwrap();
+                assert_eq!(
+                    transfer.timeout_timestamp,
+                    env.block.time.plus_seconds(60).nanos()
+                );
+                assert_eq!(
+                    transfer,
+                    MsgTransfer {
+                        timeout_timestamp: transfer.timeout_timestamp,
+                        ..original
+                    }
+                );
+            }
+            _ => panic!("expected stargate messages"),
+        }
+    }
+
+    #[test]
+    fn refresh_timeout_leaves_other_messages_unchanged() {
+        let msg = CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+            to_address: "receiver".to_string(),
+            amount: vec![Coin::new(100, "uosmo")],
+        });
+
+        assert_eq!(
+            refresh_ibc_transfer_timeout(&mock_env(), msg.clone(), None),
+            msg
+        );
+    }
+
+    #[test]
+    fn transfer_message_round_trips_channel() {
+        let msg = get_ibc_transfer_message(
+            &mock_env(),
+            "osmo1receiver".to_string(),
+            &IbcTransfer {
+                channel_id: "channel-3".to_string(),
+                memo: None,
+                timeout_seconds: None,
+            },
+            Coin::new(100, "uosmo"),
+        );
+
+        assert_eq!(
+            get_ibc_transfer_channel(&msg),
+            Some("channel-3".to_string())
+        );
+    }
+
+    #[test]
+    fn transfer_message_sets_timeout_from_block_time() {
+        let env = mock_env();
+
+        let msg = get_ibc_transfer_message(
+            &env,
+            "osmo1receiver".to_string(),
+            &IbcTransfer {
+                channel_id: "channel-3".to_string(),
+                memo: None,
+                timeout_seconds: Some(60),
+            },
+            Coin::new(100, "uosmo"),
+        );
+
+        match msg {
+            CosmosMsg::Stargate { value, .. } => {
+                let transfer = MsgTransfer::decode(value.as_slice()).unwrap();
+                assert_eq!(
+                    transfer.timeout_timestamp,
+                    env.block.time.plus_seconds(60).nanos()
+                );
+                assert_eq!(transfer.receiver, "osmo1receiver");
+                assert_eq!(transfer.sender, env.contract.address.to_string());
+            }
+            _ => panic!("expected stargate message"),
+        }
+    }
+
+    #[test]
+    fn decodes_transfer_sequence() {
+        let data = Binary::from(MsgTransferResponse { sequence: 42 }.encode_to_vec());
+
+        assert_eq!(get_ibc_transfer_sequence(Some(data)).unwrap(), 42);
+    }
+}
@@ -1,9 +1,15 @@
 pub mod authz;
+pub mod coin;
+pub mod cron;
 pub mod disbursement;
 pub mod fees;
+pub mod hooks;
+pub mod ibc;
 pub mod math;
 pub mod message;
 pub mod price;
+pub mod reply;
+pub mod staking;
 pub mod state;
 pub mod time;
 pub mod validation;
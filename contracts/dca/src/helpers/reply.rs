@@ -0,0 +1,43 @@
+use cosmwasm_std::Uint128;
+
+const REPLY_TYPE_BITS: u32 = 8;
+const REPLY_TYPE_MASK: u64 = (1 << REPLY_TYPE_BITS) - 1;
+
+pub fn get_vault_reply_id(reply_type: u64, vault_id: Uint128) -> u64 {
+    let vault_id: u64 = vault_id
+        .u128()
+        .try_into()
+        .expect("vault id should fit in a reply id");
+
+    (vault_id << REPLY_TYPE_BITS) | reply_type
+}
+
+pub fn get_reply_type(reply_id: u64) -> u64 {
+    reply_id & REPLY_TYPE_MASK
+}
+
+pub fn get_vault_id_from_reply_id(reply_id: u64) -> Uint128 {
+    Uint128::from(reply_id >> REPLY_TYPE_BITS)
+}
+
+#[cfg(test)]
+mod reply_id_tests {
+    use super::*;
+    use crate::constants::AFTER_SWAP_REPLY_ID;
+
+    #[test]
+    fn reply_id_for_first_vault_is_reply_type() {
+        assert_eq!(
+            get_vault_reply_id(AFTER_SWAP_REPLY_ID, Uint128::zero()),
+            AFTER_SWAP_REPLY_ID
+        );
+    }
+
+    #[test]
+    fn reply_id_round_trips_reply_type_and_vault_id() {
+        let reply_id = get_vault_reply_id(AFTER_SWAP_REPLY_ID, Uint128::new(83473));
+
+        assert_eq!(get_reply_type(reply_id), AFTER_SWAP_REPLY_ID);
+        assert_eq!(get_vault_id_from_reply_id(reply_id), Uint128::new(83473));
+    }
+}
@@ -0,0 +1,119 @@
+use super::authz::create_authz_exec_messages;
+use cosmos_sdk_proto::cosmos::{
+    authz::v1beta1::MsgExecResponse,
+    distribution::v1beta1::{MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse},
+};
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Env, StdError, StdResult, Uint128};
+use prost::Message;
+
+pub fn get_withdraw_delegator_rewards_message(
+    env: &Env,
+    delegator: &Addr,
+    validators: Vec<String>,
+) -> CosmosMsg {
+    create_authz_exec_messages(
+        env.contract.address.clone(),
+        String::from("/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"),
+        validators
+            .into_iter()
+            .map(|validator| MsgWithdrawDelegatorReward {
+                delegator_address: delegator.to_string(),
+                validator_address: validator,
+            })
+            .collect(),
+    )
+}
+
+pub fn get_claimed_staking_rewards(data: Option<Binary>, denom: &str) -> StdResult<Uint128> {
+    let data = data.unwrap_or_default();
+
+    let exec_response = MsgExecResponse::decode(data.as_slice())
+        .map_err(|_| StdError::generic_err("failed to decode authz exec response"))?;
+
+    exec_response
+        .results
+        .iter()
+        .map(|result| {
+            MsgWithdrawDelegatorRewardResponse::decode(result.as_slice())
+                .map_err(|_| StdError::generic_err("failed to decode withdraw rewards response"))
+        })
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .flat_map(|response| response.amount)
+        .filter(|coin| coin.denom == denom)
+        .try_fold(Uint128::zero(), |total, coin| {
+            Ok(total
+                + coin
+                    .amount
+                    .parse::<Uint128>()
+                    .map_err(|_| StdError::generic_err("invalid staking reward amount"))?)
+        })
+}
+
+#[cfg(test)]
+mod get_claimed_staking_rewards_tests {
+    use super::*;
+    use crate::tests::mocks::{DENOM_UKUJI, DENOM_UUSK};
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+    fn exec_response(rewards: Vec<Vec<(&str, u128)>>) -> Binary {
+        Binary::from(
+            MsgExecResponse {
+                results: rewards
+                    .into_iter()
+                    .map(|coins| {
+                        MsgWithdrawDelegatorRewardResponse {
+                            amount: coins
+                                .into_iter()
+                                .map(|(denom, amount)| ProtoCoin {
+                                    denom: denom.to_string(),
+                                    amount: amount.to_string(),
+                                })
+                                .collect(),
+                        }
+                        .encode_to_vec()
+                    })
+                    .collect(),
+            }
+            .encode_to_vec(),
+        )
+    }
+
+    #[test]
+    fn sums_rewards_across_validators() {
+        assert_eq!(
+            get_claimed_staking_rewards(
+                Some(exec_response(vec![
+                    vec![(DENOM_UKUJI, 120000)],
+                    vec![(DENOM_UKUJI, 30000)]
+                ])),
+                DENOM_UKUJI
+            )
+            .unwrap(),
+            Uint128::new(150000)
+        );
+    }
+
+    #[test]
+    fn ignores_rewards_in_other_denoms() {
+        assert_eq!(
+            get_claimed_staking_rewards(
+                Some(exec_response(vec![vec![
+                    (DENOM_UKUJI, 120000),
+                    (DENOM_UUSK, 30000)
+                ]])),
+                DENOM_UKUJI
+            )
+            .unwrap(),
+            Uint128::new(120000)
+        );
+    }
+
+    #[test]
+    fn with_no_response_data_returns_zero() {
+        assert_eq!(
+            get_claimed_staking_rewards(None, DENOM_UKUJI).unwrap(),
+            Uint128::zero()
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use crate::helpers::cron::CronSchedule;
 use crate::types::time_interval::TimeInterval;
 use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
 use cosmwasm_std::Timestamp;
-use std::convert::TryInto;
+use std::{cmp::min, convert::TryInto};
+
+const MAX_SIMULATED_EXECUTIONS: u128 = 1000;
+const MAX_EXECUTION_DURATION_SECONDS: u128 = 100 * 365 * 24 * 60 * 60;
 
 pub fn target_time_elapsed(current_time: Timestamp, target_execution_time: Timestamp) -> bool {
     current_time.seconds().ge(&target_execution_time.seconds())
@@ -23,6 +27,11 @@ pub fn get_next_target_time(
     let mut next_execution_time = get_next_time(started_at_time, &interval);
 
     match interval {
+        TimeInterval::Cron { .. } => {
+            if next_execution_time.le(&current_time) {
+                next_execution_time = get_next_time(current_time, &interval);
+            }
+        }
         TimeInterval::Monthly => {
             while next_execution_time.le(&current_time) {
                 next_execution_time = get_next_time(next_execution_time, &interval);
@@ -50,15 +59,39 @@ pub fn get_total_execution_duration(
     iterations: u128,
     interval: &TimeInterval,
 ) -> Duration {
-    let mut start_time = Utc
+    let start_time = Utc
         .timestamp_opt(block_time.seconds().try_into().unwrap(), 0)
         .unwrap();
 
-    (0..iterations).fold(Duration::zero(), |acc, _| {
-        let duration = get_duration(start_time, interval);
-        start_time += duration;
-        acc + duration
-    })
+    let cron_schedule = match interval {
+        TimeInterval::Cron { expression } => {
+            Some(CronSchedule::parse(expression).expect("should be a valid cron expression"))
+        }
+        _ => None,
+    };
+
+    let simulated_iterations = min(iterations, MAX_SIMULATED_EXECUTIONS);
+
+    let end_time = (0..simulated_iterations).fold(start_time, |time, _| match &cron_schedule {
+        Some(schedule) => schedule
+            .next_after(time)
+            .expect("should be a matching cron time"),
+        None => get_next_time(time, interval),
+    });
+
+    let simulated_duration = end_time - start_time;
+
+    if iterations == simulated_iterations {
+        return simulated_duration;
+    }
+
+    // Longer schedules are extrapolated from the simulated executions
+    let extrapolated_seconds = (simulated_duration.num_seconds() as u128)
+        .checked_mul(iterations)
+        .map(|seconds| seconds / simulated_iterations)
+        .unwrap_or(u128::MAX);
+
+    Duration::seconds(min(extrapolated_seconds, MAX_EXECUTION_DURATION_SECONDS) as i64)
 }
 
 fn get_duration(previous: DateTime<Utc>, interval: &TimeInterval) -> Duration {
@@ -73,6 +106,13 @@ fn get_duration(previous: DateTime<Utc>, interval: &TimeInterval) -> Duration {
         TimeInterval::Fortnightly => Duration::days(14),
         TimeInterval::Monthly => shift_months(previous, 1) - previous,
         TimeInterval::Custom { seconds } => Duration::seconds(*seconds as i64),
+        TimeInterval::Cron { expression } => {
+            CronSchedule::parse(expression)
+                .expect("should be a valid cron expression")
+                .next_after(previous)
+                .expect("should be a matching cron time")
+                - previous
+        }
     }
 }
 
@@ -202,6 +242,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn assert_cron_next_execution_times() {
+        let last_execution_time = Utc.with_ymd_and_hms(2022, 10, 3, 14, 0, 0).unwrap();
+        let scenarios = vec![
+            (
+                last_execution_time,
+                Utc.with_ymd_and_hms(2022, 10, 4, 14, 0, 0).unwrap(),
+            ),
+            (
+                last_execution_time + Duration::seconds(1),
+                Utc.with_ymd_and_hms(2022, 10, 4, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2022, 10, 7, 13, 59, 59).unwrap(),
+                Utc.with_ymd_and_hms(2022, 10, 7, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2022, 10, 7, 14, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2022, 10, 10, 14, 0, 0).unwrap(),
+            ),
+            (
+                Utc.with_ymd_and_hms(2022, 10, 8, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2022, 10, 10, 14, 0, 0).unwrap(),
+            ),
+        ];
+
+        assert_expected_next_execution_times(
+            &TimeInterval::Cron {
+                expression: "0 14 * * 1-5".to_string(),
+            },
+            last_execution_time,
+            scenarios,
+        );
+    }
+
     #[test]
     fn assert_fortnightly_next_execution_times() {
         let last_execution_time = Utc.with_ymd_and_hms(2022, 1, 1, 1, 0, 0).unwrap();
@@ -713,5 +788,56 @@ mod get_total_execution_duration_tests {
             TimeInterval::Monthly,
             shift_months(block_time_utc, 3) - block_time_utc,
         );
+
+        let weekdays_at_1400 = TimeInterval::Cron {
+            expression: "0 14 * * 1-5".to_string(),
+        };
+
+        assert_total_execution_duration(
+            block_timestamp,
+            1,
+            weekdays_at_1400.clone(),
+            Duration::days(2) + Duration::hours(4),
+        );
+        assert_total_execution_duration(
+            block_timestamp,
+            6,
+            weekdays_at_1400,
+            Duration::days(9) + Duration::hours(4),
+        );
+    }
+
+    #[test]
+    fn extrapolates_total_execution_duration_beyond_simulated_executions() {
+        let block_timestamp = Timestamp::from_seconds(
+            Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0)
+                .unwrap()
+                .timestamp() as u64,
+        );
+
+        assert_total_execution_duration(
+            block_timestamp,
+            10_000,
+            TimeInterval::Daily,
+            Duration::days(10_000),
+        );
+        assert_total_execution_duration(
+            block_timestamp,
+            4_000,
+            TimeInterval::Cron {
+                expression: "0 0 * * *".to_string(),
+            },
+            Duration::days(4_000),
+        );
+    }
+
+    #[test]
+    fn caps_total_execution_duration() {
+        assert_total_execution_duration(
+            Timestamp::from_seconds(0),
+            u128::MAX,
+            TimeInterval::Hourly,
+            Duration::days(100 * 365),
+        );
     }
 }
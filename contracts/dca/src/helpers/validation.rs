@@ -1,17 +1,28 @@
+use crate::constants::PRICE_HISTORY_CAPACITY;
 use crate::error::ContractError;
+use crate::helpers::cron::CronSchedule;
+use crate::helpers::ibc::parse_ibc_transfer_memo;
 use crate::msg::ExecuteMsg;
+use crate::state::base_denoms::BaseDenomConfig;
 use crate::state::config::get_config;
+use crate::state::cw20_denoms::is_cw20_denom;
+use crate::state::vaults::get_vault;
 use crate::types::destination::Destination;
+use crate::types::exit_condition::ExitCondition;
 use crate::types::fee_collector::FeeCollector;
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::position_type::PositionType;
+use crate::types::price_band::PriceBand;
 use crate::types::swap_adjustment_strategy::{
-    SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+    BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
 use crate::types::vault::{Vault, VaultStatus};
+use chrono::{TimeZone, Utc};
 use cosmwasm_std::{
     from_json, Addr, Binary, Coin, Decimal, Deps, Env, Storage, Timestamp, Uint128,
 };
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
 use exchange::msg::QueryMsg;
 
 pub fn assert_exactly_one_asset(funds: Vec<Coin>) -> Result<(), ContractError> {
@@ -59,6 +70,36 @@ pub fn assert_sender_is_executor(
     Ok(())
 }
 
+pub fn assert_sender_is_swap_adjustment_submitter(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    let config = get_config(storage)?;
+    if config.swap_adjustment_submitters.is_empty() {
+        return assert_sender_is_executor(storage, env, sender);
+    }
+    if !config.swap_adjustment_submitters.contains(sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+pub fn assert_swap_adjustment_quorum_is_valid(
+    quorum: u64,
+    submitters: &[Addr],
+) -> Result<(), ContractError> {
+    if !submitters.is_empty() && quorum > submitters.len() as u64 {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "swap adjustment quorum cannot be greater than the number of submitters ({})",
+                submitters.len()
+            ),
+        });
+    }
+    Ok(())
+}
+
 pub fn asset_sender_is_vault_owner(vault_owner: Addr, sender: Addr) -> Result<(), ContractError> {
     if sender != vault_owner {
         return Err(ContractError::Unauthorized {});
@@ -99,6 +140,24 @@ pub fn assert_vault_is_not_cancelled(vault: &Vault) -> Result<(), ContractError>
     Ok(())
 }
 
+pub fn assert_vault_can_be_paused(vault: &Vault) -> Result<(), ContractError> {
+    if !vault.is_active() && !vault.is_scheduled() {
+        return Err(ContractError::CustomError {
+            val: format!("vault with status {:?} cannot be paused", vault.status),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_vault_is_paused(vault: &Vault) -> Result<(), ContractError> {
+    if !vault.is_paused() {
+        return Err(ContractError::CustomError {
+            val: "vault is not paused".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_swap_amount_is_greater_than_50000(swap_amount: Uint128) -> Result<(), ContractError> {
     if swap_amount <= Uint128::from(50000u128) {
         return Err(ContractError::CustomError {
@@ -173,6 +232,49 @@ pub fn assert_fee_level_is_valid(swap_fee_percent: &Decimal) -> Result<(), Contr
     Ok(())
 }
 
+pub fn assert_performance_fee_percent_is_no_greater_than_100_percent(
+    performance_fee_percent: Decimal,
+) -> Result<(), ContractError> {
+    if performance_fee_percent > Decimal::percent(100) {
+        return Err(ContractError::CustomError {
+            val: "performance_fee_percent cannot be greater than 100%".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_base_denom_config_is_valid(
+    base_denom_config: &BaseDenomConfig,
+) -> Result<(), ContractError> {
+    if let BaseDenom::Custom(name) = &base_denom_config.base_denom {
+        if name.is_empty() {
+            return Err(ContractError::CustomError {
+                val: "custom base denom name cannot be empty".to_string(),
+            });
+        }
+    }
+
+    if base_denom_config.model_id_buckets.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "base denom must have at least one model id bucket".to_string(),
+        });
+    }
+
+    if base_denom_config
+        .model_id_buckets
+        .windows(2)
+        .any(|buckets| buckets[0].max_days >= buckets[1].max_days)
+    {
+        return Err(ContractError::CustomError {
+            val: "model id bucket max days must be strictly increasing".to_string(),
+        });
+    }
+
+    assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
+        base_denom_config.escrow_level,
+    )
+}
+
 pub fn assert_risk_weighted_average_escrow_level_is_no_greater_than_100_percent(
     risk_weighted_average_escrow_level: Decimal,
 ) -> Result<(), ContractError> {
@@ -204,6 +306,17 @@ pub fn assert_slippage_tolerance_is_less_than_or_equal_to_one(
     Ok(())
 }
 
+pub fn assert_referral_fee_share_is_no_greater_than_one(
+    referral_fee_share: Decimal,
+) -> Result<(), ContractError> {
+    if referral_fee_share > Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: "referral fee share must be less than or equal to 1".to_string(),
+        });
+    }
+    Ok(())
+}
+
 pub fn assert_no_more_than_10_fee_collectors(
     fee_collectors: &[FeeCollector],
 ) -> Result<(), ContractError> {
@@ -317,9 +430,171 @@ pub fn assert_destination_callback_addresses_are_valid(
     deps: Deps,
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
-    destinations.iter().for_each(|destination| {
-        assert_address_is_valid(deps, &destination.address, "destination").unwrap();
-    });
+    destinations
+        .iter()
+        .filter(|destination| destination.ibc_transfer.is_none())
+        .for_each(|destination| {
+            assert_address_is_valid(deps, &destination.address, "destination").unwrap();
+        });
+    Ok(())
+}
+
+pub fn assert_ibc_transfer_destinations_are_valid(
+    storage: &dyn Storage,
+    destinations: &[Destination],
+    target_denom: &str,
+) -> Result<(), ContractError> {
+    destinations.iter().try_for_each(|destination| {
+        let ibc_transfer = match &destination.ibc_transfer {
+            Some(ibc_transfer) => ibc_transfer,
+            None => return Ok(()),
+        };
+
+        if is_cw20_denom(storage, target_denom) {
+            return Err(ContractError::CustomError {
+                val: format!("cannot send cw20 denom {} over ibc", target_denom),
+            });
+        }
+
+        if destination.msg.is_some() {
+            return Err(ContractError::CustomError {
+                val: "ibc transfer destinations cannot include a callback msg".to_string(),
+            });
+        }
+
+        if destination.address.as_str().trim().is_empty() {
+            return Err(ContractError::CustomError {
+                val: "ibc transfer receiver cannot be empty".to_string(),
+            });
+        }
+
+        if !ibc_transfer.channel_id.starts_with("channel-") {
+            return Err(ContractError::CustomError {
+                val: format!("ibc channel id {} is invalid", ibc_transfer.channel_id),
+            });
+        }
+
+        if ibc_transfer.timeout_seconds == Some(0) {
+            return Err(ContractError::CustomError {
+                val: "ibc transfer timeout must be greater than 0 seconds".to_string(),
+            });
+        }
+
+        if let Some(memo) = &ibc_transfer.memo {
+            let fields = parse_ibc_transfer_memo(memo).map_err(|_| ContractError::CustomError {
+                val: "ibc transfer memo must be a json object".to_string(),
+            })?;
+
+            if fields.contains_key("ibc_callback") {
+                return Err(ContractError::CustomError {
+                    val: "ibc transfer memo cannot include an ibc_callback field".to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    })
+}
+
+pub fn assert_vault_destinations_are_valid(
+    storage: &dyn Storage,
+    destinations: &[Destination],
+    target_denom: &str,
+    source_vault_id: Option<Uint128>,
+) -> Result<(), ContractError> {
+    destinations.iter().try_for_each(|destination| {
+        let vault_id = match destination.vault_id {
+            Some(vault_id) => vault_id,
+            None => return Ok(()),
+        };
+
+        if destination.msg.is_some() || destination.ibc_transfer.is_some() {
+            return Err(ContractError::CustomError {
+                val: "vault destinations cannot include a callback msg or ibc transfer".to_string(),
+            });
+        }
+
+        if source_vault_id == Some(vault_id) {
+            return Err(ContractError::CustomError {
+                val: "a vault cannot be its own destination".to_string(),
+            });
+        }
+
+        let destination_vault = get_vault(storage, vault_id)?;
+
+        if destination.address != destination_vault.owner {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "provided an incorrect owner address for vault id {}",
+                    vault_id
+                ),
+            });
+        }
+
+        if destination_vault.is_cancelled() {
+            return Err(ContractError::CustomError {
+                val: format!("destination vault {} is cancelled", vault_id),
+            });
+        }
+
+        if destination_vault.balance.denom != target_denom {
+            return Err(ContractError::CustomError {
+                val: format!(
+                    "destination vault {} swap denom {} does not match target denom {}",
+                    vault_id, destination_vault.balance.denom, target_denom
+                ),
+            });
+        }
+
+        Ok(())
+    })
+}
+
+pub fn assert_allowance_funded_vault_is_valid(
+    funds: Vec<Coin>,
+    target_receive_amount: &Option<Uint128>,
+    performance_assessment_strategy_params: &Option<PerformanceAssessmentStrategyParams>,
+) -> Result<(), ContractError> {
+    if !funds.is_empty() {
+        return Err(ContractError::CustomError {
+            val: "cannot send funds when creating an allowance funded vault".to_string(),
+        });
+    }
+
+    if target_receive_amount.is_some() {
+        return Err(ContractError::CustomError {
+            val: "cannot provide a target_receive_amount for an allowance funded vault".to_string(),
+        });
+    }
+
+    if performance_assessment_strategy_params.is_some() {
+        return Err(ContractError::CustomError {
+            val: "allowance funded vaults do not support performance assessment strategies"
+                .to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn assert_allowance_swap_denom_is_valid(
+    deps: Deps,
+    swap_denom: &str,
+) -> Result<(), ContractError> {
+    if is_cw20_denom(deps.storage, swap_denom) || deps.api.addr_validate(swap_denom).is_err() {
+        return Ok(());
+    }
+
+    if deps
+        .querier
+        .query_wasm_smart::<TokenInfoResponse>(swap_denom, &Cw20QueryMsg::TokenInfo {})
+        .is_ok()
+    {
+        return Err(ContractError::CustomError {
+            val: format!("cw20 token {} is not registered", swap_denom),
+        });
+    }
+
     Ok(())
 }
 
@@ -370,17 +645,19 @@ pub fn assert_swap_adjustment_and_performance_assessment_strategies_are_compatib
                 }),
             }
         }
-        Some(SwapAdjustmentStrategyParams::WeightedScale { .. }) => {
-            match performance_assessment_strategy_params {
-                Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
-                    Err(ContractError::CustomError {
-                        val: "incompatible swap adjustment and performance assessment strategies"
-                            .to_string(),
-                    })
-                }
-                None => Ok(()),
+        Some(
+            SwapAdjustmentStrategyParams::WeightedScale { .. }
+            | SwapAdjustmentStrategyParams::ValueAveraging { .. }
+            | SwapAdjustmentStrategyParams::MovingAverage { .. },
+        ) => match performance_assessment_strategy_params {
+            Some(PerformanceAssessmentStrategyParams::CompareToStandardDca) => {
+                Err(ContractError::CustomError {
+                    val: "incompatible swap adjustment and performance assessment strategies"
+                        .to_string(),
+                })
             }
-        }
+            None => Ok(()),
+        },
         None => match performance_assessment_strategy_params {
             Some(_) => Err(ContractError::CustomError {
                 val: "incompatible swap adjustment and performance assessment strategies"
@@ -394,12 +671,44 @@ pub fn assert_swap_adjustment_and_performance_assessment_strategies_are_compatib
 pub fn assert_swap_adjustment_strategy_params_are_valid(
     strategy: &SwapAdjustmentStrategyParams,
 ) -> Result<(), ContractError> {
-    if let SwapAdjustmentStrategyParams::WeightedScale { multiplier, .. } = strategy {
-        if multiplier > &Decimal::percent(1000) {
-            return Err(ContractError::CustomError {
-                val: "Cannot set weighted scale multiplier to more than 10".to_string(),
-            });
+    match strategy {
+        SwapAdjustmentStrategyParams::WeightedScale { multiplier, .. } => {
+            if multiplier > &Decimal::percent(1000) {
+                return Err(ContractError::CustomError {
+                    val: "Cannot set weighted scale multiplier to more than 10".to_string(),
+                });
+            }
+        }
+        SwapAdjustmentStrategyParams::ValueAveraging {
+            target_growth_per_execution,
+        } => {
+            if target_growth_per_execution.is_zero() {
+                return Err(ContractError::CustomError {
+                    val: "Value averaging target growth per execution must be greater than 0"
+                        .to_string(),
+                });
+            }
         }
+        SwapAdjustmentStrategyParams::MovingAverage {
+            periods,
+            multiplier,
+        } => {
+            if *periods < 2 || *periods > PRICE_HISTORY_CAPACITY {
+                return Err(ContractError::CustomError {
+                    val: format!(
+                        "Moving average periods must be between 2 and {}",
+                        PRICE_HISTORY_CAPACITY
+                    ),
+                });
+            }
+
+            if multiplier > &Decimal::percent(1000) {
+                return Err(ContractError::CustomError {
+                    val: "Cannot set moving average multiplier to more than 10".to_string(),
+                });
+            }
+        }
+        SwapAdjustmentStrategyParams::RiskWeightedAverage { .. } => {}
     }
     Ok(())
 }
@@ -416,17 +725,117 @@ pub fn assert_target_start_time_is_not_in_the_past(
     Ok(())
 }
 
-pub fn assert_time_interval_is_valid(interval: &TimeInterval) -> Result<(), ContractError> {
-    if let TimeInterval::Custom { seconds } = interval {
-        if *seconds < 60 {
+pub fn assert_end_time_is_after_start_time(
+    start_time: Timestamp,
+    end_time: Timestamp,
+) -> Result<(), ContractError> {
+    if start_time.seconds().ge(&end_time.seconds()) {
+        return Err(ContractError::CustomError {
+            val: String::from(
+                "end_time_utc_seconds must be after the vault start time and the current time",
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_max_executions_is_greater_than_execution_count(
+    max_executions: u64,
+    execution_count: u64,
+) -> Result<(), ContractError> {
+    if max_executions <= execution_count {
+        return Err(ContractError::CustomError {
+            val: format!(
+                "max_executions must be greater than the current execution count of {}",
+                execution_count
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_exit_conditions_are_valid(
+    stop_loss: &Option<ExitCondition>,
+    take_profit: &Option<ExitCondition>,
+    swap_adjustment_strategy: &Option<SwapAdjustmentStrategy>,
+) -> Result<(), ContractError> {
+    if [stop_loss, take_profit]
+        .into_iter()
+        .flatten()
+        .any(|exit_condition| exit_condition.price.is_zero())
+    {
+        return Err(ContractError::CustomError {
+            val: String::from("stop loss and take profit prices must be greater than 0"),
+        });
+    }
+
+    if let (Some(stop_loss), Some(take_profit)) = (stop_loss, take_profit) {
+        if stop_loss.price >= take_profit.price {
             return Err(ContractError::CustomError {
-                val: String::from("custom time interval must be at least 60 seconds"),
+                val: String::from("stop loss price must be less than take profit price"),
             });
         }
     }
+
+    if (stop_loss.is_some() || take_profit.is_some())
+        && !matches!(
+            swap_adjustment_strategy,
+            Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+                position_type: PositionType::Exit,
+                ..
+            })
+        )
+    {
+        return Err(ContractError::CustomError {
+            val: String::from("stop loss and take profit can only be set on exit position vaults"),
+        });
+    }
     Ok(())
 }
 
+pub fn assert_maximum_receive_amount_is_greater_than_minimum_receive_amount(
+    minimum_receive_amount: Option<Uint128>,
+    maximum_receive_amount: Option<Uint128>,
+) -> Result<(), ContractError> {
+    if maximum_receive_amount.is_some_and(|maximum_receive_amount| {
+        maximum_receive_amount <= minimum_receive_amount.unwrap_or_default()
+    }) {
+        return Err(ContractError::CustomError {
+            val: String::from("maximum receive amount must be greater than minimum receive amount"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_price_band_is_valid(price_band: &PriceBand) -> Result<(), ContractError> {
+    if price_band.lower_deviation >= Decimal::one() {
+        return Err(ContractError::CustomError {
+            val: String::from("price band lower deviation must be less than 1"),
+        });
+    }
+    Ok(())
+}
+
+pub fn assert_time_interval_is_valid(interval: &TimeInterval) -> Result<(), ContractError> {
+    match interval {
+        TimeInterval::Custom { seconds } if *seconds < 60 => Err(ContractError::CustomError {
+            val: String::from("custom time interval must be at least 60 seconds"),
+        }),
+        TimeInterval::Cron { expression } => {
+            if CronSchedule::parse(expression)?
+                .next_after(Utc.timestamp_opt(0, 0).unwrap())
+                .is_none()
+            {
+                return Err(ContractError::CustomError {
+                    val: format!("cron expression {} never matches a valid date", expression),
+                });
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn assert_destinations_limit_is_not_breached(
     destinations: &[Destination],
 ) -> Result<(), ContractError> {
@@ -1,27 +1,36 @@
 use super::{
+    coin::get_send_message,
     fees::{get_automation_fee_rate, get_swap_fee_rate},
     price::{get_price, get_slippage, get_twap_to_now},
-    time::get_total_execution_duration,
+    time::{get_next_target_time, get_total_execution_duration},
 };
 use crate::{
     state::{
-        config::get_config, events::create_event, swap_adjustments::get_swap_adjustment,
+        base_denoms::{get_base_denom_config, ModelIdBucket},
+        config::get_config,
+        disburse_escrow_tasks::save_disburse_escrow_task,
+        events::create_event,
+        price_history::get_moving_average_price,
+        swap_adjustments::get_swap_adjustment,
+        triggers::{delete_trigger, save_trigger},
         vaults::update_vault,
     },
     types::{
-        event::{EventBuilder, EventData, ExecutionSkippedReason},
+        event::{EventBuilder, EventData, ExecutionSkippedReason, VaultEndReason},
         performance_assessment_strategy::PerformanceAssessmentStrategy,
+        price_band::PriceBandEdge,
         swap_adjustment_strategy::SwapAdjustmentStrategy,
         time_interval::TimeInterval,
-        vault::Vault,
+        trigger::{Trigger, TriggerConfiguration},
+        vault::{Vault, VaultStatus},
     },
 };
 use cosmwasm_std::{
-    Coin, Decimal, Deps, Env, QuerierWrapper, Response, StdError, StdResult, Storage, Timestamp,
-    Uint128,
+    Coin, Decimal, Deps, Env, QuerierWrapper, Response, StdError, StdResult, Storage, SubMsg,
+    Timestamp, Uint128,
 };
-use shared::coin::add_to;
-use std::cmp::min;
+use shared::coin::{add, add_to, empty_of};
+use std::cmp::{max, min};
 
 pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin> {
     let swap_adjustment = match vault.swap_adjustment_strategy.clone() {
@@ -58,7 +67,84 @@ pub fn get_swap_amount(deps: &Deps, env: &Env, vault: &Vault) -> StdResult<Coin>
                     .unwrap_or_else(|_| Decimal::one())
             }
         }
-        Some(strategy) => get_swap_adjustment(deps.storage, strategy, env.block.time),
+        Some(SwapAdjustmentStrategy::ValueAveraging { target_value, .. }) => {
+            let config = get_config(deps.storage)?;
+
+            let belief_price = get_twap_to_now(
+                &deps.querier,
+                config.exchange_contract_address.clone(),
+                vault.get_swap_denom(),
+                vault.target_denom.clone(),
+                config.twap_period,
+                vault.route.clone(),
+            )?;
+
+            let current_value = vault.received_amount.amount * belief_price;
+
+            return Ok(Coin::new(
+                min(
+                    target_value.saturating_sub(current_value),
+                    vault.balance.amount,
+                )
+                .into(),
+                vault.get_swap_denom(),
+            ));
+        }
+        Some(
+            strategy @ SwapAdjustmentStrategy::MovingAverage {
+                periods,
+                multiplier,
+            },
+        ) => {
+            let moving_average_price = get_moving_average_price(
+                deps.storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                periods,
+            )?;
+
+            match moving_average_price {
+                Some(moving_average_price) => {
+                    let config = get_config(deps.storage)?;
+
+                    let belief_price = get_twap_to_now(
+                        &deps.querier,
+                        config.exchange_contract_address.clone(),
+                        vault.get_swap_denom(),
+                        vault.target_denom.clone(),
+                        config.twap_period,
+                        vault.route.clone(),
+                    )?;
+
+                    let scaled_price_delta = moving_average_price.abs_diff(belief_price)
+                        / moving_average_price
+                        * multiplier;
+
+                    if belief_price > moving_average_price {
+                        max(
+                            Decimal::one()
+                                .checked_sub(scaled_price_delta)
+                                .unwrap_or_else(|_| Decimal::zero()),
+                            strategy.min_adjustment(),
+                        )
+                    } else {
+                        min(
+                            Decimal::one()
+                                .checked_add(scaled_price_delta)
+                                .unwrap_or_else(|_| Decimal::one()),
+                            strategy.max_adjustment(),
+                        )
+                    }
+                }
+                None => Decimal::one(),
+            }
+        }
+        Some(strategy) => get_swap_adjustment(
+            deps.storage,
+            strategy,
+            &get_config(deps.storage)?,
+            env.block.time,
+        ),
         None => Decimal::one(),
     };
 
@@ -75,6 +161,7 @@ pub fn get_risk_weighted_average_model_id(
     balance: &Coin,
     swap_amount: &Uint128,
     time_interval: &TimeInterval,
+    model_id_buckets: &[ModelIdBucket],
 ) -> u8 {
     let execution_duration = get_total_execution_duration(
         *block_time,
@@ -86,18 +173,40 @@ pub fn get_risk_weighted_average_model_id(
         time_interval,
     );
 
-    match execution_duration.num_days() {
-        0..=32 => 30,
-        33..=38 => 35,
-        39..=44 => 40,
-        45..=51 => 45,
-        52..=57 => 50,
-        58..=65 => 55,
-        66..=77 => 60,
-        78..=96 => 70,
-        97..=123 => 80,
-        _ => 90,
-    }
+    let execution_days = max(execution_duration.num_days(), 0) as u64;
+
+    model_id_buckets
+        .iter()
+        .find(|bucket| execution_days <= bucket.max_days)
+        .or(model_id_buckets.last())
+        .expect("base denom should have at least one model id bucket")
+        .model_id
+}
+
+pub fn get_swap_adjustment_strategy_for_balance(
+    storage: &dyn Storage,
+    env: &Env,
+    vault: &Vault,
+    balance: &Coin,
+) -> StdResult<Option<SwapAdjustmentStrategy>> {
+    Ok(match vault.swap_adjustment_strategy.clone() {
+        Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+            base_denom,
+            position_type,
+            ..
+        }) => Some(SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id: get_risk_weighted_average_model_id(
+                &env.block.time,
+                balance,
+                &vault.swap_amount,
+                &vault.time_interval,
+                &get_base_denom_config(storage, &base_denom)?.model_id_buckets,
+            ),
+            base_denom,
+            position_type,
+        }),
+        swap_adjustment_strategy => swap_adjustment_strategy,
+    })
 }
 
 pub fn get_performance_factor(vault: &Vault, current_price: Decimal) -> StdResult<Decimal> {
@@ -181,6 +290,32 @@ pub fn simulate_standard_dca_execution(
                 return Ok((vault, response));
             }
 
+            let price_band_breach = if vault.maximum_receive_amount_exceeded(belief_price)? {
+                Some((belief_price, PriceBandEdge::Lower))
+            } else {
+                vault
+                    .price_band_breached(belief_price, actual_price)
+                    .map(|edge| (actual_price, edge))
+            };
+
+            if let Some((price, edge)) = price_band_breach {
+                create_event(
+                    storage,
+                    EventBuilder::new(
+                        vault.id,
+                        env.block.clone(),
+                        EventData::SimulatedDcaVaultExecutionSkipped {
+                            reason: ExecutionSkippedReason::PriceBandBreached { price, edge },
+                        },
+                    ),
+                )?;
+
+                response =
+                    response.add_attribute("simulated_execution_skipped", "price_band_breached");
+
+                return Ok((vault, response));
+            }
+
             let slippage = get_slippage(
                 querier,
                 config.exchange_contract_address,
@@ -208,8 +343,8 @@ pub fn simulate_standard_dca_execution(
                 return Ok((vault, response));
             }
 
-            let fee_rate = get_swap_fee_rate(storage, &vault.swap_adjustment_strategy)?
-                + get_automation_fee_rate(storage, &vault)?;
+            let fee_rate =
+                get_swap_fee_rate(storage, &vault)? + get_automation_fee_rate(storage, &vault)?;
 
             let received_amount_before_fee = swap_amount * (Decimal::one() / actual_price);
             let fee_amount = received_amount_before_fee * fee_rate;
@@ -258,21 +393,126 @@ pub fn simulate_standard_dca_execution(
     }
 }
 
+pub fn deposit_into_vault(
+    storage: &mut dyn Storage,
+    env: &Env,
+    vault: Vault,
+    amount: Coin,
+) -> StdResult<Vault> {
+    let vault_was_inactive = vault.is_inactive();
+    let new_balance = add(vault.balance.clone(), amount.clone())?;
+    let swap_adjustment_strategy =
+        get_swap_adjustment_strategy_for_balance(storage, env, &vault, &new_balance)?;
+
+    let vault = update_vault(
+        storage,
+        Vault {
+            balance: new_balance.clone(),
+            deposited_amount: add(vault.deposited_amount.clone(), amount.clone())?,
+            status: if vault.is_inactive() {
+                VaultStatus::Active
+            } else {
+                vault.status
+            },
+            swap_adjustment_strategy,
+            ..vault
+        },
+    )?;
+
+    create_event(
+        storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultFundsDeposited { amount },
+        ),
+    )?;
+
+    if vault.is_active() && vault_was_inactive && vault.trigger.is_none() {
+        save_trigger(
+            storage,
+            Trigger {
+                vault_id: vault.id,
+                configuration: TriggerConfiguration::Time {
+                    target_time: get_next_target_time(
+                        env.block.time,
+                        vault.started_at.unwrap_or(env.block.time),
+                        vault.time_interval.clone(),
+                    ),
+                },
+            },
+        )?;
+    };
+
+    Ok(vault)
+}
+
+pub fn end_vault(
+    storage: &mut dyn Storage,
+    env: &Env,
+    vault: Vault,
+    reason: VaultEndReason,
+) -> StdResult<(Vault, Vec<SubMsg>)> {
+    let mut sub_msgs = Vec::<SubMsg>::new();
+
+    if vault.balance.amount > Uint128::zero() {
+        sub_msgs.push(SubMsg::new(get_send_message(
+            storage,
+            vault.owner.to_string(),
+            vault.balance.clone(),
+        )));
+    }
+
+    if vault.escrowed_amount.amount > Uint128::zero() {
+        save_disburse_escrow_task(storage, vault.id, env.block.time)?;
+    }
+
+    create_event(
+        storage,
+        EventBuilder::new(
+            vault.id,
+            env.block.clone(),
+            EventData::DcaVaultEnded {
+                reason,
+                execution_count: vault.execution_count,
+                refunded: vault.balance.clone(),
+            },
+        ),
+    )?;
+
+    delete_trigger(storage, vault.id)?;
+
+    let vault = update_vault(
+        storage,
+        Vault {
+            status: VaultStatus::Inactive,
+            balance: empty_of(vault.balance.clone()),
+            ..vault
+        },
+    )?;
+
+    Ok((vault, sub_msgs))
+}
+
 #[cfg(test)]
 mod get_swap_amount_tests {
     use super::*;
     use crate::{
-        constants::{HALF_DECIMAL, ONE, ONE_AND_HALF_DECIMAL, TWO_DECIMAL, TWO_MICRONS},
-        state::swap_adjustments::update_swap_adjustment,
+        constants::{
+            HALF_DECIMAL, ONE, ONE_AND_HALF_DECIMAL, ONE_DECIMAL, ONE_HUNDRED,
+            PRICE_HISTORY_INTERVAL_SECONDS, TWO_DECIMAL, TWO_MICRONS,
+        },
+        state::{price_history::record_price, swap_adjustments::update_swap_adjustment},
         tests::{
             helpers::{instantiate_contract, setup_vault},
-            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI},
+            mocks::{calc_mock_dependencies, ADMIN, DENOM_UKUJI, DENOM_UUSK},
         },
         types::swap_adjustment_strategy::SwapAdjustmentStrategy,
     };
     use cosmwasm_std::{
         coin,
         testing::{mock_dependencies, mock_env, mock_info},
+        Addr,
     };
 
     #[test]
@@ -312,6 +552,8 @@ mod get_swap_amount_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
@@ -326,6 +568,7 @@ mod get_swap_amount_tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             vault.swap_adjustment_strategy.clone().unwrap(),
+            &Addr::unchecked(ADMIN),
             swap_adjustment,
             env.block.time,
         )
@@ -344,6 +587,8 @@ mod get_swap_amount_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
@@ -360,6 +605,7 @@ mod get_swap_amount_tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             vault.swap_adjustment_strategy.clone().unwrap(),
+            &Addr::unchecked(ADMIN),
             swap_adjustment,
             env.block.time,
         )
@@ -378,6 +624,8 @@ mod get_swap_amount_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
@@ -394,6 +642,7 @@ mod get_swap_amount_tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             vault.swap_adjustment_strategy.clone().unwrap(),
+            &Addr::unchecked(ADMIN),
             swap_adjustment,
             env.block.time,
         )
@@ -412,6 +661,8 @@ mod get_swap_amount_tests {
         let mut deps = mock_dependencies();
         let env = mock_env();
 
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
         let vault = setup_vault(
             deps.as_mut(),
             env.clone(),
@@ -428,6 +679,7 @@ mod get_swap_amount_tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             vault.swap_adjustment_strategy.clone().unwrap(),
+            &Addr::unchecked(ADMIN),
             swap_adjustment,
             env.block.time,
         )
@@ -576,6 +828,233 @@ mod get_swap_amount_tests {
 
         assert_eq!(swap_amount.amount, Uint128::zero());
     }
+
+    #[test]
+    fn va_should_return_amount_needed_to_reach_target_value() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution: ONE,
+                    target_value: ONE * Uint128::new(3),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, ONE);
+    }
+
+    #[test]
+    fn va_should_return_zero_swap_amount_when_ahead_of_target_value() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution: ONE,
+                    target_value: ONE,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, Uint128::zero());
+    }
+
+    #[test]
+    fn va_should_return_full_balance_when_target_value_exceeds_balance() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                received_amount: Coin::new(ONE.into(), DENOM_UUSK),
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::ValueAveraging {
+                    target_growth_per_execution: ONE,
+                    target_value: ONE_HUNDRED,
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.balance.amount);
+    }
+
+    #[test]
+    fn ma_should_return_unadjusted_swap_amount_without_enough_price_history() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    periods: 2,
+                    multiplier: Decimal::one(),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        deps.querier.update_fin_price(&HALF_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount);
+    }
+
+    #[test]
+    fn ma_should_return_increased_swap_amount_when_price_below_moving_average() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    periods: 2,
+                    multiplier: Decimal::one(),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        for (index, price) in vec![TWO_DECIMAL, TWO_DECIMAL].into_iter().enumerate() {
+            record_price(
+                deps.as_mut().storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                price,
+                env.block
+                    .time
+                    .plus_seconds(index as u64 * PRICE_HISTORY_INTERVAL_SECONDS),
+            )
+            .unwrap();
+        }
+
+        deps.querier.update_fin_price(&ONE_AND_HALF_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(
+            swap_amount.amount,
+            vault.swap_amount * Decimal::percent(125)
+        );
+    }
+
+    #[test]
+    fn ma_should_return_decreased_swap_amount_when_price_above_moving_average() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    periods: 2,
+                    multiplier: Decimal::one(),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        for (index, price) in vec![ONE_DECIMAL, ONE_DECIMAL].into_iter().enumerate() {
+            record_price(
+                deps.as_mut().storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                price,
+                env.block
+                    .time
+                    .plus_seconds(index as u64 * PRICE_HISTORY_INTERVAL_SECONDS),
+            )
+            .unwrap();
+        }
+
+        deps.querier.update_fin_price(&ONE_AND_HALF_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount * Decimal::percent(50));
+    }
+
+    #[test]
+    fn ma_should_not_return_less_than_minimum_adjustment() {
+        let mut deps = calc_mock_dependencies();
+        let env = mock_env();
+
+        instantiate_contract(deps.as_mut(), env.clone(), mock_info(ADMIN, &[]));
+
+        let vault = setup_vault(
+            deps.as_mut(),
+            env.clone(),
+            Vault {
+                swap_adjustment_strategy: Some(SwapAdjustmentStrategy::MovingAverage {
+                    periods: 2,
+                    multiplier: Decimal::one(),
+                }),
+                ..Vault::default()
+            },
+        );
+
+        for (index, price) in vec![HALF_DECIMAL, HALF_DECIMAL].into_iter().enumerate() {
+            record_price(
+                deps.as_mut().storage,
+                &vault.get_swap_denom(),
+                &vault.target_denom,
+                price,
+                env.block
+                    .time
+                    .plus_seconds(index as u64 * PRICE_HISTORY_INTERVAL_SECONDS),
+            )
+            .unwrap();
+        }
+
+        deps.querier.update_fin_price(&TWO_DECIMAL);
+
+        let swap_amount = get_swap_amount(&deps.as_ref(), &env, &vault).unwrap();
+
+        assert_eq!(swap_amount.amount, vault.swap_amount * Decimal::percent(20));
+    }
 }
 
 #[cfg(test)]
@@ -583,9 +1062,10 @@ mod get_risk_weighted_average_strategy_model_id_tests {
     use crate::{
         constants::{ONE, TEN},
         helpers::vault::get_risk_weighted_average_model_id,
+        state::base_denoms::{bitcoin_base_denom_config, ModelIdBucket},
         types::time_interval::TimeInterval,
     };
-    use cosmwasm_std::{testing::mock_env, Coin, Uint128};
+    use cosmwasm_std::{testing::mock_env, Coin, Decimal, Uint128};
 
     #[test]
     fn should_return_30_when_days_less_than_30() {
@@ -599,7 +1079,8 @@ mod get_risk_weighted_average_strategy_model_id_tests {
                 &env.block.time,
                 &balance,
                 &swap_amount,
-                &TimeInterval::Daily
+                &TimeInterval::Daily,
+                &bitcoin_base_denom_config(Decimal::zero()).model_id_buckets
             ),
             30
         );
@@ -617,7 +1098,8 @@ mod get_risk_weighted_average_strategy_model_id_tests {
                 &env.block.time,
                 &balance,
                 &swap_amount,
-                &TimeInterval::Daily
+                &TimeInterval::Daily,
+                &bitcoin_base_denom_config(Decimal::zero()).model_id_buckets
             ),
             90
         );
@@ -635,7 +1117,85 @@ mod get_risk_weighted_average_strategy_model_id_tests {
                 &env.block.time,
                 &balance,
                 &swap_amount,
-                &TimeInterval::Daily
+                &TimeInterval::Daily,
+                &bitcoin_base_denom_config(Decimal::zero()).model_id_buckets
+            ),
+            60
+        );
+    }
+
+    #[test]
+    fn should_use_cron_schedule_to_calculate_days() {
+        let env = mock_env();
+
+        let balance = Coin::new((ONE * Uint128::new(5)).into(), "base");
+        let swap_amount = ONE;
+
+        assert_eq!(
+            get_risk_weighted_average_model_id(
+                &env.block.time,
+                &balance,
+                &swap_amount,
+                &TimeInterval::Cron {
+                    expression: "0 0 1,15 * *".to_string()
+                },
+                &bitcoin_base_denom_config(Decimal::zero()).model_id_buckets
+            ),
+            60
+        );
+    }
+
+    #[test]
+    fn should_use_registered_model_id_buckets() {
+        let env = mock_env();
+
+        let balance = Coin::new((ONE * Uint128::new(45)).into(), "base");
+        let swap_amount = ONE;
+
+        assert_eq!(
+            get_risk_weighted_average_model_id(
+                &env.block.time,
+                &balance,
+                &swap_amount,
+                &TimeInterval::Daily,
+                &[
+                    ModelIdBucket {
+                        max_days: 40,
+                        model_id: 30,
+                    },
+                    ModelIdBucket {
+                        max_days: 80,
+                        model_id: 60,
+                    },
+                ]
+            ),
+            60
+        );
+    }
+
+    #[test]
+    fn should_use_last_model_id_when_days_exceed_all_buckets() {
+        let env = mock_env();
+
+        let balance = Coin::new((ONE * Uint128::new(200)).into(), "base");
+        let swap_amount = ONE;
+
+        assert_eq!(
+            get_risk_weighted_average_model_id(
+                &env.block.time,
+                &balance,
+                &swap_amount,
+                &TimeInterval::Daily,
+                &[
+                    ModelIdBucket {
+                        max_days: 40,
+                        model_id: 30,
+                    },
+                    ModelIdBucket {
+                        max_days: 80,
+                        model_id: 60,
+                    },
+                ]
             ),
             60
         );
@@ -862,9 +1422,10 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(events.len(), 0);
         assert_eq!(updated_vault, vault);
@@ -897,9 +1458,10 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let events = get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None)
-            .unwrap()
-            .events;
+        let events =
+            get_events_by_resource_id_handler(deps.as_ref(), vault.id, None, None, None, None)
+                .unwrap()
+                .events;
 
         assert_eq!(events.len(), 0);
         assert_eq!(updated_vault, vault);
@@ -938,10 +1500,16 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(storage_deps.as_ref(), vault.id, None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            storage_deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert!(events.contains(&Event {
             id: 1,
@@ -987,10 +1555,16 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(storage_deps.as_ref(), vault.id, None, None, None)
-                .unwrap()
-                .events;
+        let events = get_events_by_resource_id_handler(
+            storage_deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+        .events;
 
         assert!(events.contains(&Event {
             id: 1,
@@ -1035,16 +1609,18 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let events =
-            get_events_by_resource_id_handler(storage_deps.as_ref(), vault.id, None, None, None)
-                .unwrap()
-                .events;
-
-        let fee_rate = get_swap_fee_rate(
-            storage_deps.as_ref().storage,
-            &vault.swap_adjustment_strategy,
+        let events = get_events_by_resource_id_handler(
+            storage_deps.as_ref(),
+            vault.id,
+            None,
+            None,
+            None,
+            None,
         )
         .unwrap()
+        .events;
+
+        let fee_rate = get_swap_fee_rate(storage_deps.as_ref().storage, &vault).unwrap()
             + get_automation_fee_rate(storage_deps.as_ref().storage, &vault).unwrap();
 
         let received_amount = vault.swap_amount * Decimal::percent(95);
@@ -1098,11 +1674,7 @@ mod simulate_standard_dca_execution_tests {
         )
         .unwrap();
 
-        let fee_rate = get_swap_fee_rate(
-            storage_deps.as_ref().storage,
-            &vault.swap_adjustment_strategy,
-        )
-        .unwrap()
+        let fee_rate = get_swap_fee_rate(storage_deps.as_ref().storage, &vault).unwrap()
             + get_automation_fee_rate(storage_deps.as_ref().storage, &vault).unwrap();
 
         let received_amount_before_fee = vault.swap_amount * Decimal::percent(95);
@@ -1,15 +1,23 @@
+use crate::state::base_denoms::{BaseDenomConfig, ModelIdBucket};
+use crate::state::referrals::ReferralVolume;
+use crate::state::statistics::PairStatistics;
+use crate::state::swap_adjustments::SwapAdjustmentSubmission;
 use crate::types::config::Config;
 use crate::types::destination::Destination;
-use crate::types::event::Event;
+use crate::types::event::{Event, EventFilter};
+use crate::types::exit_condition::ExitCondition;
 use crate::types::fee_collector::FeeCollector;
+use crate::types::funding_mode::FundingMode;
 use crate::types::performance_assessment_strategy::PerformanceAssessmentStrategyParams;
+use crate::types::price_band::PriceBand;
 use crate::types::swap_adjustment_strategy::{
-    SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
+    BaseDenom, SwapAdjustmentStrategy, SwapAdjustmentStrategyParams,
 };
 use crate::types::time_interval::TimeInterval;
 use crate::types::vault::{Vault, VaultStatus};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Int128, Uint128, Uint64};
+use cw20::Cw20ReceiveMsg;
 use exchange::msg::Pair;
 
 #[cw_serde]
@@ -47,11 +55,24 @@ pub enum ExecuteMsg {
         target_receive_amount: Option<Uint128>,
         performance_assessment_strategy: Option<PerformanceAssessmentStrategyParams>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
+        end_time_utc_seconds: Option<Uint64>,
+        max_executions: Option<u64>,
+        stop_loss: Option<ExitCondition>,
+        take_profit: Option<ExitCondition>,
+        maximum_receive_amount: Option<Uint128>,
+        price_band: Option<PriceBand>,
+        referrer: Option<Addr>,
+        hook: Option<Addr>,
+        funding_mode: Option<FundingMode>,
     },
     Deposit {
         address: Addr,
         vault_id: Uint128,
     },
+    WithdrawFromVault {
+        vault_id: Uint128,
+        amount: Uint128,
+    },
     UpdateVault {
         vault_id: Uint128,
         label: Option<String>,
@@ -61,14 +82,37 @@ pub enum ExecuteMsg {
         time_interval: Option<TimeInterval>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategyParams>,
         swap_amount: Option<Uint128>,
+        end_time_utc_seconds: Option<Uint64>,
+        max_executions: Option<u64>,
+        stop_loss: Option<ExitCondition>,
+        take_profit: Option<ExitCondition>,
+        maximum_receive_amount: Option<Uint128>,
+        price_band: Option<PriceBand>,
+        hook: Option<Addr>,
     },
     CancelVault {
         vault_id: Uint128,
     },
+    PauseVault {
+        vault_id: Uint128,
+    },
+    ResumeVault {
+        vault_id: Uint128,
+    },
+    TransferVaultOwnership {
+        vault_id: Uint128,
+        new_owner: Addr,
+    },
+    AcceptVaultOwnership {
+        vault_id: Uint128,
+    },
     ExecuteTrigger {
         trigger_id: Uint128,
         route: Option<Binary>,
     },
+    ExecuteTriggers {
+        trigger_ids: Vec<Uint128>,
+    },
     UpdateConfig {
         executors: Option<Vec<Addr>>,
         fee_collectors: Option<Vec<FeeCollector>>,
@@ -81,11 +125,35 @@ pub enum ExecuteMsg {
         twap_period: Option<u64>,
         default_slippage_tolerance: Option<Decimal>,
         exchange_contract_address: Option<Addr>,
+        referral_fee_share: Option<Decimal>,
+        value_averaging_swap_fee_percent: Option<Decimal>,
+        swap_adjustment_submitters: Option<Vec<Addr>>,
+        swap_adjustment_quorum: Option<u64>,
+        performance_fee_percent: Option<Decimal>,
+        escrow_crystallisation_period_seconds: Option<u64>,
+        hook_gas_limit: Option<u64>,
+    },
+    CreateCustomSwapFee {
+        denom: String,
+        paired_denom: Option<String>,
+        swap_fee_percent: Decimal,
+    },
+    RemoveCustomSwapFee {
+        denom: String,
+        paired_denom: Option<String>,
     },
     UpdateSwapAdjustment {
         strategy: SwapAdjustmentStrategy,
         value: Decimal,
     },
+    RegisterBaseDenom {
+        base_denom: BaseDenom,
+        model_id_buckets: Vec<ModelIdBucket>,
+        escrow_level: Decimal,
+    },
+    RegisterCw20Denom {
+        denom: String,
+    },
     DisburseEscrow {
         vault_id: Uint128,
     },
@@ -102,6 +170,37 @@ pub enum ExecuteMsg {
     MigrateLimitOrder {
         vault_id: Uint128,
     },
+    MigrateStatistics {
+        limit: Option<u16>,
+    },
+    MigrateEvents {
+        limit: Option<u16>,
+    },
+    Claim {},
+    RetryPostExecutionAction {
+        vault_id: Uint128,
+        event_id: u64,
+    },
+    Receive(Cw20ReceiveMsg),
+}
+
+#[cw_serde]
+pub enum IbcLifecycleComplete {
+    IbcAck {
+        channel: String,
+        sequence: u64,
+        ack: String,
+        success: bool,
+    },
+    IbcTimeout {
+        channel: String,
+        sequence: u64,
+    },
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    IbcLifecycleComplete(IbcLifecycleComplete),
 }
 
 #[cw_serde]
@@ -139,17 +238,36 @@ pub enum QueryMsg {
         start_after: Option<u64>,
         limit: Option<u16>,
         reverse: Option<bool>,
+        filter: Option<EventFilter>,
     },
     #[returns(EventsResponse)]
     GetEvents {
         start_after: Option<u64>,
         limit: Option<u16>,
         reverse: Option<bool>,
+        filter: Option<EventFilter>,
     },
     #[returns(VaultPerformanceResponse)]
     GetVaultPerformance { vault_id: Uint128 },
+    #[returns(VaultReportResponse)]
+    GetVaultReport { vault_id: Uint128 },
     #[returns(DisburseEscrowTasksResponse)]
     GetDisburseEscrowTasks { limit: Option<u16> },
+    #[returns(CustomFeesResponse)]
+    GetCustomSwapFees {},
+    #[returns(ReferralVolumeResponse)]
+    GetReferralVolume { referrer: Addr },
+    #[returns(ClaimableResponse)]
+    GetClaimable { address: Addr },
+    #[returns(StatisticsResponse)]
+    GetStatistics {
+        swap_denom: Option<String>,
+        target_denom: Option<String>,
+    },
+    #[returns(BaseDenomsResponse)]
+    GetBaseDenoms {},
+    #[returns(SwapAdjustmentSubmissionsResponse)]
+    GetSwapAdjustmentSubmissions { strategy: SwapAdjustmentStrategy },
 }
 
 #[cw_serde]
@@ -183,6 +301,22 @@ pub struct VaultPerformanceResponse {
     pub factor: Decimal,
 }
 
+#[cw_serde]
+pub struct VaultReportResponse {
+    pub vault_id: Uint128,
+    pub executions_completed: u64,
+    pub executions_skipped: u64,
+    pub total_swapped: Coin,
+    pub total_received: Coin,
+    pub fees_paid: Coin,
+    pub average_price: Option<Decimal>,
+    pub minimum_price: Option<Decimal>,
+    pub maximum_price: Option<Decimal>,
+    pub current_price: Option<Decimal>,
+    pub current_value: Option<Coin>,
+    pub unrealised_pnl: Option<Int128>,
+}
+
 #[cw_serde]
 pub struct VaultsResponse {
     pub vaults: Vec<Vault>,
@@ -198,6 +332,34 @@ pub struct CustomFeesResponse {
     pub custom_fees: Vec<(String, Decimal)>,
 }
 
+#[cw_serde]
+pub struct ReferralVolumeResponse {
+    pub referral_volume: ReferralVolume,
+}
+
+#[cw_serde]
+pub struct ClaimableResponse {
+    pub claimable: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct StatisticsResponse {
+    pub active_vault_count: u64,
+    pub total_value_locked: Vec<Coin>,
+    pub pairs: Vec<PairStatistics>,
+}
+
+#[cw_serde]
+pub struct BaseDenomsResponse {
+    pub base_denoms: Vec<BaseDenomConfig>,
+}
+
+#[cw_serde]
+pub struct SwapAdjustmentSubmissionsResponse {
+    pub submissions: Vec<SwapAdjustmentSubmission>,
+    pub value: Decimal,
+}
+
 #[cw_serde]
 pub struct DisburseEscrowTasksResponse {
     pub vault_ids: Vec<Uint128>,
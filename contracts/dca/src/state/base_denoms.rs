@@ -0,0 +1,118 @@
+use crate::{constants::BITCOIN_MODEL_ID_BUCKETS, types::swap_adjustment_strategy::BaseDenom};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct ModelIdBucket {
+    pub max_days: u64,
+    pub model_id: u8,
+}
+
+#[cw_serde]
+pub struct BaseDenomConfig {
+    pub base_denom: BaseDenom,
+    pub model_id_buckets: Vec<ModelIdBucket>,
+    pub escrow_level: Decimal,
+}
+
+const BASE_DENOMS: Map<u64, BaseDenomConfig> = Map::new("base_denoms_v1");
+
+pub fn bitcoin_base_denom_config(escrow_level: Decimal) -> BaseDenomConfig {
+    BaseDenomConfig {
+        base_denom: BaseDenom::Bitcoin,
+        model_id_buckets: BITCOIN_MODEL_ID_BUCKETS
+            .iter()
+            .map(|(max_days, model_id)| ModelIdBucket {
+                max_days: *max_days,
+                model_id: *model_id,
+            })
+            .collect(),
+        escrow_level,
+    }
+}
+
+pub fn save_base_denom_config(
+    storage: &mut dyn Storage,
+    base_denom_config: &BaseDenomConfig,
+) -> StdResult<()> {
+    BASE_DENOMS.save(
+        storage,
+        base_denom_config.base_denom.hash(),
+        base_denom_config,
+    )
+}
+
+pub fn is_base_denom_registered(storage: &dyn Storage, base_denom: &BaseDenom) -> bool {
+    BASE_DENOMS.has(storage, base_denom.hash())
+}
+
+pub fn get_base_denom_config(
+    storage: &dyn Storage,
+    base_denom: &BaseDenom,
+) -> StdResult<BaseDenomConfig> {
+    BASE_DENOMS
+        .may_load(storage, base_denom.hash())?
+        .ok_or_else(|| {
+            StdError::generic_err(format!("base denom {:?} is not registered", base_denom))
+        })
+}
+
+pub fn get_base_denom_configs(storage: &dyn Storage) -> StdResult<Vec<BaseDenomConfig>> {
+    BASE_DENOMS
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, base_denom_config)| base_denom_config))
+        .collect()
+}
+
+#[cfg(test)]
+mod base_denoms_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn gets_registered_base_denom_config() {
+        let mut deps = mock_dependencies();
+
+        let base_denom_config = BaseDenomConfig {
+            base_denom: BaseDenom::Custom("ethereum".to_string()),
+            model_id_buckets: vec![ModelIdBucket {
+                max_days: 30,
+                model_id: 30,
+            }],
+            escrow_level: Decimal::percent(5),
+        };
+
+        save_base_denom_config(deps.as_mut().storage, &base_denom_config).unwrap();
+
+        assert_eq!(
+            get_base_denom_config(
+                deps.as_ref().storage,
+                &BaseDenom::Custom("ethereum".to_string())
+            )
+            .unwrap(),
+            base_denom_config
+        );
+    }
+
+    #[test]
+    fn with_unregistered_base_denom_fails() {
+        let mut deps = mock_dependencies();
+
+        save_base_denom_config(
+            deps.as_mut().storage,
+            &bitcoin_base_denom_config(Decimal::percent(5)),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_base_denom_config(
+                deps.as_ref().storage,
+                &BaseDenom::Custom("bitcoin".to_string())
+            )
+            .unwrap_err()
+            .to_string(),
+            "Generic error: base denom Custom(\"bitcoin\") is not registered"
+        );
+    }
+}
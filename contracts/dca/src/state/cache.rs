@@ -1,22 +1,41 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, SubMsg, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Binary, Coin, Decimal, SubMsg, Uint128};
+use cw_storage_plus::Map;
 use std::collections::VecDeque;
 
-pub const VAULT_ID_CACHE: Item<Uint128> = Item::new("vault_id_cache_v1");
-
 #[cw_serde]
 pub struct SwapCache {
     pub swap_denom_balance: Coin,
     pub receive_denom_balance: Coin,
 }
 
-pub const SWAP_CACHE: Item<SwapCache> = Item::new("swap_cache_v1");
+pub const SWAP_CACHE: Map<u128, SwapCache> = Map::new("swap_cache_v2");
+
+#[cw_serde]
+pub struct PendingSwapCache {
+    pub pull_amount: Coin,
+    pub swap_amount: Coin,
+    pub minimum_receive_amount: Uint128,
+    pub route: Option<Binary>,
+}
+
+pub const PENDING_SWAP_CACHE: Map<u128, PendingSwapCache> = Map::new("pending_swap_cache_v1");
+
+#[cw_serde]
+pub struct StakingRewardsClaimCache {
+    pub belief_price: Decimal,
+    pub route: Option<Binary>,
+}
+
+pub const STAKING_REWARDS_CLAIM_CACHE: Map<u128, StakingRewardsClaimCache> =
+    Map::new("staking_rewards_claim_cache_v1");
 
 #[cw_serde]
 pub struct PostExecutionActionCacheEntry {
     pub msg: SubMsg,
     pub funds: Vec<Coin>,
+    #[serde(default)]
+    pub destination_num: Option<usize>,
 }
 
 pub const POST_EXECUTION_ACTION_CACHE: Map<u128, VecDeque<PostExecutionActionCacheEntry>> =
@@ -0,0 +1,156 @@
+use cosmwasm_std::{Addr, Coin, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+
+const CLAIMABLE_BALANCES: Map<Addr, Vec<Coin>> = Map::new("claimable_balances_v1");
+
+const RETRIED_POST_EXECUTION_ACTIONS: Map<u64, bool> =
+    Map::new("retried_post_execution_actions_v1");
+
+pub fn add_claimable_funds(
+    storage: &mut dyn Storage,
+    address: Addr,
+    funds: Vec<Coin>,
+) -> StdResult<Vec<Coin>> {
+    CLAIMABLE_BALANCES.update(storage, address, |existing| -> StdResult<_> {
+        let mut balance = existing.unwrap_or_default();
+        for coin in funds {
+            match balance.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(existing) => existing.amount += coin.amount,
+                None => balance.push(coin),
+            }
+        }
+        Ok(balance)
+    })
+}
+
+pub fn subtract_claimable_funds(
+    storage: &mut dyn Storage,
+    address: Addr,
+    funds: Vec<Coin>,
+) -> StdResult<Vec<Coin>> {
+    let mut balance = get_claimable_balance(storage, address.clone())?;
+
+    for coin in funds {
+        let existing = balance
+            .iter_mut()
+            .find(|c| c.denom == coin.denom)
+            .ok_or_else(|| {
+                StdError::generic_err(format!("no claimable balance of {}", coin.denom))
+            })?;
+        existing.amount = existing.amount.checked_sub(coin.amount)?;
+    }
+
+    balance.retain(|coin| !coin.amount.is_zero());
+
+    if balance.is_empty() {
+        CLAIMABLE_BALANCES.remove(storage, address);
+    } else {
+        CLAIMABLE_BALANCES.save(storage, address, &balance)?;
+    }
+
+    Ok(balance)
+}
+
+pub fn get_claimable_balance(storage: &dyn Storage, address: Addr) -> StdResult<Vec<Coin>> {
+    Ok(CLAIMABLE_BALANCES
+        .may_load(storage, address)?
+        .unwrap_or_default())
+}
+
+pub fn clear_claimable_balance(storage: &mut dyn Storage, address: Addr) -> StdResult<Vec<Coin>> {
+    let balance = get_claimable_balance(storage, address.clone())?;
+    CLAIMABLE_BALANCES.remove(storage, address);
+    Ok(balance)
+}
+
+pub fn mark_post_execution_action_retried(
+    storage: &mut dyn Storage,
+    event_id: u64,
+) -> StdResult<()> {
+    RETRIED_POST_EXECUTION_ACTIONS.save(storage, event_id, &true)
+}
+
+pub fn post_execution_action_was_retried(storage: &dyn Storage, event_id: u64) -> bool {
+    RETRIED_POST_EXECUTION_ACTIONS.has(storage, event_id)
+}
+
+#[cfg(test)]
+mod claimable_balances_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accrues_funds_per_denom() {
+        let mut deps = mock_dependencies();
+        let address = Addr::unchecked("owner");
+
+        add_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(100, "ukuji")],
+        )
+        .unwrap();
+        add_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(50, "uusk"), Coin::new(20, "ukuji")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_claimable_balance(deps.as_ref().storage, address).unwrap(),
+            vec![Coin::new(120, "ukuji"), Coin::new(50, "uusk")]
+        );
+    }
+
+    #[test]
+    fn subtracts_funds_and_removes_empty_denoms() {
+        let mut deps = mock_dependencies();
+        let address = Addr::unchecked("owner");
+
+        add_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(100, "ukuji"), Coin::new(50, "uusk")],
+        )
+        .unwrap();
+
+        subtract_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(100, "ukuji"), Coin::new(20, "uusk")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_claimable_balance(deps.as_ref().storage, address).unwrap(),
+            vec![Coin::new(30, "uusk")]
+        );
+    }
+
+    #[test]
+    fn subtracting_more_than_claimable_fails() {
+        let mut deps = mock_dependencies();
+        let address = Addr::unchecked("owner");
+
+        add_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(100, "ukuji")],
+        )
+        .unwrap();
+
+        assert!(subtract_claimable_funds(
+            deps.as_mut().storage,
+            address.clone(),
+            vec![Coin::new(101, "ukuji")],
+        )
+        .is_err());
+        assert!(subtract_claimable_funds(
+            deps.as_mut().storage,
+            address,
+            vec![Coin::new(1, "uusk")],
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use cosmwasm_std::{Decimal, Order, StdResult, Storage};
+use cw_storage_plus::Map;
+use std::cmp::min;
+
+const CUSTOM_SWAP_FEES: Map<String, Decimal> = Map::new("custom_swap_fees_v1");
+
+pub fn get_custom_swap_fee_key(denom: String, paired_denom: Option<String>) -> String {
+    match paired_denom {
+        Some(paired_denom) => {
+            let mut denoms = [denom, paired_denom];
+            denoms.sort();
+            denoms.join("+")
+        }
+        None => denom,
+    }
+}
+
+pub fn save_custom_swap_fee(
+    storage: &mut dyn Storage,
+    key: String,
+    swap_fee_percent: Decimal,
+) -> StdResult<()> {
+    CUSTOM_SWAP_FEES.save(storage, key, &swap_fee_percent)
+}
+
+pub fn remove_custom_swap_fee(storage: &mut dyn Storage, key: String) {
+    CUSTOM_SWAP_FEES.remove(storage, key)
+}
+
+pub fn get_custom_swap_fees(storage: &dyn Storage) -> StdResult<Vec<(String, Decimal)>> {
+    CUSTOM_SWAP_FEES
+        .range(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+pub fn find_custom_swap_fee(
+    storage: &dyn Storage,
+    swap_denom: String,
+    target_denom: String,
+) -> StdResult<Option<Decimal>> {
+    if let Some(pair_fee) = CUSTOM_SWAP_FEES.may_load(
+        storage,
+        get_custom_swap_fee_key(swap_denom.clone(), Some(target_denom.clone())),
+    )? {
+        return Ok(Some(pair_fee));
+    }
+
+    Ok(
+        match (
+            CUSTOM_SWAP_FEES.may_load(storage, swap_denom)?,
+            CUSTOM_SWAP_FEES.may_load(storage, target_denom)?,
+        ) {
+            (Some(swap_denom_fee), Some(target_denom_fee)) => {
+                Some(min(swap_denom_fee, target_denom_fee))
+            }
+            (swap_denom_fee, target_denom_fee) => swap_denom_fee.or(target_denom_fee),
+        },
+    )
+}
+
+#[cfg(test)]
+mod custom_swap_fees_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn pair_key_is_independent_of_denom_order() {
+        assert_eq!(
+            get_custom_swap_fee_key("ukuji".to_string(), Some("uusk".to_string())),
+            get_custom_swap_fee_key("uusk".to_string(), Some("ukuji".to_string()))
+        );
+    }
+
+    #[test]
+    fn finds_no_fee_when_none_saved() {
+        let deps = mock_dependencies();
+
+        assert_eq!(
+            find_custom_swap_fee(deps.as_ref().storage, "ukuji".into(), "uusk".into()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_lowest_denom_fee() {
+        let mut deps = mock_dependencies();
+
+        save_custom_swap_fee(deps.as_mut().storage, "ukuji".into(), Decimal::percent(2)).unwrap();
+        save_custom_swap_fee(deps.as_mut().storage, "uusk".into(), Decimal::percent(1)).unwrap();
+
+        assert_eq!(
+            find_custom_swap_fee(deps.as_ref().storage, "ukuji".into(), "uusk".into()).unwrap(),
+            Some(Decimal::percent(1))
+        );
+    }
+
+    #[test]
+    fn prefers_pair_fee_over_denom_fees() {
+        let mut deps = mock_dependencies();
+
+        save_custom_swap_fee(deps.as_mut().storage, "ukuji".into(), Decimal::percent(1)).unwrap();
+        save_custom_swap_fee(
+            deps.as_mut().storage,
+            get_custom_swap_fee_key("uusk".into(), Some("ukuji".into())),
+            Decimal::percent(3),
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_custom_swap_fee(deps.as_ref().storage, "ukuji".into(), "uusk".into()).unwrap(),
+            Some(Decimal::percent(3))
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::Map;
+
+const CW20_DENOMS: Map<&str, bool> = Map::new("cw20_denoms_v8");
+
+pub fn save_cw20_denom(store: &mut dyn Storage, denom: &str) -> StdResult<()> {
+    CW20_DENOMS.save(store, denom, &true)
+}
+
+pub fn is_cw20_denom(store: &dyn Storage, denom: &str) -> bool {
+    CW20_DENOMS.has(store, denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn is_cw20_denom_after_saving() {
+        let mut deps = mock_dependencies();
+
+        save_cw20_denom(deps.as_mut().storage, "cw20-token").unwrap();
+
+        assert!(is_cw20_denom(deps.as_ref().storage, "cw20-token"));
+    }
+
+    #[test]
+    fn is_not_cw20_denom_when_never_saved() {
+        let deps = mock_dependencies();
+
+        assert!(!is_cw20_denom(deps.as_ref().storage, "ukuji"));
+    }
+}
@@ -1,17 +1,42 @@
-use super::state_helpers::fetch_and_increment_counter;
-use crate::types::event::{Event, EventBuilder};
-use cosmwasm_std::{from_json, to_json_binary, Binary, StdResult, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, UniqueIndex};
+use super::{
+    execution_summaries::{record_completed_execution, record_skipped_execution},
+    state_helpers::fetch_and_increment_counter,
+    vaults::get_vault_ids_by_owner,
+};
+use crate::types::event::{Event, EventBuilder, EventData, EventFilter};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Order, StdError, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
+use std::cmp::{max, min};
+use std::iter::{from_fn, Peekable};
 
 const EVENT_COUNTER: Item<u64> = Item::new("event_counter_v8");
 
+#[cw_serde]
+pub struct EventIndexMigration {
+    pub next_event_id: u64,
+    pub end_event_id: u64,
+}
+
+const EVENT_INDEX_MIGRATION: Item<EventIndexMigration> = Item::new("event_index_migration_v1");
+
 pub struct EventIndexes<'a> {
     pub resource_id: UniqueIndex<'a, (u128, u64), Binary, u64>,
+    pub event_type: UniqueIndex<'a, (String, u64), Binary, u64>,
+    pub timestamp: UniqueIndex<'a, (u64, u64), Binary, u64>,
+    pub block_height: UniqueIndex<'a, (u64, u64), Binary, u64>,
 }
 
 impl<'a> IndexList<Binary> for EventIndexes<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Binary>> + '_> {
-        let v: Vec<&dyn Index<Binary>> = vec![&self.resource_id];
+        let v: Vec<&dyn Index<Binary>> = vec![
+            &self.resource_id,
+            &self.event_type,
+            &self.timestamp,
+            &self.block_height,
+        ];
         Box::new(v.into_iter())
     }
 }
@@ -26,6 +51,30 @@ pub fn event_store<'a>() -> IndexedMap<'a, u64, Binary, EventIndexes<'a>> {
             },
             "serialised_events_v8__resource_id",
         ),
+        event_type: UniqueIndex::new(
+            |event| {
+                from_json(event)
+                    .map(|event: Event| (event.data.event_type().to_string(), event.id))
+                    .expect("deserialised event")
+            },
+            "serialised_events_v8__event_type",
+        ),
+        timestamp: UniqueIndex::new(
+            |event| {
+                from_json(event)
+                    .map(|event: Event| (event.timestamp.nanos(), event.id))
+                    .expect("deserialised event")
+            },
+            "serialised_events_v8__timestamp",
+        ),
+        block_height: UniqueIndex::new(
+            |event| {
+                from_json(event)
+                    .map(|event: Event| (event.block_height, event.id))
+                    .expect("deserialised event")
+            },
+            "serialised_events_v8__block_height",
+        ),
     };
     IndexedMap::new("serialised_events_v8", indexes)
 }
@@ -40,9 +89,283 @@ pub fn create_event(store: &mut dyn Storage, event_builder: EventBuilder) -> Std
     Ok(event.id)
 }
 
+pub fn get_event(store: &dyn Storage, id: u64) -> StdResult<Event> {
+    from_json(event_store().load(store, id)?)
+}
+
 pub fn create_events(store: &mut dyn Storage, event_builders: Vec<EventBuilder>) -> StdResult<()> {
     for event_builder in event_builders {
         create_event(store, event_builder)?;
     }
     Ok(())
 }
+
+pub fn start_event_index_migration(store: &mut dyn Storage) -> StdResult<()> {
+    if EVENT_INDEX_MIGRATION.may_load(store)?.is_some() {
+        return Ok(());
+    }
+
+    let end_event_id = EVENT_COUNTER.may_load(store)?.unwrap_or_default() + 1;
+
+    EVENT_INDEX_MIGRATION.save(
+        store,
+        &EventIndexMigration {
+            next_event_id: 1,
+            end_event_id,
+        },
+    )
+}
+
+pub fn migrate_event_indexes(
+    store: &mut dyn Storage,
+    limit: u16,
+) -> StdResult<EventIndexMigration> {
+    let migration = EVENT_INDEX_MIGRATION
+        .may_load(store)?
+        .ok_or_else(|| StdError::generic_err("event index migration has not been started"))?;
+
+    let page_end = min(
+        migration.next_event_id + limit as u64,
+        migration.end_event_id,
+    );
+
+    let events = event_store()
+        .range(
+            store,
+            Some(Bound::inclusive(migration.next_event_id)),
+            Some(Bound::exclusive(page_end)),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (id, event) in events {
+        event_store().save(store, id, &event)?;
+
+        let event = from_json::<Event>(&event)?;
+
+        match event.data {
+            EventData::DcaVaultExecutionCompleted {
+                sent,
+                received,
+                fee,
+            } => record_completed_execution(store, event.resource_id, &sent, &received, &fee)?,
+            EventData::DcaVaultExecutionSkipped { .. } => {
+                record_skipped_execution(store, event.resource_id)?
+            }
+            _ => {}
+        }
+    }
+
+    let migration = EventIndexMigration {
+        next_event_id: max(page_end, migration.next_event_id),
+        ..migration
+    };
+
+    EVENT_INDEX_MIGRATION.save(store, &migration)?;
+
+    Ok(migration)
+}
+
+fn first_event_id_in_range(
+    store: &dyn Storage,
+    index: &UniqueIndex<(u64, u64), Binary, u64>,
+    from: Option<u64>,
+    to: Option<u64>,
+    order: Order,
+) -> StdResult<Option<u64>> {
+    index
+        .range(
+            store,
+            from.map(|from| Bound::inclusive((from, 0))),
+            to.map(|to| Bound::inclusive((to, u64::MAX))),
+            order,
+        )
+        .next()
+        .transpose()
+        .map(|result| result.map(|(id, _)| id))
+}
+
+fn get_event_id_bounds(
+    store: &dyn Storage,
+    filter: &EventFilter,
+) -> StdResult<Option<(Option<u64>, Option<u64>)>> {
+    let indexes = event_store().idx;
+
+    let ranges = [
+        (
+            &indexes.timestamp,
+            filter.from_timestamp.map(|timestamp| timestamp.nanos()),
+            filter.to_timestamp.map(|timestamp| timestamp.nanos()),
+        ),
+        (
+            &indexes.block_height,
+            filter.from_block_height,
+            filter.to_block_height,
+        ),
+    ];
+
+    let mut lower_bound: Option<u64> = None;
+    let mut upper_bound: Option<u64> = None;
+
+    for (index, from, to) in ranges {
+        if from.is_none() && to.is_none() {
+            continue;
+        }
+
+        let first_id = first_event_id_in_range(store, index, from, to, Order::Ascending)?;
+        let last_id = first_event_id_in_range(store, index, from, to, Order::Descending)?;
+
+        match (first_id, last_id) {
+            (Some(first_id), Some(last_id)) => {
+                lower_bound = Some(lower_bound.map_or(first_id, |bound| max(bound, first_id)));
+                upper_bound = Some(upper_bound.map_or(last_id, |bound| min(bound, last_id)));
+            }
+            _ => return Ok(None),
+        }
+    }
+
+    Ok(Some((lower_bound, upper_bound)))
+}
+
+pub fn get_events(
+    store: &dyn Storage,
+    resource_id: Option<Uint128>,
+    filter: EventFilter,
+    start_after: Option<u64>,
+    limit: usize,
+    reverse: Option<bool>,
+) -> StdResult<Vec<Event>> {
+    let (mut lower_bound, mut upper_bound) = match get_event_id_bounds(store, &filter)? {
+        Some(bounds) => bounds,
+        None => return Ok(vec![]),
+    };
+
+    if let Some(start_after) = start_after {
+        if reverse.is_some() {
+            match start_after.checked_sub(1) {
+                Some(bound) => {
+                    upper_bound = Some(upper_bound.map_or(bound, |upper| min(upper, bound)))
+                }
+                None => return Ok(vec![]),
+            }
+        } else {
+            let bound = start_after + 1;
+            lower_bound = Some(lower_bound.map_or(bound, |lower| max(lower, bound)));
+        }
+    }
+
+    if let (Some(lower), Some(upper)) = (lower_bound, upper_bound) {
+        if lower > upper {
+            return Ok(vec![]);
+        }
+    }
+
+    let min_bound = lower_bound.map(Bound::inclusive);
+    let max_bound = upper_bound.map(Bound::inclusive);
+    let order = match reverse {
+        Some(true) => Order::Descending,
+        _ => Order::Ascending,
+    };
+
+    let store_events = event_store();
+
+    let owner_vault_ids = filter
+        .owner
+        .clone()
+        .map(|owner| get_vault_ids_by_owner(store, owner))
+        .transpose()?;
+
+    let event_ids: Box<dyn Iterator<Item = StdResult<u64>>> =
+        match (resource_id, &owner_vault_ids, filter.event_type.clone()) {
+            (Some(resource_id), _, _) => Box::new(
+                store_events
+                    .idx
+                    .resource_id
+                    .prefix(resource_id.into())
+                    .keys(store, min_bound, max_bound, order),
+            ),
+            (None, Some(owner_vault_ids), _) => Box::new(merge_event_ids(
+                owner_vault_ids
+                    .iter()
+                    .map(|vault_id| {
+                        store_events
+                            .idx
+                            .resource_id
+                            .prefix((*vault_id).into())
+                            .keys(store, min_bound.clone(), max_bound.clone(), order)
+                    })
+                    .collect(),
+                order,
+            )),
+            (None, None, Some(event_type)) => Box::new(
+                store_events
+                    .idx
+                    .event_type
+                    .prefix(event_type)
+                    .keys(store, min_bound, max_bound, order),
+            ),
+            (None, None, None) => Box::new(store_events.keys(store, min_bound, max_bound, order)),
+        };
+
+    let mut events = vec![];
+
+    for id in event_ids {
+        if events.len() >= limit {
+            break;
+        }
+
+        let event: Event = from_json(store_events.load(store, id?)?)?;
+
+        if let Some(owner_vault_ids) = &owner_vault_ids {
+            if !owner_vault_ids.contains(&event.resource_id) {
+                continue;
+            }
+        }
+
+        if let Some(event_type) = &filter.event_type {
+            if event.data.event_type() != event_type {
+                continue;
+            }
+        }
+
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+fn merge_event_ids<'a>(
+    event_ids: Vec<Box<dyn Iterator<Item = StdResult<u64>> + 'a>>,
+    order: Order,
+) -> impl Iterator<Item = StdResult<u64>> + 'a {
+    let mut event_ids = event_ids
+        .into_iter()
+        .map(Iterator::peekable)
+        .collect::<Vec<Peekable<_>>>();
+
+    from_fn(move || {
+        let mut next_index = None;
+        let mut next_id = None;
+
+        for (index, ids) in event_ids.iter_mut().enumerate() {
+            match ids.peek() {
+                Some(Ok(id)) => {
+                    let is_next = match (next_id, order) {
+                        (None, _) => true,
+                        (Some(next_id), Order::Ascending) => *id < next_id,
+                        (Some(next_id), Order::Descending) => *id > next_id,
+                    };
+
+                    if is_next {
+                        next_index = Some(index);
+                        next_id = Some(*id);
+                    }
+                }
+                Some(Err(_)) => return ids.next(),
+                None => {}
+            }
+        }
+
+        next_index.and_then(|index| event_ids[index].next())
+    })
+}
@@ -0,0 +1,143 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Decimal, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use std::cmp::{max, min};
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ExecutionSummary {
+    pub executions_completed: u64,
+    pub executions_skipped: u64,
+    pub swapped_amount: Uint128,
+    pub received_amount: Uint128,
+    pub fee_amount: Uint128,
+    pub minimum_price: Option<Decimal>,
+    pub maximum_price: Option<Decimal>,
+}
+
+const EXECUTION_SUMMARIES: Map<u128, ExecutionSummary> = Map::new("execution_summaries_v1");
+
+pub fn record_completed_execution(
+    storage: &mut dyn Storage,
+    vault_id: Uint128,
+    sent: &Coin,
+    received: &Coin,
+    fee: &Coin,
+) -> StdResult<()> {
+    let summary = get_execution_summary(storage, vault_id)?;
+
+    let price = if received.amount.is_zero() {
+        None
+    } else {
+        Some(Decimal::from_ratio(sent.amount, received.amount))
+    };
+
+    EXECUTION_SUMMARIES.save(
+        storage,
+        vault_id.into(),
+        &ExecutionSummary {
+            executions_completed: summary.executions_completed + 1,
+            swapped_amount: summary.swapped_amount.checked_add(sent.amount)?,
+            received_amount: summary.received_amount.checked_add(received.amount)?,
+            fee_amount: summary.fee_amount.checked_add(fee.amount)?,
+            minimum_price: match (summary.minimum_price, price) {
+                (Some(minimum_price), Some(price)) => Some(min(minimum_price, price)),
+                (minimum_price, price) => minimum_price.or(price),
+            },
+            maximum_price: match (summary.maximum_price, price) {
+                (Some(maximum_price), Some(price)) => Some(max(maximum_price, price)),
+                (maximum_price, price) => maximum_price.or(price),
+            },
+            ..summary
+        },
+    )
+}
+
+pub fn record_skipped_execution(storage: &mut dyn Storage, vault_id: Uint128) -> StdResult<()> {
+    let summary = get_execution_summary(storage, vault_id)?;
+
+    EXECUTION_SUMMARIES.save(
+        storage,
+        vault_id.into(),
+        &ExecutionSummary {
+            executions_skipped: summary.executions_skipped + 1,
+            ..summary
+        },
+    )
+}
+
+pub fn get_execution_summary(
+    storage: &dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<ExecutionSummary> {
+    Ok(EXECUTION_SUMMARIES
+        .may_load(storage, vault_id.into())?
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod execution_summaries_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+    use std::str::FromStr;
+
+    #[test]
+    fn accrues_completed_and_skipped_executions() {
+        let mut deps = mock_dependencies();
+        let vault_id = Uint128::one();
+
+        record_completed_execution(
+            deps.as_mut().storage,
+            vault_id,
+            &Coin::new(100, "ukuji"),
+            &Coin::new(50, "uusk"),
+            &Coin::new(1, "uusk"),
+        )
+        .unwrap();
+
+        record_skipped_execution(deps.as_mut().storage, vault_id).unwrap();
+
+        record_completed_execution(
+            deps.as_mut().storage,
+            vault_id,
+            &Coin::new(100, "ukuji"),
+            &Coin::new(40, "uusk"),
+            &Coin::new(1, "uusk"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_execution_summary(deps.as_ref().storage, vault_id).unwrap(),
+            ExecutionSummary {
+                executions_completed: 2,
+                executions_skipped: 1,
+                swapped_amount: Uint128::new(200),
+                received_amount: Uint128::new(90),
+                fee_amount: Uint128::new(2),
+                minimum_price: Some(Decimal::from_str("2").unwrap()),
+                maximum_price: Some(Decimal::from_str("2.5").unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_price_of_execution_with_nothing_received() {
+        let mut deps = mock_dependencies();
+        let vault_id = Uint128::one();
+
+        record_completed_execution(
+            deps.as_mut().storage,
+            vault_id,
+            &Coin::new(100, "ukuji"),
+            &Coin::new(0, "uusk"),
+            &Coin::new(0, "uusk"),
+        )
+        .unwrap();
+
+        let summary = get_execution_summary(deps.as_ref().storage, vault_id).unwrap();
+
+        assert_eq!(summary.executions_completed, 1);
+        assert_eq!(summary.minimum_price, None);
+        assert_eq!(summary.maximum_price, None);
+    }
+}
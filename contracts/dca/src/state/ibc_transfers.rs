@@ -0,0 +1,33 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, StdResult, Storage, SubMsg, Uint128};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct PendingIbcTransfer {
+    pub vault_id: Uint128,
+    pub msg: SubMsg,
+    pub funds: Vec<Coin>,
+}
+
+const PENDING_IBC_TRANSFERS: Map<(String, u64), PendingIbcTransfer> =
+    Map::new("pending_ibc_transfers_v1");
+
+pub fn save_pending_ibc_transfer(
+    storage: &mut dyn Storage,
+    channel_id: String,
+    sequence: u64,
+    transfer: &PendingIbcTransfer,
+) -> StdResult<()> {
+    PENDING_IBC_TRANSFERS.save(storage, (channel_id, sequence), transfer)
+}
+
+pub fn remove_pending_ibc_transfer(
+    storage: &mut dyn Storage,
+    channel_id: String,
+    sequence: u64,
+) -> StdResult<Option<PendingIbcTransfer>> {
+    let key = (channel_id, sequence);
+    let transfer = PENDING_IBC_TRANSFERS.may_load(storage, key.clone())?;
+    PENDING_IBC_TRANSFERS.remove(storage, key);
+    Ok(transfer)
+}
@@ -1,8 +1,17 @@
+pub mod base_denoms;
 pub mod cache;
+pub mod claimable_balances;
 pub mod config;
+pub mod custom_swap_fees;
+pub mod cw20_denoms;
 pub mod disburse_escrow_tasks;
 pub mod events;
+pub mod execution_summaries;
+pub mod ibc_transfers;
+pub mod price_history;
+pub mod referrals;
 pub mod state_helpers;
+pub mod statistics;
 pub mod swap_adjustments;
 pub mod triggers;
 pub mod vaults;
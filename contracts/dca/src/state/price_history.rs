@@ -0,0 +1,174 @@
+use crate::constants::{PRICE_HISTORY_CAPACITY, PRICE_HISTORY_INTERVAL_SECONDS};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+struct PriceHistoryCursor {
+    count: u64,
+    last_recorded_at: u64,
+}
+
+const PRICE_HISTORY: Map<(&str, &str, u64), Decimal> = Map::new("price_history_v1");
+
+const PRICE_HISTORY_CURSORS: Map<(&str, &str), PriceHistoryCursor> =
+    Map::new("price_history_cursors_v1");
+
+pub fn record_price(
+    storage: &mut dyn Storage,
+    swap_denom: &str,
+    target_denom: &str,
+    price: Decimal,
+    block_time: Timestamp,
+) -> StdResult<()> {
+    let cursor = PRICE_HISTORY_CURSORS.may_load(storage, (swap_denom, target_denom))?;
+
+    if let Some(cursor) = &cursor {
+        if block_time.seconds() < cursor.last_recorded_at + PRICE_HISTORY_INTERVAL_SECONDS {
+            return Ok(());
+        }
+    }
+
+    let count = cursor.map_or(0, |cursor| cursor.count);
+
+    PRICE_HISTORY.save(
+        storage,
+        (swap_denom, target_denom, count % PRICE_HISTORY_CAPACITY),
+        &price,
+    )?;
+
+    PRICE_HISTORY_CURSORS.save(
+        storage,
+        (swap_denom, target_denom),
+        &PriceHistoryCursor {
+            count: count + 1,
+            last_recorded_at: block_time.seconds(),
+        },
+    )
+}
+
+pub fn get_moving_average_price(
+    storage: &dyn Storage,
+    swap_denom: &str,
+    target_denom: &str,
+    periods: u64,
+) -> StdResult<Option<Decimal>> {
+    let count = PRICE_HISTORY_CURSORS
+        .may_load(storage, (swap_denom, target_denom))?
+        .map_or(0, |cursor| cursor.count);
+
+    if periods == 0 || periods > PRICE_HISTORY_CAPACITY || count < periods {
+        return Ok(None);
+    }
+
+    let total = ((count - periods)..count).try_fold(Decimal::zero(), |total, index| {
+        PRICE_HISTORY
+            .load(
+                storage,
+                (swap_denom, target_denom, index % PRICE_HISTORY_CAPACITY),
+            )
+            .map(|price| total + price)
+    })?;
+
+    Ok(Some(total / Uint128::from(periods)))
+}
+
+#[cfg(test)]
+mod price_history_tests {
+    use super::*;
+    use crate::tests::mocks::{DENOM_UKUJI, DENOM_UUSK};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+
+    fn record_prices(storage: &mut dyn Storage, prices: Vec<u64>) {
+        let env = mock_env();
+
+        for (index, price) in prices.into_iter().enumerate() {
+            record_price(
+                storage,
+                DENOM_UUSK,
+                DENOM_UKUJI,
+                Decimal::percent(price),
+                env.block
+                    .time
+                    .plus_seconds(index as u64 * PRICE_HISTORY_INTERVAL_SECONDS),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn averages_the_most_recent_prices() {
+        let mut deps = mock_dependencies();
+
+        record_prices(deps.as_mut().storage, vec![100, 200, 300, 400]);
+
+        assert_eq!(
+            get_moving_average_price(deps.as_ref().storage, DENOM_UUSK, DENOM_UKUJI, 2).unwrap(),
+            Some(Decimal::percent(350))
+        );
+    }
+
+    #[test]
+    fn with_fewer_prices_than_periods_returns_none() {
+        let mut deps = mock_dependencies();
+
+        record_prices(deps.as_mut().storage, vec![100, 200]);
+
+        assert_eq!(
+            get_moving_average_price(deps.as_ref().storage, DENOM_UUSK, DENOM_UKUJI, 3).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn does_not_record_more_than_once_per_interval() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        record_price(
+            deps.as_mut().storage,
+            DENOM_UUSK,
+            DENOM_UKUJI,
+            Decimal::percent(100),
+            env.block.time,
+        )
+        .unwrap();
+
+        record_price(
+            deps.as_mut().storage,
+            DENOM_UUSK,
+            DENOM_UKUJI,
+            Decimal::percent(300),
+            env.block
+                .time
+                .plus_seconds(PRICE_HISTORY_INTERVAL_SECONDS - 1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_moving_average_price(deps.as_ref().storage, DENOM_UUSK, DENOM_UKUJI, 1).unwrap(),
+            Some(Decimal::percent(100))
+        );
+    }
+
+    #[test]
+    fn overwrites_oldest_prices_when_full() {
+        let mut deps = mock_dependencies();
+
+        let mut prices = vec![1000; PRICE_HISTORY_CAPACITY as usize];
+        prices.push(100);
+
+        record_prices(deps.as_mut().storage, prices);
+
+        assert_eq!(
+            get_moving_average_price(
+                deps.as_ref().storage,
+                DENOM_UUSK,
+                DENOM_UKUJI,
+                PRICE_HISTORY_CAPACITY
+            )
+            .unwrap(),
+            Some(Decimal::percent(991))
+        );
+    }
+}
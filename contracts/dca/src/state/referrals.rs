@@ -0,0 +1,99 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, StdResult, Storage};
+use cw_storage_plus::Map;
+
+#[cw_serde]
+pub struct ReferralVolume {
+    pub volume: Vec<Coin>,
+    pub fees_earned: Vec<Coin>,
+}
+
+const REFERRAL_VOLUMES: Map<Addr, ReferralVolume> = Map::new("referral_volumes_v1");
+
+fn add_coin(coins: &mut Vec<Coin>, coin: Coin) {
+    match coins.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(existing) => existing.amount += coin.amount,
+        None => coins.push(coin),
+    }
+}
+
+pub fn record_referral(
+    storage: &mut dyn Storage,
+    referrer: Addr,
+    volume: Coin,
+    fee_earned: Coin,
+) -> StdResult<ReferralVolume> {
+    REFERRAL_VOLUMES.update(storage, referrer, |existing| -> StdResult<_> {
+        let mut referral_volume = existing.unwrap_or(ReferralVolume {
+            volume: vec![],
+            fees_earned: vec![],
+        });
+        add_coin(&mut referral_volume.volume, volume);
+        add_coin(&mut referral_volume.fees_earned, fee_earned);
+        Ok(referral_volume)
+    })
+}
+
+pub fn get_referral_volume(storage: &dyn Storage, referrer: Addr) -> StdResult<ReferralVolume> {
+    Ok(REFERRAL_VOLUMES
+        .may_load(storage, referrer)?
+        .unwrap_or(ReferralVolume {
+            volume: vec![],
+            fees_earned: vec![],
+        }))
+}
+
+#[cfg(test)]
+mod referrals_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn accrues_volume_and_fees_per_denom() {
+        let mut deps = mock_dependencies();
+        let referrer = Addr::unchecked("referrer");
+
+        record_referral(
+            deps.as_mut().storage,
+            referrer.clone(),
+            Coin::new(100, "ukuji"),
+            Coin::new(1, "uusk"),
+        )
+        .unwrap();
+        record_referral(
+            deps.as_mut().storage,
+            referrer.clone(),
+            Coin::new(200, "ukuji"),
+            Coin::new(2, "uusk"),
+        )
+        .unwrap();
+        record_referral(
+            deps.as_mut().storage,
+            referrer.clone(),
+            Coin::new(50, "uusk"),
+            Coin::new(3, "ukuji"),
+        )
+        .unwrap();
+
+        let referral_volume = get_referral_volume(deps.as_ref().storage, referrer).unwrap();
+
+        assert_eq!(
+            referral_volume,
+            ReferralVolume {
+                volume: vec![Coin::new(300, "ukuji"), Coin::new(50, "uusk")],
+                fees_earned: vec![Coin::new(3, "uusk"), Coin::new(3, "ukuji")],
+            }
+        );
+    }
+
+    #[test]
+    fn returns_empty_volume_for_unknown_referrer() {
+        let deps = mock_dependencies();
+
+        let referral_volume =
+            get_referral_volume(deps.as_ref().storage, Addr::unchecked("referrer")).unwrap();
+
+        assert!(referral_volume.volume.is_empty());
+        assert!(referral_volume.fees_earned.is_empty());
+    }
+}
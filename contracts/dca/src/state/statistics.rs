@@ -0,0 +1,276 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct PairStatistics {
+    pub swap_denom: String,
+    pub target_denom: String,
+    pub execution_count: u64,
+    pub swapped_amount: Uint128,
+    pub received_amount: Uint128,
+    pub fee_amount: Uint128,
+}
+
+const ACTIVE_VAULT_COUNT: Item<u64> = Item::new("active_vault_count_v1");
+const TOTAL_VALUE_LOCKED: Map<String, Uint128> = Map::new("total_value_locked_v1");
+const PAIR_STATISTICS: Map<(String, String), PairStatistics> = Map::new("pair_statistics_v1");
+
+#[cw_serde]
+pub struct StatisticsMigration {
+    pub next_vault_id: Uint128,
+    pub end_vault_id: Uint128,
+}
+
+const STATISTICS_MIGRATION: Item<StatisticsMigration> = Item::new("statistics_migration_v1");
+
+pub fn start_statistics_migration(
+    storage: &mut dyn Storage,
+    end_vault_id: Uint128,
+) -> StdResult<()> {
+    if STATISTICS_MIGRATION.may_load(storage)?.is_some() {
+        return Ok(());
+    }
+
+    ACTIVE_VAULT_COUNT.save(storage, &0)?;
+
+    let denoms = TOTAL_VALUE_LOCKED
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for denom in denoms {
+        TOTAL_VALUE_LOCKED.remove(storage, denom);
+    }
+
+    STATISTICS_MIGRATION.save(
+        storage,
+        &StatisticsMigration {
+            next_vault_id: Uint128::one(),
+            end_vault_id,
+        },
+    )
+}
+
+pub fn get_statistics_migration(storage: &dyn Storage) -> StdResult<Option<StatisticsMigration>> {
+    STATISTICS_MIGRATION.may_load(storage)
+}
+
+pub fn save_statistics_migration(
+    storage: &mut dyn Storage,
+    migration: &StatisticsMigration,
+) -> StdResult<()> {
+    STATISTICS_MIGRATION.save(storage, migration)
+}
+
+pub fn statistics_are_tracked_for_vault(
+    storage: &dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<bool> {
+    Ok(match STATISTICS_MIGRATION.may_load(storage)? {
+        Some(migration) => vault_id < migration.next_vault_id || vault_id >= migration.end_vault_id,
+        None => true,
+    })
+}
+
+pub fn increment_active_vault_count(storage: &mut dyn Storage) -> StdResult<u64> {
+    let count = get_active_vault_count(storage)? + 1;
+    ACTIVE_VAULT_COUNT.save(storage, &count)?;
+    Ok(count)
+}
+
+pub fn decrement_active_vault_count(storage: &mut dyn Storage) -> StdResult<u64> {
+    let count = get_active_vault_count(storage)?
+        .checked_sub(1)
+        .ok_or_else(|| StdError::generic_err("active vault count cannot be negative"))?;
+    ACTIVE_VAULT_COUNT.save(storage, &count)?;
+    Ok(count)
+}
+
+pub fn get_active_vault_count(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(ACTIVE_VAULT_COUNT.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn add_to_total_value_locked(storage: &mut dyn Storage, coin: &Coin) -> StdResult<Uint128> {
+    TOTAL_VALUE_LOCKED.update(storage, coin.denom.clone(), |amount| -> StdResult<_> {
+        Ok(amount.unwrap_or_default() + coin.amount)
+    })
+}
+
+pub fn subtract_from_total_value_locked(
+    storage: &mut dyn Storage,
+    coin: &Coin,
+) -> StdResult<Uint128> {
+    TOTAL_VALUE_LOCKED.update(storage, coin.denom.clone(), |amount| -> StdResult<_> {
+        Ok(amount.unwrap_or_default().checked_sub(coin.amount)?)
+    })
+}
+
+pub fn get_total_value_locked(storage: &dyn Storage) -> StdResult<Vec<Coin>> {
+    TOTAL_VALUE_LOCKED
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(denom, amount)| Coin { denom, amount }))
+        .collect()
+}
+
+pub fn record_execution_statistics(
+    storage: &mut dyn Storage,
+    coin_sent: &Coin,
+    coin_received: &Coin,
+    fee_amount: Uint128,
+) -> StdResult<PairStatistics> {
+    PAIR_STATISTICS.update(
+        storage,
+        (coin_sent.denom.clone(), coin_received.denom.clone()),
+        |statistics| -> StdResult<_> {
+            let statistics = statistics.unwrap_or(PairStatistics {
+                swap_denom: coin_sent.denom.clone(),
+                target_denom: coin_received.denom.clone(),
+                execution_count: 0,
+                swapped_amount: Uint128::zero(),
+                received_amount: Uint128::zero(),
+                fee_amount: Uint128::zero(),
+            });
+
+            Ok(PairStatistics {
+                execution_count: statistics.execution_count + 1,
+                swapped_amount: statistics.swapped_amount + coin_sent.amount,
+                received_amount: statistics.received_amount + coin_received.amount,
+                fee_amount: statistics.fee_amount + fee_amount,
+                ..statistics
+            })
+        },
+    )
+}
+
+pub fn get_pair_statistics(
+    storage: &dyn Storage,
+    swap_denom: Option<String>,
+    target_denom: Option<String>,
+) -> StdResult<Vec<PairStatistics>> {
+    match (swap_denom, target_denom) {
+        (Some(swap_denom), Some(target_denom)) => Ok(PAIR_STATISTICS
+            .may_load(storage, (swap_denom, target_denom))?
+            .into_iter()
+            .collect()),
+        (Some(swap_denom), None) => PAIR_STATISTICS
+            .prefix(swap_denom)
+            .range(storage, None, None, Order::Ascending)
+            .map(|result| result.map(|(_, statistics)| statistics))
+            .collect(),
+        (None, target_denom) => PAIR_STATISTICS
+            .range(storage, None, None, Order::Ascending)
+            .filter(|result| match (result, &target_denom) {
+                (Ok((_, statistics)), Some(target_denom)) => {
+                    &statistics.target_denom == target_denom
+                }
+                _ => true,
+            })
+            .map(|result| result.map(|(_, statistics)| statistics))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod statistics_tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn decrementing_active_vault_count_below_zero_fails() {
+        let mut deps = mock_dependencies();
+
+        let err = decrement_active_vault_count(deps.as_mut().storage).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Generic error: active vault count cannot be negative"
+        );
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 0);
+    }
+
+    #[test]
+    fn subtracting_more_than_total_value_locked_fails() {
+        let mut deps = mock_dependencies();
+
+        add_to_total_value_locked(deps.as_mut().storage, &Coin::new(100, "ukuji")).unwrap();
+
+        subtract_from_total_value_locked(deps.as_mut().storage, &Coin::new(101, "ukuji"))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn starting_statistics_migration_resets_statistics() {
+        let mut deps = mock_dependencies();
+
+        increment_active_vault_count(deps.as_mut().storage).unwrap();
+        add_to_total_value_locked(deps.as_mut().storage, &Coin::new(100, "ukuji")).unwrap();
+
+        start_statistics_migration(deps.as_mut().storage, Uint128::new(10)).unwrap();
+
+        assert_eq!(get_active_vault_count(deps.as_ref().storage).unwrap(), 0);
+        assert!(get_total_value_locked(deps.as_ref().storage)
+            .unwrap()
+            .is_empty());
+        assert!(!statistics_are_tracked_for_vault(deps.as_ref().storage, Uint128::one()).unwrap());
+        assert!(statistics_are_tracked_for_vault(deps.as_ref().storage, Uint128::new(10)).unwrap());
+    }
+
+    #[test]
+    fn tracks_total_value_locked_per_denom() {
+        let mut deps = mock_dependencies();
+
+        add_to_total_value_locked(deps.as_mut().storage, &Coin::new(100, "ukuji")).unwrap();
+        add_to_total_value_locked(deps.as_mut().storage, &Coin::new(50, "uusk")).unwrap();
+        subtract_from_total_value_locked(deps.as_mut().storage, &Coin::new(30, "ukuji")).unwrap();
+
+        assert_eq!(
+            get_total_value_locked(deps.as_ref().storage).unwrap(),
+            vec![Coin::new(70, "ukuji"), Coin::new(50, "uusk")]
+        );
+    }
+
+    #[test]
+    fn filters_pair_statistics_by_denoms() {
+        let mut deps = mock_dependencies();
+
+        for (swap_denom, target_denom) in [("ukuji", "uusk"), ("uusk", "ukuji"), ("uatom", "uusk")]
+        {
+            record_execution_statistics(
+                deps.as_mut().storage,
+                &Coin::new(100, swap_denom),
+                &Coin::new(90, target_denom),
+                Uint128::new(1),
+            )
+            .unwrap();
+        }
+
+        let pairs = |swap_denom: Option<&str>, target_denom: Option<&str>| {
+            get_pair_statistics(
+                deps.as_ref().storage,
+                swap_denom.map(String::from),
+                target_denom.map(String::from),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|statistics| (statistics.swap_denom, statistics.target_denom))
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(pairs(None, None).len(), 3);
+        assert_eq!(
+            pairs(Some("ukuji"), None),
+            vec![("ukuji".to_string(), "uusk".to_string())]
+        );
+        assert_eq!(
+            pairs(None, Some("uusk")),
+            vec![
+                ("uatom".to_string(), "uusk".to_string()),
+                ("ukuji".to_string(), "uusk".to_string())
+            ]
+        );
+        assert_eq!(
+            pairs(Some("uusk"), Some("ukuji")),
+            vec![("uusk".to_string(), "ukuji".to_string())]
+        );
+    }
+}
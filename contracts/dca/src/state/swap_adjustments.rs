@@ -1,55 +1,197 @@
-use crate::types::swap_adjustment_strategy::SwapAdjustmentStrategy;
+use crate::types::{config::Config, swap_adjustment_strategy::SwapAdjustmentStrategy};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Decimal, StdResult, Storage, Timestamp};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Decimal, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
+use std::cmp::max;
 
 #[cw_serde]
-struct SwapAdjustment {
+pub struct SwapAdjustmentSubmission {
+    pub submitter: Addr,
+    pub value: Decimal,
+    pub timestamp: u64,
+}
+
+struct SwapAdjustmentSubmissionIndexes<'a> {
+    pub submitter: MultiIndex<'a, Addr, SwapAdjustmentSubmission, (u64, Addr)>,
+}
+
+impl<'a> IndexList<SwapAdjustmentSubmission> for SwapAdjustmentSubmissionIndexes<'a> {
+    fn get_indexes(
+        &'_ self,
+    ) -> Box<dyn Iterator<Item = &'_ dyn Index<SwapAdjustmentSubmission>> + '_> {
+        let v: Vec<&dyn Index<SwapAdjustmentSubmission>> = vec![&self.submitter];
+        Box::new(v.into_iter())
+    }
+}
+
+fn swap_adjustment_submission_store<'a>(
+) -> IndexedMap<'a, (u64, &'a Addr), SwapAdjustmentSubmission, SwapAdjustmentSubmissionIndexes<'a>>
+{
+    let indexes = SwapAdjustmentSubmissionIndexes {
+        submitter: MultiIndex::new(
+            |_, submission| submission.submitter.clone(),
+            "swap_adjustment_submissions_v1",
+            "swap_adjustment_submissions_v1__submitter",
+        ),
+    };
+    IndexedMap::new("swap_adjustment_submissions_v1", indexes)
+}
+
+const SWAP_ADJUSTMENT_SUBMITTERS: Map<&Addr, bool> = Map::new("swap_adjustment_submitters_v1");
+
+#[cw_serde]
+struct LegacySwapAdjustment {
     value: Decimal,
     timestamp: u64,
 }
 
-const SWAP_ADJUSTMENTS: Map<u64, SwapAdjustment> = Map::new("swap_adjustments_v8");
+const LEGACY_SWAP_ADJUSTMENTS: Map<u64, LegacySwapAdjustment> = Map::new("swap_adjustments_v8");
+
+fn is_current_submitter(config: &Config, submitter: &Addr) -> bool {
+    if config.swap_adjustment_submitters.is_empty() {
+        return config.executors.contains(submitter) || submitter == config.admin;
+    }
+
+    config.swap_adjustment_submitters.contains(submitter)
+}
+
+pub fn migrate_legacy_swap_adjustments(
+    storage: &mut dyn Storage,
+    submitter: &Addr,
+) -> StdResult<()> {
+    let legacy_adjustments = LEGACY_SWAP_ADJUSTMENTS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (strategy_hash, adjustment) in legacy_adjustments {
+        if !swap_adjustment_submission_store().has(storage, (strategy_hash, submitter)) {
+            save_swap_adjustment_submission(
+                storage,
+                strategy_hash,
+                &SwapAdjustmentSubmission {
+                    submitter: submitter.clone(),
+                    value: adjustment.value,
+                    timestamp: adjustment.timestamp,
+                },
+            )?;
+        }
+
+        LEGACY_SWAP_ADJUSTMENTS.remove(storage, strategy_hash);
+    }
+
+    Ok(())
+}
+
+pub fn remove_swap_adjustment_submissions_from_former_submitters(
+    storage: &mut dyn Storage,
+    config: &Config,
+) -> StdResult<()> {
+    let former_submitters = SWAP_ADJUSTMENT_SUBMITTERS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|submitter| !is_current_submitter(config, submitter))
+        .collect::<Vec<_>>();
+
+    for submitter in former_submitters {
+        let strategy_hashes = swap_adjustment_submission_store()
+            .idx
+            .submitter
+            .prefix(submitter.clone())
+            .keys(storage, None, None, Order::Ascending)
+            .map(|result| result.map(|(strategy_hash, _)| strategy_hash))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for strategy_hash in strategy_hashes {
+            swap_adjustment_submission_store().remove(storage, (strategy_hash, &submitter))?;
+        }
+
+        SWAP_ADJUSTMENT_SUBMITTERS.remove(storage, &submitter);
+    }
+
+    Ok(())
+}
+
+fn save_swap_adjustment_submission(
+    storage: &mut dyn Storage,
+    strategy_hash: u64,
+    submission: &SwapAdjustmentSubmission,
+) -> StdResult<()> {
+    SWAP_ADJUSTMENT_SUBMITTERS.save(storage, &submission.submitter, &true)?;
+    swap_adjustment_submission_store().save(
+        storage,
+        (strategy_hash, &submission.submitter),
+        submission,
+    )
+}
 
 pub fn update_swap_adjustment(
     storage: &mut dyn Storage,
     strategy: SwapAdjustmentStrategy,
+    submitter: &Addr,
     value: Decimal,
     block_time: Timestamp,
 ) -> StdResult<()> {
-    SWAP_ADJUSTMENTS.save(
+    save_swap_adjustment_submission(
         storage,
         strategy.hash(),
-        &SwapAdjustment {
+        &SwapAdjustmentSubmission {
+            submitter: submitter.clone(),
             value,
             timestamp: block_time.seconds(),
         },
     )
 }
 
+pub fn get_swap_adjustment_submissions(
+    storage: &dyn Storage,
+    strategy: SwapAdjustmentStrategy,
+) -> StdResult<Vec<SwapAdjustmentSubmission>> {
+    swap_adjustment_submission_store()
+        .prefix(strategy.hash())
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, submission)| submission))
+        .collect()
+}
+
 pub fn get_swap_adjustment(
     storage: &dyn Storage,
     strategy: SwapAdjustmentStrategy,
+    config: &Config,
     block_time: Timestamp,
 ) -> Decimal {
-    let adjustment = SWAP_ADJUSTMENTS
-        .load(storage, strategy.hash())
-        .unwrap_or_else(|_| SwapAdjustment {
-            value: Decimal::one(),
-            timestamp: block_time.seconds(),
-        });
+    let mut values = get_swap_adjustment_submissions(storage, strategy.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|submission| {
+            is_current_submitter(config, &submission.submitter)
+                && submission.timestamp + strategy.ttl() > block_time.seconds()
+        })
+        .map(|submission| submission.value)
+        .collect::<Vec<Decimal>>();
+
+    if values.is_empty() || (values.len() as u64) < max(config.swap_adjustment_quorum, 1) {
+        return Decimal::one();
+    }
+
+    values.sort();
 
-    if adjustment.timestamp + strategy.ttl() > block_time.seconds() {
-        adjustment.value
+    let middle = values.len() / 2;
+
+    if values.len() % 2 == 0 {
+        (values[middle - 1] + values[middle]) / Uint128::new(2)
     } else {
-        Decimal::one()
+        values[middle]
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{position_type::PositionType, swap_adjustment_strategy::BaseDenom};
+    use crate::{
+        tests::mocks::ADMIN,
+        types::{position_type::PositionType, swap_adjustment_strategy::BaseDenom},
+    };
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env},
         Decimal,
@@ -71,6 +213,7 @@ mod tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             strategy.clone(),
+            &Addr::unchecked(ADMIN),
             adjustment_value,
             env.block.time,
         )
@@ -79,6 +222,7 @@ mod tests {
         let adjustment = get_swap_adjustment(
             deps.as_ref().storage,
             strategy,
+            &Config::default(),
             env.block.time.plus_seconds(1),
         );
 
@@ -101,6 +245,7 @@ mod tests {
         update_swap_adjustment(
             deps.as_mut().storage,
             strategy.clone(),
+            &Addr::unchecked(ADMIN),
             adjustment_value,
             env.block.time,
         )
@@ -109,9 +254,306 @@ mod tests {
         let adjustment = get_swap_adjustment(
             deps.as_ref().storage,
             strategy.clone(),
+            &Config::default(),
             env.block.time.plus_seconds(strategy.ttl() + 1),
         );
 
         assert_eq!(adjustment, Decimal::one());
     }
+
+    fn config(quorum: u64) -> Config {
+        Config {
+            swap_adjustment_submitters: (1..=4)
+                .map(|i| Addr::unchecked(format!("submitter-{}", i)))
+                .collect(),
+            swap_adjustment_quorum: quorum,
+            ..Config::default()
+        }
+    }
+
+    fn submit(
+        storage: &mut dyn Storage,
+        strategy: &SwapAdjustmentStrategy,
+        submissions: Vec<(&str, u64, Timestamp)>,
+    ) {
+        for (submitter, percent, block_time) in submissions {
+            update_swap_adjustment(
+                storage,
+                strategy.clone(),
+                &Addr::unchecked(submitter),
+                Decimal::percent(percent),
+                block_time,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn gets_median_of_odd_number_of_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("submitter-2", 300, env.block.time),
+                ("submitter-3", 110, env.block.time),
+            ],
+        );
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, &config(3), env.block.time),
+            Decimal::percent(110)
+        );
+    }
+
+    #[test]
+    fn gets_median_of_even_number_of_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("submitter-2", 300, env.block.time),
+                ("submitter-3", 110, env.block.time),
+                ("submitter-4", 130, env.block.time),
+            ],
+        );
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, &config(3), env.block.time),
+            Decimal::percent(120)
+        );
+    }
+
+    #[test]
+    fn overwrites_previous_submission_from_same_submitter() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("submitter-1", 130, env.block.time),
+            ],
+        );
+
+        let submissions =
+            get_swap_adjustment_submissions(deps.as_ref().storage, strategy.clone()).unwrap();
+
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].value, Decimal::percent(130));
+    }
+
+    #[test]
+    fn gets_default_swap_adjustment_if_quorum_not_reached() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("submitter-2", 110, env.block.time),
+            ],
+        );
+
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, &config(3), env.block.time),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn excludes_stale_submissions_from_quorum_and_median() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                (
+                    "submitter-2",
+                    110,
+                    env.block.time.plus_seconds(strategy.ttl()),
+                ),
+                (
+                    "submitter-3",
+                    130,
+                    env.block.time.plus_seconds(strategy.ttl()),
+                ),
+            ],
+        );
+
+        let block_time = env.block.time.plus_seconds(strategy.ttl() + 1);
+
+        assert_eq!(
+            get_swap_adjustment(
+                deps.as_ref().storage,
+                strategy.clone(),
+                &config(2),
+                block_time
+            ),
+            Decimal::percent(120)
+        );
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, &config(3), block_time),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn excludes_submissions_from_former_submitters() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("former-submitter", 300, env.block.time),
+                ("submitter-3", 110, env.block.time),
+            ],
+        );
+
+        assert_eq!(
+            get_swap_adjustment(
+                deps.as_ref().storage,
+                strategy.clone(),
+                &config(2),
+                env.block.time
+            ),
+            Decimal::percent(100)
+        );
+        assert_eq!(
+            get_swap_adjustment(deps.as_ref().storage, strategy, &config(3), env.block.time),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn removes_submissions_from_former_submitters() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        submit(
+            deps.as_mut().storage,
+            &strategy,
+            vec![
+                ("submitter-1", 90, env.block.time),
+                ("former-submitter", 300, env.block.time),
+            ],
+        );
+
+        remove_swap_adjustment_submissions_from_former_submitters(
+            deps.as_mut().storage,
+            &config(1),
+        )
+        .unwrap();
+
+        let submissions = get_swap_adjustment_submissions(deps.as_ref().storage, strategy).unwrap();
+
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].submitter, Addr::unchecked("submitter-1"));
+    }
+
+    #[test]
+    fn removes_submissions_from_former_submitters_across_strategies() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategies = [30, 35, 40].map(|model_id| SwapAdjustmentStrategy::RiskWeightedAverage {
+            model_id,
+            base_denom: BaseDenom::Bitcoin,
+            position_type: PositionType::Enter,
+        });
+
+        for strategy in strategies.iter() {
+            submit(
+                deps.as_mut().storage,
+                strategy,
+                vec![
+                    ("submitter-1", 90, env.block.time),
+                    ("former-submitter", 300, env.block.time),
+                ],
+            );
+        }
+
+        remove_swap_adjustment_submissions_from_former_submitters(
+            deps.as_mut().storage,
+            &config(1),
+        )
+        .unwrap();
+
+        for strategy in strategies {
+            let submissions =
+                get_swap_adjustment_submissions(deps.as_ref().storage, strategy).unwrap();
+
+            assert_eq!(submissions.len(), 1);
+            assert_eq!(submissions[0].submitter, Addr::unchecked("submitter-1"));
+        }
+
+        assert!(!SWAP_ADJUSTMENT_SUBMITTERS
+            .has(deps.as_ref().storage, &Addr::unchecked("former-submitter")));
+        assert!(
+            SWAP_ADJUSTMENT_SUBMITTERS.has(deps.as_ref().storage, &Addr::unchecked("submitter-1"))
+        );
+    }
+
+    #[test]
+    fn migrates_legacy_swap_adjustments_to_submissions() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        let strategy = SwapAdjustmentStrategy::default();
+
+        LEGACY_SWAP_ADJUSTMENTS
+            .save(
+                deps.as_mut().storage,
+                strategy.hash(),
+                &LegacySwapAdjustment {
+                    value: Decimal::percent(90),
+                    timestamp: env.block.time.seconds(),
+                },
+            )
+            .unwrap();
+
+        migrate_legacy_swap_adjustments(deps.as_mut().storage, &Addr::unchecked(ADMIN)).unwrap();
+
+        assert_eq!(
+            get_swap_adjustment(
+                deps.as_ref().storage,
+                strategy.clone(),
+                &Config::default(),
+                env.block.time
+            ),
+            Decimal::percent(90)
+        );
+        assert!(!LEGACY_SWAP_ADJUSTMENTS.has(deps.as_ref().storage, strategy.hash()));
+    }
 }
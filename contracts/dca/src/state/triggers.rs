@@ -1,8 +1,10 @@
 use crate::types::trigger::{Trigger, TriggerConfiguration};
 use cosmwasm_std::{Order, StdResult, Storage, Timestamp, Uint128};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex, UniqueIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex, UniqueIndex};
 use std::marker::PhantomData;
 
+const PAUSED_TRIGGERS: Map<u128, TriggerConfiguration> = Map::new("paused_triggers_v1");
+
 pub(crate) struct TriggerIndexes<'a> {
     pub due_date: MultiIndex<'a, u64, Trigger, u128>,
     pub order_idx: UniqueIndex<'a, u128, Trigger, u128>,
@@ -48,6 +50,23 @@ pub fn delete_trigger(store: &mut dyn Storage, vault_id: Uint128) -> StdResult<(
     trigger_store().remove(store, vault_id.into())
 }
 
+pub fn save_paused_trigger(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+    configuration: &TriggerConfiguration,
+) -> StdResult<()> {
+    PAUSED_TRIGGERS.save(store, vault_id.into(), configuration)
+}
+
+pub fn take_paused_trigger(
+    store: &mut dyn Storage,
+    vault_id: Uint128,
+) -> StdResult<Option<TriggerConfiguration>> {
+    let configuration = PAUSED_TRIGGERS.may_load(store, vault_id.into())?;
+    PAUSED_TRIGGERS.remove(store, vault_id.into());
+    Ok(configuration)
+}
+
 pub fn get_time_triggers(
     store: &dyn Storage,
     due_before: Timestamp,
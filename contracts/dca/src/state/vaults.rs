@@ -1,17 +1,31 @@
-use super::{config::get_config, triggers::get_trigger};
+use super::{
+    config::get_config,
+    statistics::{
+        add_to_total_value_locked, decrement_active_vault_count, get_statistics_migration,
+        increment_active_vault_count, save_statistics_migration, start_statistics_migration,
+        statistics_are_tracked_for_vault, subtract_from_total_value_locked, StatisticsMigration,
+    },
+    triggers::get_trigger,
+};
 use crate::{
     helpers::state::fetch_and_increment_counter,
     types::{
         destination::Destination,
+        exit_condition::ExitCondition,
+        funding_mode::FundingMode,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
+        price_band::PriceBand,
         swap_adjustment_strategy::SwapAdjustmentStrategy,
         time_interval::TimeInterval,
         vault::{Vault, VaultBuilder, VaultStatus},
     },
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Order, StdResult, Storage, Timestamp, Uint128};
+use cosmwasm_std::{
+    Addr, Binary, Coin, Decimal, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, UniqueIndex};
+use std::cmp::{max, min};
 
 const VAULT_COUNTER: Item<u64> = Item::new("vault_counter_v8");
 
@@ -44,7 +58,9 @@ pub fn migrate_vault(store: &mut dyn Storage, vault: Vault) -> StdResult<()> {
 
 pub fn save_vault(store: &mut dyn Storage, vault_builder: VaultBuilder) -> StdResult<Vault> {
     let vault = vault_builder.build(fetch_and_increment_counter(store, VAULT_COUNTER)?.into());
-    vault_store().save(store, vault.id.into(), &vault.clone().into())?;
+    let vault_data: VaultData = vault.clone().into();
+    vault_store().save(store, vault.id.into(), &vault_data)?;
+    update_statistics(store, None, &vault_data)?;
     Ok(vault)
 }
 
@@ -53,6 +69,22 @@ pub fn get_vault(store: &dyn Storage, vault_id: Uint128) -> StdResult<Vault> {
     vault_from(store, &vault_data)
 }
 
+pub fn get_vault_owner(store: &dyn Storage, vault_id: Uint128) -> StdResult<Option<Addr>> {
+    Ok(vault_store()
+        .may_load(store, vault_id.into())?
+        .map(|vault_data| vault_data.owner))
+}
+
+pub fn get_vault_ids_by_owner(store: &dyn Storage, owner: Addr) -> StdResult<Vec<Uint128>> {
+    vault_store()
+        .idx
+        .owner
+        .prefix(owner)
+        .keys(store, None, None, Order::Ascending)
+        .map(|result| result.map(Uint128::from))
+        .collect()
+}
+
 pub fn get_vaults_by_address(
     store: &dyn Storage,
     address: Addr,
@@ -104,10 +136,88 @@ pub fn get_vaults(
 }
 
 pub fn update_vault(store: &mut dyn Storage, vault: Vault) -> StdResult<Vault> {
-    vault_store().save(store, vault.id.into(), &vault.clone().into())?;
+    let existing_vault_data = vault_store().may_load(store, vault.id.into())?;
+    let vault_data: VaultData = vault.clone().into();
+    vault_store().save(store, vault.id.into(), &vault_data)?;
+    update_statistics(store, existing_vault_data, &vault_data)?;
     Ok(vault)
 }
 
+pub fn start_vault_statistics_migration(store: &mut dyn Storage) -> StdResult<()> {
+    let end_vault_id = VAULT_COUNTER.may_load(store)?.unwrap_or_default() + 1;
+    start_statistics_migration(store, end_vault_id.into())
+}
+
+pub fn migrate_vault_statistics(
+    store: &mut dyn Storage,
+    limit: u16,
+) -> StdResult<StatisticsMigration> {
+    let migration = get_statistics_migration(store)?
+        .ok_or_else(|| StdError::generic_err("statistics migration has not been started"))?;
+
+    let page_end = min(
+        migration.next_vault_id + Uint128::from(limit),
+        migration.end_vault_id,
+    );
+
+    let vaults = vault_store()
+        .range(
+            store,
+            Some(Bound::inclusive(migration.next_vault_id.u128())),
+            Some(Bound::exclusive(page_end.u128())),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let migration = StatisticsMigration {
+        next_vault_id: max(page_end, migration.next_vault_id),
+        ..migration
+    };
+
+    save_statistics_migration(store, &migration)?;
+
+    for (_, vault_data) in vaults {
+        update_statistics(store, None, &vault_data)?;
+    }
+
+    Ok(migration)
+}
+
+fn update_statistics(
+    store: &mut dyn Storage,
+    existing_vault_data: Option<VaultData>,
+    vault_data: &VaultData,
+) -> StdResult<()> {
+    if !statistics_are_tracked_for_vault(store, vault_data.id)? {
+        return Ok(());
+    }
+
+    let was_active = existing_vault_data
+        .as_ref()
+        .is_some_and(|existing| existing.status == VaultStatus::Active);
+    let is_active = vault_data.status == VaultStatus::Active;
+
+    if is_active && !was_active {
+        increment_active_vault_count(store)?;
+    }
+
+    if was_active && !is_active {
+        decrement_active_vault_count(store)?;
+    }
+
+    if let Some(existing) = existing_vault_data {
+        if existing.balance == vault_data.balance {
+            return Ok(());
+        }
+
+        subtract_from_total_value_locked(store, &existing.balance)?;
+    }
+
+    add_to_total_value_locked(store, &vault_data.balance)?;
+
+    Ok(())
+}
+
 #[cw_serde]
 struct VaultData {
     id: Uint128,
@@ -131,6 +241,21 @@ struct VaultData {
     escrowed_amount: Coin,
     performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pending_owner: Option<Addr>,
+    end_time: Option<Timestamp>,
+    max_executions: Option<u64>,
+    #[serde(default)]
+    execution_count: u64,
+    stop_loss: Option<ExitCondition>,
+    take_profit: Option<ExitCondition>,
+    maximum_receive_amount: Option<Uint128>,
+    price_band: Option<PriceBand>,
+    referrer: Option<Addr>,
+    hook: Option<Addr>,
+    #[serde(default)]
+    funding_mode: FundingMode,
+    #[serde(default)]
+    performance_fee_high_water_mark: Uint128,
 }
 
 impl From<Vault> for VaultData {
@@ -157,6 +282,18 @@ impl From<Vault> for VaultData {
             escrowed_amount: vault.escrowed_amount,
             performance_assessment_strategy: vault.performance_assessment_strategy,
             swap_adjustment_strategy: vault.swap_adjustment_strategy,
+            pending_owner: vault.pending_owner,
+            end_time: vault.end_time,
+            max_executions: vault.max_executions,
+            execution_count: vault.execution_count,
+            stop_loss: vault.stop_loss,
+            take_profit: vault.take_profit,
+            maximum_receive_amount: vault.maximum_receive_amount,
+            price_band: vault.price_band,
+            referrer: vault.referrer,
+            hook: vault.hook,
+            funding_mode: vault.funding_mode,
+            performance_fee_high_water_mark: vault.performance_fee_high_water_mark,
         }
     }
 }
@@ -186,6 +323,18 @@ fn vault_from(store: &dyn Storage, data: &VaultData) -> StdResult<Vault> {
         escrowed_amount: data.escrowed_amount.clone(),
         performance_assessment_strategy: data.performance_assessment_strategy.clone(),
         swap_adjustment_strategy: data.swap_adjustment_strategy.clone(),
+        pending_owner: data.pending_owner.clone(),
+        end_time: data.end_time,
+        max_executions: data.max_executions,
+        execution_count: data.execution_count,
+        stop_loss: data.stop_loss.clone(),
+        take_profit: data.take_profit.clone(),
+        maximum_receive_amount: data.maximum_receive_amount,
+        price_band: data.price_band.clone(),
+        referrer: data.referrer.clone(),
+        hook: data.hook.clone(),
+        funding_mode: data.funding_mode.clone(),
+        performance_fee_high_water_mark: data.performance_fee_high_water_mark,
         trigger,
     })
 }
@@ -4,7 +4,6 @@ use crate::{
     contract::instantiate,
     msg::{ExecuteMsg, InstantiateMsg},
     state::{
-        cache::VAULT_ID_CACHE,
         triggers::save_trigger,
         vaults::{get_vault, update_vault},
     },
@@ -13,6 +12,7 @@ use crate::{
         destination::Destination,
         event::{EventBuilder, EventData},
         fee_collector::FeeCollector,
+        funding_mode::FundingMode,
         performance_assessment_strategy::PerformanceAssessmentStrategy,
         position_type::PositionType,
         swap_adjustment_strategy::{
@@ -93,6 +93,13 @@ impl Default for Config {
             default_slippage_tolerance: Decimal::percent(2),
             old_staking_router_address: Addr::unchecked("staking-router"),
             exchange_contract_address: Addr::unchecked(EXCHANGE_CONTRACT_ADDRESS),
+            referral_fee_share: Decimal::zero(),
+            value_averaging_swap_fee_percent: None,
+            swap_adjustment_submitters: vec![],
+            swap_adjustment_quorum: 1,
+            performance_fee_percent: None,
+            escrow_crystallisation_period_seconds: None,
+            hook_gas_limit: None,
         }
     }
 }
@@ -103,6 +110,8 @@ impl Default for Destination {
             allocation: Decimal::percent(100),
             address: Addr::unchecked(USER),
             msg: None,
+            ibc_transfer: None,
+            vault_id: None,
         }
     }
 }
@@ -124,6 +133,8 @@ impl Default for Vault {
                     })
                     .unwrap(),
                 ),
+                ibc_transfer: None,
+                vault_id: None,
             }],
             status: VaultStatus::Active,
             balance: Coin::new(TEN.into(), DENOM_UKUJI),
@@ -144,6 +155,18 @@ impl Default for Vault {
             }),
             swap_adjustment_strategy: None,
             performance_assessment_strategy: None,
+            pending_owner: None,
+            end_time: None,
+            max_executions: None,
+            execution_count: 0,
+            stop_loss: None,
+            take_profit: None,
+            maximum_receive_amount: None,
+            price_band: None,
+            referrer: None,
+            hook: None,
+            funding_mode: FundingMode::Deposit,
+            performance_fee_high_water_mark: Uint128::zero(),
         }
     }
 }
@@ -232,7 +255,5 @@ pub fn setup_vault(deps: DepsMut, env: Env, mut vault: Vault) -> Vault {
         .unwrap();
     }
 
-    VAULT_ID_CACHE.save(deps.storage, &vault.id).unwrap();
-
     get_vault(deps.storage, vault.id).unwrap()
 }
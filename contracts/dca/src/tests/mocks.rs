@@ -1,8 +1,8 @@
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
     from_json, to_json_binary, Binary, Coin, ContractResult, CustomQuery, Decimal, Decimal256,
-    Empty, OwnedDeps, Querier, QuerierResult, QueryRequest, StdError, StdResult, SystemError,
-    SystemResult, Uint128, Uint256, WasmQuery,
+    Empty, FullDelegation, OwnedDeps, Querier, QuerierResult, QueryRequest, StdError, StdResult,
+    SystemError, SystemResult, Uint128, Uint256, Validator, WasmQuery,
 };
 use exchange::msg::Order;
 use exchange::msg::Pair;
@@ -31,6 +31,14 @@ impl<C: DeserializeOwned> CalcMockQuerier<C> {
         let mut querier = MockQuerier::<C>::new(&[]);
 
         querier.update_wasm(|query| {
+            if let WasmQuery::Smart { msg, .. } = query {
+                if from_json::<ExchangeQueryMsg>(msg).is_err() {
+                    return SystemResult::Err(SystemError::UnsupportedRequest {
+                        kind: "non exchange smart query".to_string(),
+                    });
+                }
+            }
+
             SystemResult::Ok(ContractResult::Ok(match query {
                 WasmQuery::Smart { msg, .. } => match from_json::<ExchangeQueryMsg>(msg).unwrap() {
                     ExchangeQueryMsg::GetPairs { .. } => {
@@ -114,6 +122,20 @@ impl<C: CustomQuery + DeserializeOwned> CalcMockQuerier<C> {
         self.mock_querier.update_wasm(wasm_handler);
     }
 
+    pub fn update_balance(&mut self, addr: impl Into<String>, balance: Vec<Coin>) {
+        self.mock_querier.update_balance(addr, balance);
+    }
+
+    pub fn update_staking(
+        &mut self,
+        denom: &str,
+        validators: &[Validator],
+        delegations: &[FullDelegation],
+    ) {
+        self.mock_querier
+            .update_staking(denom, validators, delegations);
+    }
+
     pub fn update_fin_price(&mut self, price: &'static Decimal) {
         self.mock_querier.update_wasm(move |query| {
             SystemResult::Ok(ContractResult::Ok(match query {
@@ -17,4 +17,18 @@ pub struct Config {
     pub default_slippage_tolerance: Decimal,
     pub old_staking_router_address: Addr,
     pub exchange_contract_address: Addr,
+    #[serde(default)]
+    pub referral_fee_share: Decimal,
+    #[serde(default)]
+    pub value_averaging_swap_fee_percent: Option<Decimal>,
+    #[serde(default)]
+    pub swap_adjustment_submitters: Vec<Addr>,
+    #[serde(default)]
+    pub swap_adjustment_quorum: u64,
+    #[serde(default)]
+    pub performance_fee_percent: Option<Decimal>,
+    #[serde(default)]
+    pub escrow_crystallisation_period_seconds: Option<u64>,
+    #[serde(default)]
+    pub hook_gas_limit: Option<u64>,
 }
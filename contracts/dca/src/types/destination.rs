@@ -1,9 +1,20 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Decimal};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+
+#[cw_serde]
+pub struct IbcTransfer {
+    pub channel_id: String,
+    pub memo: Option<String>,
+    pub timeout_seconds: Option<u64>,
+}
 
 #[cw_serde]
 pub struct Destination {
     pub allocation: Decimal,
     pub address: Addr,
     pub msg: Option<Binary>,
+    #[serde(default)]
+    pub ibc_transfer: Option<IbcTransfer>,
+    #[serde(default)]
+    pub vault_id: Option<Uint128>,
 }
@@ -1,22 +1,35 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, SubMsg, Timestamp, Uint128};
 
-use super::update::Update;
+use super::{exit_condition::ExitAction, price_band::PriceBandEdge, update::Update};
 
 #[cw_serde]
 pub enum ExecutionSkippedReason {
     SlippageToleranceExceeded,
     PriceThresholdExceeded { price: Decimal },
+    PriceBandBreached { price: Decimal, edge: PriceBandEdge },
     SwapAmountAdjustedToZero,
     SlippageQueryError,
+    AllowancePullFailed,
+    NoStakingRewardsClaimed,
+    StakingRewardsClaimFailed,
     UnknownError { msg: String },
 }
 
+#[cw_serde]
+pub enum VaultEndReason {
+    EndTimeReached,
+    MaxExecutionsReached,
+}
+
 #[cw_serde]
 pub enum EventData {
     DcaVaultFundsDeposited {
         amount: Coin,
     },
+    DcaVaultFundsWithdrawn {
+        amount: Coin,
+    },
     DcaVaultExecutionTriggered {
         base_denom: String,
         quote_denom: String,
@@ -39,6 +52,21 @@ pub enum EventData {
         reason: ExecutionSkippedReason,
     },
     DcaVaultCancelled {},
+    DcaVaultStopLossTriggered {
+        price: Decimal,
+        action: ExitAction,
+    },
+    DcaVaultTakeProfitTriggered {
+        price: Decimal,
+        action: ExitAction,
+    },
+    DcaVaultEnded {
+        reason: VaultEndReason,
+        execution_count: u64,
+        refunded: Coin,
+    },
+    DcaVaultPaused {},
+    DcaVaultResumed {},
     DcaVaultEscrowDisbursed {
         amount_disbursed: Coin,
         performance_fee: Coin,
@@ -50,6 +78,47 @@ pub enum EventData {
     DcaVaultUpdated {
         updates: Vec<Update>,
     },
+    DcaVaultOwnershipTransferProposed {
+        owner: Addr,
+        pending_owner: Addr,
+    },
+    DcaVaultOwnershipTransferred {
+        previous_owner: Addr,
+        new_owner: Addr,
+    },
+}
+
+impl EventData {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            EventData::DcaVaultFundsDeposited { .. } => "dca_vault_funds_deposited",
+            EventData::DcaVaultFundsWithdrawn { .. } => "dca_vault_funds_withdrawn",
+            EventData::DcaVaultExecutionTriggered { .. } => "dca_vault_execution_triggered",
+            EventData::DcaVaultExecutionCompleted { .. } => "dca_vault_execution_completed",
+            EventData::SimulatedDcaVaultExecutionCompleted { .. } => {
+                "simulated_dca_vault_execution_completed"
+            }
+            EventData::DcaVaultExecutionSkipped { .. } => "dca_vault_execution_skipped",
+            EventData::SimulatedDcaVaultExecutionSkipped { .. } => {
+                "simulated_dca_vault_execution_skipped"
+            }
+            EventData::DcaVaultCancelled { .. } => "dca_vault_cancelled",
+            EventData::DcaVaultStopLossTriggered { .. } => "dca_vault_stop_loss_triggered",
+            EventData::DcaVaultTakeProfitTriggered { .. } => "dca_vault_take_profit_triggered",
+            EventData::DcaVaultEnded { .. } => "dca_vault_ended",
+            EventData::DcaVaultPaused { .. } => "dca_vault_paused",
+            EventData::DcaVaultResumed { .. } => "dca_vault_resumed",
+            EventData::DcaVaultEscrowDisbursed { .. } => "dca_vault_escrow_disbursed",
+            EventData::DcaVaultPostExecutionActionFailed { .. } => {
+                "dca_vault_post_execution_action_failed"
+            }
+            EventData::DcaVaultUpdated { .. } => "dca_vault_updated",
+            EventData::DcaVaultOwnershipTransferProposed { .. } => {
+                "dca_vault_ownership_transfer_proposed"
+            }
+            EventData::DcaVaultOwnershipTransferred { .. } => "dca_vault_ownership_transferred",
+        }
+    }
 }
 
 #[cw_serde]
@@ -61,6 +130,17 @@ pub struct Event {
     pub data: EventData,
 }
 
+#[cw_serde]
+#[derive(Default)]
+pub struct EventFilter {
+    pub event_type: Option<String>,
+    pub owner: Option<Addr>,
+    pub from_timestamp: Option<Timestamp>,
+    pub to_timestamp: Option<Timestamp>,
+    pub from_block_height: Option<u64>,
+    pub to_block_height: Option<u64>,
+}
+
 #[derive(Clone)]
 pub struct EventBuilder {
     resource_id: Uint128,
@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum ExitAction {
+    SwapRemainingBalance,
+    CancelVault,
+}
+
+#[cw_serde]
+pub struct ExitCondition {
+    pub price: Decimal,
+    pub action: ExitAction,
+}
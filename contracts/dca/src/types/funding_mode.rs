@@ -0,0 +1,12 @@
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+#[derive(Default)]
+pub enum FundingMode {
+    #[default]
+    Deposit,
+    Allowance {
+        swap_denom: String,
+    },
+    StakingRewards,
+}
@@ -0,0 +1,39 @@
+use super::event::ExecutionSkippedReason;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Uint128};
+
+#[cw_serde]
+pub enum VaultHookNotification {
+    ExecutionCompleted {
+        sent: Coin,
+        received: Coin,
+        fee: Coin,
+    },
+    ExecutionSkipped {
+        reason: ExecutionSkippedReason,
+    },
+    VaultCancelled {
+        refunded: Coin,
+    },
+    BalanceLow {
+        balance: Coin,
+        swap_amount: Uint128,
+    },
+    EscrowDisbursed {
+        amount_disbursed: Coin,
+        performance_fee: Coin,
+    },
+    VaultFinished {
+        swapped_amount: Coin,
+        received_amount: Coin,
+    },
+}
+
+#[cw_serde]
+pub enum HookExecuteMsg {
+    DcaVaultHook {
+        vault_id: Uint128,
+        owner: Addr,
+        notification: VaultHookNotification,
+    },
+}
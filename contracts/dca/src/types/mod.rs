@@ -2,9 +2,13 @@ pub mod config;
 pub mod dca_plus_config;
 pub mod destination;
 pub mod event;
+pub mod exit_condition;
 pub mod fee_collector;
+pub mod funding_mode;
+pub mod hook;
 pub mod performance_assessment_strategy;
 pub mod position_type;
+pub mod price_band;
 pub mod swap_adjustment_strategy;
 pub mod time_interval;
 pub mod trigger;
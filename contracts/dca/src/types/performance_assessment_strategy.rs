@@ -1,7 +1,8 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Coin, Decimal};
 
-use super::vault::Vault;
+use super::{config::Config, vault::Vault};
+use crate::constants::DEFAULT_PERFORMANCE_FEE_PERCENT;
 
 #[cw_serde]
 pub enum PerformanceAssessmentStrategy {
@@ -25,9 +26,11 @@ impl PerformanceAssessmentStrategy {
         }
     }
 
-    pub fn performance_fee_rate(&self) -> Decimal {
+    pub fn performance_fee_rate(&self, config: &Config) -> Decimal {
         match self {
-            PerformanceAssessmentStrategy::CompareToStandardDca { .. } => Decimal::percent(20),
+            PerformanceAssessmentStrategy::CompareToStandardDca { .. } => config
+                .performance_fee_percent
+                .unwrap_or(DEFAULT_PERFORMANCE_FEE_PERCENT),
         }
     }
 }
@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+pub struct PriceBand {
+    pub lower_deviation: Decimal,
+    pub upper_deviation: Decimal,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum PriceBandEdge {
+    Lower,
+    Upper,
+}
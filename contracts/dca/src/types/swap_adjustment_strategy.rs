@@ -15,6 +15,14 @@ pub enum SwapAdjustmentStrategy {
         multiplier: Decimal,
         increase_only: bool,
     },
+    ValueAveraging {
+        target_growth_per_execution: Uint128,
+        target_value: Uint128,
+    },
+    MovingAverage {
+        periods: u64,
+        multiplier: Decimal,
+    },
 }
 
 #[cw_serde]
@@ -28,11 +36,25 @@ pub enum SwapAdjustmentStrategyParams {
         multiplier: Decimal,
         increase_only: bool,
     },
+    ValueAveraging {
+        target_growth_per_execution: Uint128,
+    },
+    MovingAverage {
+        periods: u64,
+        multiplier: Decimal,
+    },
 }
 
 #[cw_serde]
 pub enum BaseDenom {
     Bitcoin,
+    Custom(String),
+}
+
+impl BaseDenom {
+    pub fn hash(&self) -> u64 {
+        calculate_hash(&to_json_binary(self).unwrap())
+    }
 }
 
 impl SwapAdjustmentStrategy {
@@ -49,17 +71,21 @@ impl SwapAdjustmentStrategy {
 
     pub fn max_adjustment(&self) -> Decimal {
         match self {
-            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => Decimal::percent(350),
-            SwapAdjustmentStrategy::WeightedScale { .. } => Decimal::MAX,
+            SwapAdjustmentStrategy::RiskWeightedAverage { .. }
+            | SwapAdjustmentStrategy::MovingAverage { .. } => Decimal::percent(350),
+            SwapAdjustmentStrategy::WeightedScale { .. }
+            | SwapAdjustmentStrategy::ValueAveraging { .. } => Decimal::MAX,
         }
     }
 
     pub fn min_adjustment(&self) -> Decimal {
         match self {
-            SwapAdjustmentStrategy::RiskWeightedAverage { .. } => Decimal::percent(20),
+            SwapAdjustmentStrategy::RiskWeightedAverage { .. }
+            | SwapAdjustmentStrategy::MovingAverage { .. } => Decimal::percent(20),
             SwapAdjustmentStrategy::WeightedScale { increase_only, .. } => {
                 Decimal::percent(if *increase_only { 100 } else { 0 })
             }
+            SwapAdjustmentStrategy::ValueAveraging { .. } => Decimal::zero(),
         }
     }
 }
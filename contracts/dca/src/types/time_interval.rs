@@ -14,6 +14,7 @@ pub enum TimeInterval {
     Fortnightly,
     Monthly,
     Custom { seconds: u64 },
+    Cron { expression: String },
 }
 
 impl From<TimeInterval> for String {
@@ -29,6 +30,7 @@ impl From<TimeInterval> for String {
             TimeInterval::Fortnightly => "Fortnightly".to_string(),
             TimeInterval::Monthly => "Monthly".to_string(),
             TimeInterval::Custom { seconds } => format!("Custom:{}", seconds),
+            TimeInterval::Cron { expression } => format!("Cron:{}", expression),
         }
     }
 }
@@ -46,6 +48,7 @@ impl Display for TimeInterval {
             TimeInterval::Fortnightly => write!(f, "Fortnightly"),
             TimeInterval::Monthly => write!(f, "Monthly"),
             TimeInterval::Custom { seconds } => write!(f, "Custom:{}", seconds),
+            TimeInterval::Cron { expression } => write!(f, "Cron:{}", expression),
         }
     }
 }
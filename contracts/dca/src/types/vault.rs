@@ -1,9 +1,15 @@
 use super::{
-    destination::Destination, performance_assessment_strategy::PerformanceAssessmentStrategy,
-    swap_adjustment_strategy::SwapAdjustmentStrategy, time_interval::TimeInterval,
+    destination::Destination,
+    event::VaultEndReason,
+    exit_condition::ExitCondition,
+    funding_mode::FundingMode,
+    performance_assessment_strategy::PerformanceAssessmentStrategy,
+    price_band::{PriceBand, PriceBandEdge},
+    swap_adjustment_strategy::SwapAdjustmentStrategy,
+    time_interval::TimeInterval,
     trigger::TriggerConfiguration,
 };
-use crate::helpers::time::get_total_execution_duration;
+use crate::{constants::LOW_BALANCE_SWAP_COUNT, helpers::time::get_total_execution_duration};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     Addr, Binary, Coin, Decimal, Decimal256, StdResult, Timestamp, Uint128, Uint256,
@@ -16,6 +22,7 @@ pub enum VaultStatus {
     Active,
     Inactive,
     Cancelled,
+    Paused,
 }
 
 #[cw_serde]
@@ -42,6 +49,18 @@ pub struct Vault {
     pub trigger: Option<TriggerConfiguration>,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub pending_owner: Option<Addr>,
+    pub end_time: Option<Timestamp>,
+    pub max_executions: Option<u64>,
+    pub execution_count: u64,
+    pub stop_loss: Option<ExitCondition>,
+    pub take_profit: Option<ExitCondition>,
+    pub maximum_receive_amount: Option<Uint128>,
+    pub price_band: Option<PriceBand>,
+    pub referrer: Option<Addr>,
+    pub hook: Option<Addr>,
+    pub funding_mode: FundingMode,
+    pub performance_fee_high_water_mark: Uint128,
 }
 
 impl Vault {
@@ -98,6 +117,53 @@ impl Vault {
             })
     }
 
+    pub fn maximum_receive_amount_exceeded(&self, belief_price: Decimal) -> StdResult<bool> {
+        self.maximum_receive_amount
+            .map_or(Ok(false), |maximum_receive_amount| {
+                let expected_receive_amount_at_price =
+                    Decimal256::from_ratio(self.swap_amount, Uint256::one())
+                        .checked_div(belief_price.into())
+                        .expect("belief price should be larger than 0");
+
+                Ok(expected_receive_amount_at_price
+                    > Decimal256::from_ratio(maximum_receive_amount, Uint256::one()))
+            })
+    }
+
+    pub fn price_band_breached(
+        &self,
+        belief_price: Decimal,
+        current_price: Decimal,
+    ) -> Option<PriceBandEdge> {
+        self.price_band.as_ref().and_then(|price_band| {
+            if current_price < belief_price * (Decimal::one() - price_band.lower_deviation) {
+                Some(PriceBandEdge::Lower)
+            } else if current_price > belief_price * (Decimal::one() + price_band.upper_deviation) {
+                Some(PriceBandEdge::Upper)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn stop_loss_triggered(&self, belief_price: Decimal) -> bool {
+        self.stop_loss.as_ref().is_some_and(|stop_loss| {
+            match stop_loss.price.checked_mul(belief_price) {
+                Ok(price) => price >= Decimal::one(),
+                Err(_) => true,
+            }
+        })
+    }
+
+    pub fn take_profit_triggered(&self, belief_price: Decimal) -> bool {
+        self.take_profit.as_ref().is_some_and(|take_profit| {
+            take_profit
+                .price
+                .checked_mul(belief_price)
+                .is_ok_and(|price| price <= Decimal::one())
+        })
+    }
+
     pub fn is_active(&self) -> bool {
         self.status == VaultStatus::Active
     }
@@ -110,6 +176,33 @@ impl Vault {
         self.status == VaultStatus::Inactive
     }
 
+    pub fn get_end_reason(&self, current_time: Timestamp) -> Option<VaultEndReason> {
+        if self
+            .max_executions
+            .is_some_and(|max_executions| self.execution_count >= max_executions)
+        {
+            return Some(VaultEndReason::MaxExecutionsReached);
+        }
+
+        if self
+            .end_time
+            .is_some_and(|end_time| current_time >= end_time)
+        {
+            return Some(VaultEndReason::EndTimeReached);
+        }
+
+        None
+    }
+
+    pub fn has_low_balance(&self) -> bool {
+        !self.is_allowance_funded()
+            && !self.balance.amount.is_zero()
+            && self.balance.amount
+                < self
+                    .swap_amount
+                    .saturating_mul(Uint128::new(LOW_BALANCE_SWAP_COUNT))
+    }
+
     pub fn should_not_continue(&self) -> bool {
         self.is_inactive()
             && self.performance_assessment_strategy.clone().map_or(
@@ -120,9 +213,24 @@ impl Vault {
             )
     }
 
+    pub fn is_allowance_funded(&self) -> bool {
+        matches!(
+            self.funding_mode,
+            FundingMode::Allowance { .. } | FundingMode::StakingRewards
+        )
+    }
+
+    pub fn is_staking_rewards_funded(&self) -> bool {
+        self.funding_mode == FundingMode::StakingRewards
+    }
+
     pub fn is_cancelled(&self) -> bool {
         self.status == VaultStatus::Cancelled
     }
+
+    pub fn is_paused(&self) -> bool {
+        self.status == VaultStatus::Paused
+    }
 }
 
 pub struct VaultBuilder {
@@ -146,9 +254,19 @@ pub struct VaultBuilder {
     pub escrowed_amount: Coin,
     pub performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
     pub swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+    pub end_time: Option<Timestamp>,
+    pub max_executions: Option<u64>,
+    pub stop_loss: Option<ExitCondition>,
+    pub take_profit: Option<ExitCondition>,
+    pub maximum_receive_amount: Option<Uint128>,
+    pub price_band: Option<PriceBand>,
+    pub referrer: Option<Addr>,
+    pub hook: Option<Addr>,
+    pub funding_mode: FundingMode,
 }
 
 impl VaultBuilder {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         created_at: Timestamp,
         owner: Addr,
@@ -170,6 +288,15 @@ impl VaultBuilder {
         escrowed_amount: Coin,
         performance_assessment_strategy: Option<PerformanceAssessmentStrategy>,
         swap_adjustment_strategy: Option<SwapAdjustmentStrategy>,
+        end_time: Option<Timestamp>,
+        max_executions: Option<u64>,
+        stop_loss: Option<ExitCondition>,
+        take_profit: Option<ExitCondition>,
+        maximum_receive_amount: Option<Uint128>,
+        price_band: Option<PriceBand>,
+        referrer: Option<Addr>,
+        hook: Option<Addr>,
+        funding_mode: FundingMode,
     ) -> VaultBuilder {
         VaultBuilder {
             created_at,
@@ -192,6 +319,15 @@ impl VaultBuilder {
             escrowed_amount,
             performance_assessment_strategy,
             swap_adjustment_strategy,
+            end_time,
+            max_executions,
+            stop_loss,
+            take_profit,
+            maximum_receive_amount,
+            price_band,
+            referrer,
+            hook,
+            funding_mode,
         }
     }
 
@@ -218,6 +354,18 @@ impl VaultBuilder {
             escrowed_amount: self.escrowed_amount,
             performance_assessment_strategy: self.performance_assessment_strategy,
             swap_adjustment_strategy: self.swap_adjustment_strategy,
+            pending_owner: None,
+            end_time: self.end_time,
+            max_executions: self.max_executions,
+            execution_count: 0,
+            stop_loss: self.stop_loss,
+            take_profit: self.take_profit,
+            maximum_receive_amount: self.maximum_receive_amount,
+            price_band: self.price_band,
+            referrer: self.referrer,
+            hook: self.hook,
+            funding_mode: self.funding_mode,
+            performance_fee_high_water_mark: Uint128::zero(),
             trigger: None,
         }
     }
@@ -425,3 +573,126 @@ mod price_threshold_exceeded_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod maximum_receive_amount_exceeded_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_not_be_exceeded_when_price_equals_threshold() {
+        let vault = Vault {
+            swap_amount: Uint128::new(100),
+            maximum_receive_amount: Some(Uint128::new(50)),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.maximum_receive_amount_exceeded(Decimal::from_str("2.0").unwrap()),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn should_be_exceeded_when_price_is_below_threshold() {
+        let vault = Vault {
+            swap_amount: Uint128::new(100),
+            maximum_receive_amount: Some(Uint128::new(50)),
+            ..Vault::default()
+        };
+
+        assert_eq!(
+            vault.maximum_receive_amount_exceeded(Decimal::from_str("1.9").unwrap()),
+            Ok(true)
+        );
+    }
+}
+
+#[cfg(test)]
+mod price_band_breached_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn vault_with_price_band() -> Vault {
+        Vault {
+            price_band: Some(PriceBand {
+                lower_deviation: Decimal::percent(5),
+                upper_deviation: Decimal::percent(10),
+            }),
+            ..Vault::default()
+        }
+    }
+
+    #[test]
+    fn should_not_be_breached_when_price_is_within_band() {
+        assert_eq!(
+            vault_with_price_band()
+                .price_band_breached(Decimal::one(), Decimal::from_str("1.1").unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn should_breach_lower_edge_when_price_is_below_band() {
+        assert_eq!(
+            vault_with_price_band()
+                .price_band_breached(Decimal::one(), Decimal::from_str("0.94").unwrap()),
+            Some(PriceBandEdge::Lower)
+        );
+    }
+
+    #[test]
+    fn should_breach_upper_edge_when_price_is_above_band() {
+        assert_eq!(
+            vault_with_price_band()
+                .price_band_breached(Decimal::one(), Decimal::from_str("1.11").unwrap()),
+            Some(PriceBandEdge::Upper)
+        );
+    }
+}
+
+#[cfg(test)]
+mod exit_conditions_triggered_tests {
+    use super::*;
+    use crate::types::exit_condition::{ExitAction, ExitCondition};
+
+    fn vault_with_exit_conditions(price: Decimal) -> Vault {
+        Vault {
+            stop_loss: Some(ExitCondition {
+                price,
+                action: ExitAction::CancelVault,
+            }),
+            take_profit: Some(ExitCondition {
+                price,
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            ..Vault::default()
+        }
+    }
+
+    #[test]
+    fn with_overflowing_price_should_trigger_stop_loss_only() {
+        let vault = vault_with_exit_conditions(Decimal::MAX);
+
+        assert!(vault.stop_loss_triggered(Decimal::percent(200)));
+        assert!(!vault.take_profit_triggered(Decimal::percent(200)));
+    }
+
+    #[test]
+    fn with_price_within_range_should_not_trigger() {
+        let vault = Vault {
+            stop_loss: Some(ExitCondition {
+                price: Decimal::percent(80),
+                action: ExitAction::CancelVault,
+            }),
+            take_profit: Some(ExitCondition {
+                price: Decimal::percent(150),
+                action: ExitAction::SwapRemainingBalance,
+            }),
+            ..Vault::default()
+        };
+
+        assert!(!vault.stop_loss_triggered(Decimal::one()));
+        assert!(!vault.take_profit_triggered(Decimal::one()));
+    }
+}